@@ -5,7 +5,9 @@
 //! and securely release payments upon task completion.
 
 use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, Address, Env, Map, String, Symbol, Vec,
+    auth::{ContractContext, InvokerContractAuthEntry, SubContractInvocation},
+    contract, contracterror, contractevent, contractimpl, contracttype, token, vec, xdr::ToXdr,
+    Address, Bytes, BytesN, Env, IntoVal, InvokeError, Map, String, Symbol, Vec,
 };
 
 // Task status enumeration
@@ -41,6 +43,15 @@ pub struct TaskApplication {
     pub message: String,            // Optional application message
 }
 
+// Task comment structure
+#[contracttype]
+#[derive(Clone)]
+pub struct TaskComment {
+    pub author: Address,            // Creator or assignee who posted the comment
+    pub posted_at: u64,             // Posting timestamp
+    pub text: String,               // Comment body
+}
+
 // Task structure with all necessary fields
 #[contracttype]
 #[derive(Clone)]
@@ -48,7 +59,7 @@ pub struct Task {
     pub id: u64,                    // Unique identifier
     pub title: String,              // Task title
     pub description: String,        // Detailed description
-    pub github_link: String,        // GitHub repository link (can be empty string)
+    pub github_link: Option<String>, // GitHub repository link (None when not provided)
     pub funding_amount: i128,       // Amount funded in stroops
     pub deadline: u64,              // Unix timestamp
     pub creator: Address,           // Creator's address
@@ -59,21 +70,420 @@ pub struct Task {
     pub creator_approved: bool,     // Creator's approval flag
     pub assignee_approved: bool,    // Assignee's completion flag
     pub applications: Vec<TaskApplication>, // List of applications
+    pub priority: u32,              // Priority level (0 low … 3 urgent)
+    pub auto_release: bool,         // Release funds as soon as the assignee completes
+    pub depends_on: Vec<u64>,       // Tasks that must finish before this one can start
+    pub released_so_far: i128,      // Escrowed amount already paid out of the contract
+    pub completion_proof: Option<String>, // Deliverable reference submitted by the assignee
+    pub token_decimals: u32,        // Decimals of the funding token at creation
+    pub was_expired: bool,          // Refunded because the deadline passed, not cancelled
+    pub comments: Vec<TaskComment>, // Coordination thread between creator and assignee
+    pub abandoned: bool,            // Cancelled by the assignee rather than the creator
+    pub previous_assignees: Vec<Address>, // Assignees replaced through reassignment
+    pub disputed: bool,             // Awaiting arbitration; funds cannot be released
+    pub payout_address: Option<Address>, // Wallet receiving the payout instead of the assignee
+    pub processing: bool,           // Escrow transfer in flight; other operations are rejected
+    pub cancel_requested_at: Option<u64>, // When the creator gave notice of cancellation
+    pub creator_rated: bool,        // Assignee has already rated the creator for this task
+    pub notify_on_release: bool,    // Call the recipient's release callback after payout
+    pub vault_deposit: i128,        // Escrow currently deposited in the yield vault
+    pub relisted_from: Option<u64>, // Cancelled task this one was re-posted from
+    pub reminded: bool,             // Assignee has been sent a deadline reminder
+    pub oracle: Option<Address>,    // Contract that must approve the release, if any
+    pub oracle_fn: Option<Symbol>,  // Oracle function called with the task ID, returning bool
+    pub open_pool: bool,            // Reopened for any worker to claim
+    pub refund_address: Option<Address>, // Wallet receiving refunds instead of the creator
+    pub fee_bps: u32,               // Platform fee rate locked in at creation
+    pub expiry_action: ExpiryAction, // What marking the task expired does
+    pub deliverable_hash: Option<BytesN<32>>, // Hash of the delivered artifact, if submitted
+    pub started_at: Option<u64>,    // When the current assignee started work
+    pub pledged: bool,              // Funding is promised but not yet escrowed
+    pub accept_window: u64,         // Seconds an assignee has to start work (0 for no limit)
+    pub accept_by: Option<u64>,     // When the current assignment lapses if not started
+}
+
+// Follow-up applied automatically when a task is marked expired
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ExpiryAction {
+    Refund,                        // Stay expired so the creator can reclaim the escrow
+    AutoReassignToBackup(Address), // Hand the task to the backup assignee
+    ReopenToPool,                  // Reopen the task for any worker to claim
+}
+
+// Resolution forced by the deployer on a stuck task
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AdminOutcome {
+    PayAssignee,   // Release the escrow to the assignee (minus platform fee)
+    RefundCreator, // Refund the escrow to the creator
+    Split(u32),    // Pay the given percentage to the assignee, refund the rest
+}
+
+// Whether the platform takes its fee when a dispute is resolved
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DisputeFeePolicy {
+    NoFeeOnRefund,       // Disputes settle without any platform fee
+    FeeOnDisputedPayout, // The usual fee is taken when the assignee is paid
+}
+
+// Party that bears the platform fee when quoting a task's funding
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FeePayer {
+    Assignee, // Fee is deducted from the payout
+    Creator,  // Creator tops up the funding to cover the fee
+}
+
+// Event emitted when the deployer force-resolves a task
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminResolved {
+    #[topic]
+    pub task_id: u64,
+    pub deployer: Address,
+    pub outcome: AdminOutcome,
+    pub assignee_amount: i128,
+    pub refund_amount: i128,
+    pub platform_fee: i128,
+}
+
+// Event emitted when the creator cancels a task
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskCancelled {
+    #[topic]
+    pub task_id: u64,
+    pub creator: Address,
+    pub refund_amount: i128,
+}
+
+// Event emitted when the assignee gives up a task and the creator is refunded
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskAbandoned {
+    #[topic]
+    pub task_id: u64,
+    pub assignee: Address,
+    pub refund_amount: i128,
+}
+
+// Event emitted when the creator reclaims the funds of an expired task
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskExpiredRefunded {
+    #[topic]
+    pub task_id: u64,
+    pub creator: Address,
+    pub refund_amount: i128,
+}
+
+// Event emitted when a party to a task disputes it
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisputeRaised {
+    #[topic]
+    pub task_id: u64,
+    pub raised_by: Address,
+}
+
+// Event emitted when a cancelled task is re-posted as a new task
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TaskRelisted {
+    #[topic]
+    pub task_id: u64,
+    pub new_task_id: u64,
+    pub creator: Address,
+}
+
+// Event emitted when the assignee replaces a completed task's proof
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CompletionProofUpdated {
+    #[topic]
+    pub task_id: u64,
+    pub assignee: Address,
+    pub proof: String,
+}
+
+// One page of results from a bounded task scan
+#[contracttype]
+#[derive(Clone)]
+pub struct TaskPage {
+    pub tasks: Vec<Task>,           // Matching tasks in ID order
+    pub next_cursor: Option<u64>,   // Task ID to resume scanning from, if any remain
+}
+
+// Optional settings supplied when creating a task
+#[contracttype]
+#[derive(Clone)]
+pub struct TaskOptions {
+    pub priority: u32,              // Priority level (0 low … 3 urgent)
+    pub auto_release: bool,         // Release funds as soon as the assignee completes
+    pub depends_on: Vec<u64>,       // Tasks that must finish before this one can start
+    pub client_ref: Option<String>, // Idempotency key; retries with the same key reuse the task
+    pub notify_on_release: bool,    // Call the recipient's release callback after payout
+    pub oracle: Option<Address>,    // Contract that must approve the release, if any
+    pub oracle_fn: Option<Symbol>,  // Oracle function called with the task ID, returning bool
+    pub expiry_action: ExpiryAction, // What marking the task expired does
+    pub accept_window: u64,         // Seconds an assignee has to start work (0 for no limit)
+}
+
+// Preview of the fund split a release would perform
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReleaseOutcome {
+    pub assignee: Address,          // Assignee credited with the payout
+    pub assignee_amount: i128,      // Payout, net of the fee unless it is charged in the fee token
+    pub platform_fee: i128,         // Fee retained by the platform
+    pub accumulated_fees: i128,     // Platform fee accumulator after release
+}
+
+// Running payment totals for a single user
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct UserFinancials {
+    pub earned: i128,               // Paid out to the user as assignee (after fees)
+    pub spent: i128,                // Released from the user's escrow (plus tips)
+    pub locked: i128,               // Currently held in escrow for the user's tasks
+}
+
+// Track record of a user acting as assignee
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AssigneeReputation {
+    pub completed: u32,             // Tasks paid out to the user
+    pub failed: u32,                // Tasks the user abandoned
+}
+
+// Money picture of a single task
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FundingBreakdown {
+    pub gross_funding: i128,        // Amount the creator funded
+    pub platform_fee: i128,         // Fee taken when the funding is paid out
+    pub assignee_payout: i128,      // Funding paid to the assignee, net of any payment-token fee
+    pub escrow_held: i128,          // Funding still held by the contract
+}
+
+// Which of a task's service-level windows have been breached
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SlaStatus {
+    pub unaccepted: bool,           // Assigned but not started by its accept-by time
+    pub overdue: bool,              // Still open past its deadline
+    pub release_overdue: bool,      // Completed and left unreleased past the release timeout
+}
+
+// Replacement parameters for re-posting a task
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RelistParams {
+    pub title: String,              // New task title
+    pub description: String,        // New task description
+    pub github_link: String,        // New GitHub link (empty string for none)
+    pub funding_amount: i128,       // New funding; only the difference is transferred
+    pub deadline: u64,              // New deadline (Unix timestamp)
+    pub assignee: Option<Address>,  // Assignee for the new task, if any
+}
+
+// Fee outflow a withdrawal request asks the signers to approve
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum WithdrawalAction {
+    PlatformFees(Address, i128), // Pay this amount of platform fees to the recipient
+    FeeTokenFees(Address, i128), // Pay this amount of fee-token fees to the recipient
+    AutoSweep(Option<Address>),  // Sweep release fees to this treasury, or stop sweeping
+}
+
+// Fee outflow awaiting signer approvals
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WithdrawalRequest {
+    pub id: u64,                  // Request ID
+    pub action: WithdrawalAction, // Outflow the approvals authorize
+    pub approvals: Vec<Address>,  // Signers that have approved it
+}
+
+// Snapshot of every configurable contract parameter
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Settings {
+    pub token: Address,             // Payment token contract
+    pub deployer: Address,          // Platform fee recipient and administrator
+    pub platform_fee_percentage: u32, // Fee taken from each payout
+    pub cancel_fee_bps: u32,        // Fee taken from creator cancellations
+    pub release_timeout: u64,       // Seconds before an assignee may claim unreleased funds
+    pub min_task_duration: u64,     // Minimum seconds between creation and deadline
+    pub max_active_assignments: u32, // Per-assignee cap on active tasks (0 = unlimited)
+    pub require_github_link: bool,  // Whether new tasks must reference a GitHub link
+    pub withdrawal_threshold: u32,  // Signer approvals required per fee withdrawal
+    pub vault: Option<Address>,     // Yield vault holding idle escrow, if configured
+    pub min_fee: i128,              // Smallest platform fee taken on a payout
+    pub block_deployer_assignee: bool, // Whether the deployer is barred from being assigned tasks
+    pub restrict_expiry: bool,      // Whether only participants may mark tasks expired
+    pub fee_token: Option<Address>, // Token platform fees are charged in, if not the payment token
+    pub dispute_fee_policy: DisputeFeePolicy, // Whether dispute payouts take a platform fee
+    pub min_work_time: u64,         // Minimum seconds between starting and completing a task
+    pub fee_treasury: Option<Address>, // Treasury receiving release fees directly, if set
+    pub priority_fee_bps: Vec<u32>, // Fee bps by priority (index); overrides the flat fee rate
+}
+
+// Running contract-wide totals for analytics
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct Stats {
+    pub total_tasks: u64,           // Tasks ever created
+    pub total_escrowed: i128,       // Funding ever deposited into escrow
+    pub total_fees_earned: i128,    // Platform and cancellation fees ever collected
+    pub total_fees_withdrawn: i128, // Fees ever withdrawn by the deployer
+    pub active_tasks: u64,          // Tasks whose escrow has not been settled yet
+    pub completed_tasks: u64,       // Tasks whose funds were released to the assignee
+    pub released_volume: i128,      // Escrow paid out through releases, fees included
+    pub release_fees: i128,         // Platform fees taken on those releases
+    pub total_paid: i128,           // Escrow paid out to assignees, net of fees
+    pub total_refunded: i128,       // Escrow returned to creators, net of fees
+    pub fee_token_fees: i128,       // Release fees charged to creators in the fee token
+}
+
+// Contract-wide money flows for reconciliation
+//
+// Escrowed funds are always accounted for as paid, refunded, fees earned, or still locked.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LedgerSummary {
+    pub total_escrowed: i128,       // Funding ever deposited into escrow
+    pub total_paid: i128,           // Escrow paid out to assignees, net of fees
+    pub total_refunded: i128,       // Escrow returned to creators, net of fees
+    pub total_fees_earned: i128,    // Platform and cancellation fees ever collected
+    pub total_fees_withdrawn: i128, // Fees ever withdrawn by the deployer
+    pub fee_token_fees: i128,       // Release fees charged in the fee token, outside the escrow
+}
+
+// Reasons a proposed task would be rejected at creation
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TaskError {
+    EmptyTitle = 1,
+    EmptyDescription = 2,
+    NonPositiveFunding = 3,
+    FundingTooLarge = 4,
+    DeadlineInPast = 5,
+    DeadlineTooSoon = 6,
 }
 
 // Storage keys for contract state
-const TASKS: Symbol = symbol_short!("TASKS");
-const USER_TASKS: Symbol = symbol_short!("USR_TSKS");
-const ASSIGNED_TASKS: Symbol = symbol_short!("ASG_TSKS");
-const TASK_COUNTER: Symbol = symbol_short!("TSK_CNTR");
-const TOKEN: Symbol = symbol_short!("TOKEN");
-const DEPLOYER: Symbol = symbol_short!("DEPLOYER");
-const PLATFORM_FEES: Symbol = symbol_short!("PLT_FEES");
-const USER_PROFILES: Symbol = symbol_short!("USR_PROF");
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Tasks,                  // All tasks by ID
+    UserTasks(Address),     // Task IDs created by a user
+    AssignedTasks(Address), // Task IDs assigned to a user
+    TaskCounter,            // Next task ID
+    Token,                  // Payment token
+    Deployer,               // Contract deployer
+    PlatformFees,           // Accumulated platform fees
+    UserProfiles,           // User profiles by address
+    Username(Address),      // Username registered by a user
+    ReleaseTimeout,         // Auto-release timeout
+    FeeSigners,             // Fee withdrawal signers
+    SignThreshold,          // Approvals needed to withdraw fees
+    WithdrawRequests,       // Pending fee withdrawal requests by ID
+    WithdrawCounter,        // Next withdrawal request ID
+    MaxActive,              // Per-assignee active task cap
+    UserFinancials,         // Per-user financial summaries
+    Reputation,             // Assignee reputations
+    MinDuration,            // Minimum task duration
+    RequireLink,            // Whether a GitHub link is required
+    CancelFeeBps,           // Creator cancellation fee
+    ClientRefs,             // Idempotency keys by creator
+    CreatorRatings,         // Creator ratings
+    Commitments,            // Assignee commitments by task
+    CreatorFees,            // Fees paid per creator
+    Vault,                  // Yield vault
+    VaultBalance,           // Escrow deposited per task
+    MinFee,                 // Minimum platform fee
+    Stats,                  // Contract-wide totals
+    BlockDeployer,          // Whether the deployer may be assigned
+    RestrictExpiry,         // Whether expiry is participant-only
+    Candidates,             // Candidate assignees by task
+    PriorityFees,           // Platform fee rate per priority
+    GithubTasks,            // Tasks with a GitHub link
+    FeeToken,               // Token fees are charged in
+    FeeTokenFees,           // Accumulated fee-token fees
+    FeeTokenDebts,          // Fee-token fees owed per creator
+    DisputeFee,             // Dispute fee policy
+    MinWorkTime,            // Minimum time between starting and completing a task
+    FeeTreasury,            // Treasury release fees are swept to
+    Version,                // Storage layout version
+}
 
+// Version of the storage layout written by this contract
+//
+// Layout 1 is the first to use typed `DataKey`s. Earlier builds kept state under bare Symbol
+// keys, but they had no upgrade entry point, so no deployment holding that data can ever run
+// this code and no migration from it is needed.
+const STORAGE_VERSION: u32 = 1;
 // Platform fee percentage (3% = 3/100)
 const PLATFORM_FEE_PERCENTAGE: u32 = 3;
 
+// Basis points in 100%
+const BPS_DENOMINATOR: u32 = 10_000;
+
+// Largest funding amount whose fee can be computed at any rate without overflowing
+const MAX_FUNDING_AMOUNT: i128 = i128::MAX / BPS_DENOMINATOR as i128;
+
+// Platform fee rate for priorities without a configured rate
+const DEFAULT_FEE_BPS: u32 = PLATFORM_FEE_PERCENTAGE * 100;
+
+// Highest accepted task priority level
+const MAX_TASK_PRIORITY: u32 = 3;
+
+// Maximum number of entries returned by list queries
+const MAX_PAGE_SIZE: u32 = 50;
+
+// Maximum number of task IDs examined by a single scanning query
+const MAX_SCAN_COUNT: u64 = 100;
+
+// Maximum number of tasks refunded by a single bulk cancellation
+const MAX_CANCEL_BATCH: u32 = 50;
+
+// Maximum number of tasks handled by a single keeper batch
+const MAX_KEEPER_BATCH: u32 = 50;
+
+// Maximum number of tasks handed over by a single bulk reassignment
+const MAX_REASSIGN_BATCH: u32 = 50;
+
+// Time the assignee has to salvage a task after the creator requests cancellation (1 day)
+const CANCEL_NOTICE_PERIOD: u64 = 24 * 60 * 60;
+
+// Highest score an assignee can give a creator (scores start at 1)
+const MAX_RATING_SCORE: u32 = 5;
+
+// Success rate reported for assignees without any finished tasks
+const NO_TASK_HISTORY: u32 = u32::MAX;
+
+// Maximum length of a single task comment
+const MAX_COMMENT_LENGTH: u32 = 280;
+
+// Maximum number of comments kept on a task
+const MAX_COMMENTS_PER_TASK: u32 = 50;
+
+// Maximum number of tasks returned by the recent tasks feed
+const MAX_RECENT_TASKS: u32 = 10;
+
+// Default minimum time between a task's creation and its deadline (1 hour)
+const DEFAULT_MIN_TASK_DURATION: u64 = 60 * 60;
+
+// Default time after completion before the assignee may claim payment (7 days)
+const DEFAULT_RELEASE_TIMEOUT: u64 = 7 * 24 * 60 * 60;
+
+// Function invoked on the payout recipient when a task opts into release notifications
+const RELEASE_CALLBACK: &str = "on_release";
+
 // Contract implementation
 #[contract]
 pub struct TaskMaster;
@@ -83,25 +493,66 @@ impl TaskMaster {
     /// Initialize the contract with token address
     ///
     /// # Arguments
-    /// * `token` - Address of the token contract for payments
+    /// * `token` - Address of the token contract for payments (the native XLM
+    ///   Stellar Asset Contract or any SEP-41 token)
     /// * `deployer` - Address of the contract deployer who will receive platform fees
-    pub fn initialize(env: Env, token: Address, deployer: Address) {
+    /// * `signers` - Addresses allowed to approve fee withdrawals (empty for none)
+    /// * `threshold` - Number of signer approvals required per withdrawal (0 if no signers)
+    pub fn initialize(
+        env: Env,
+        token: Address,
+        deployer: Address,
+        signers: Vec<Address>,
+        threshold: u32,
+    ) {
         // Check if already initialized
-        if env.storage().instance().has(&TASK_COUNTER) {
+        if env.storage().instance().has(&DataKey::TaskCounter) {
             panic!("Contract already initialized");
         }
 
+        // Validate the M-of-N withdrawal configuration
+        if signers.is_empty() {
+            if threshold != 0 {
+                panic!("Threshold requires signers");
+            }
+        } else if threshold == 0 || threshold > signers.len() {
+            panic!("Invalid signer threshold");
+        }
+
         // Initialize task counter to 1
-        env.storage().instance().set(&TASK_COUNTER, &1u64);
+        env.storage().instance().set(&DataKey::TaskCounter, &1u64);
         
         // Store token address
-        env.storage().instance().set(&TOKEN, &token);
+        env.storage().instance().set(&DataKey::Token, &token);
         
         // Store deployer address
-        env.storage().instance().set(&DEPLOYER, &deployer);
+        env.storage().instance().set(&DataKey::Deployer, &deployer);
         
         // Initialize platform fees accumulator to 0
-        env.storage().instance().set(&PLATFORM_FEES, &0i128);
+        env.storage().instance().set(&DataKey::PlatformFees, &0i128);
+
+        // Store fee withdrawal signers and approval threshold
+        env.storage().instance().set(&DataKey::FeeSigners, &signers);
+        env.storage().instance().set(&DataKey::SignThreshold, &threshold);
+
+        // Record the storage layout version
+        env.storage().instance().set(&DataKey::Version, &STORAGE_VERSION);
+    }
+
+    /// Get the storage layout version the contract was initialized with
+    ///
+    /// # Returns
+    /// The layout version, or 0 if the contract is not initialized
+    pub fn get_storage_version(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::Version).unwrap_or(0)
+    }
+
+    /// Check whether the contract has been initialized
+    ///
+    /// # Returns
+    /// `true` once `initialize` has been called
+    pub fn is_initialized(env: Env) -> bool {
+        env.storage().instance().has(&DataKey::TaskCounter)
     }
 
     /// Register a user profile with a permanent username
@@ -113,7 +564,7 @@ impl TaskMaster {
         user.require_auth();
 
         // Check if user already has a profile
-        if env.storage().instance().has(&user) {
+        if env.storage().instance().has(&DataKey::Username(user.clone())) {
             panic!("User already registered");
         }
 
@@ -121,7 +572,7 @@ impl TaskMaster {
         let user_profiles: Map<Address, UserProfile> = env
             .storage()
             .instance()
-            .get(&USER_PROFILES)
+            .get(&DataKey::UserProfiles)
             .unwrap_or(Map::new(&env));
         
         // Check all existing profiles for username uniqueness
@@ -142,13 +593,13 @@ impl TaskMaster {
         let mut profiles = env
             .storage()
             .instance()
-            .get(&USER_PROFILES)
+            .get(&DataKey::UserProfiles)
             .unwrap_or(Map::new(&env));
         profiles.set(user.clone(), profile);
-        env.storage().instance().set(&USER_PROFILES, &profiles);
+        env.storage().instance().set(&DataKey::UserProfiles, &profiles);
 
         // Also store by address for quick lookup
-        env.storage().instance().set(&user, &username);
+        env.storage().instance().set(&DataKey::Username(user), &username);
     }
 
     /// Get user profile by address
@@ -162,7 +613,7 @@ impl TaskMaster {
         let user_profiles: Map<Address, UserProfile> = env
             .storage()
             .instance()
-            .get(&USER_PROFILES)
+            .get(&DataKey::UserProfiles)
             .unwrap_or(Map::new(&env));
         user_profiles.get(user)
     }
@@ -180,7 +631,7 @@ impl TaskMaster {
         let user_profiles: Map<Address, UserProfile> = env
             .storage()
             .instance()
-            .get(&USER_PROFILES)
+            .get(&DataKey::UserProfiles)
             .unwrap_or(Map::new(&env));
         let profile = user_profiles.get(applicant.clone())
             .unwrap_or_else(|| panic!("User must register a username before applying for tasks"));
@@ -188,7 +639,7 @@ impl TaskMaster {
         let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
+            .get(&DataKey::Tasks)
             .unwrap_or(Map::new(&env));
         let mut task = tasks
             .get(task_id)
@@ -217,7 +668,7 @@ impl TaskMaster {
 
         // Store updated task
         tasks.set(task_id, task);
-        env.storage().instance().set(&TASKS, &tasks);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
     }
 
     /// Get all applications for a task
@@ -231,7 +682,7 @@ impl TaskMaster {
         let tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
+            .get(&DataKey::Tasks)
             .unwrap_or(Map::new(&env));
         let task = tasks
             .get(task_id)
@@ -239,6 +690,63 @@ impl TaskMaster {
         task.applications
     }
 
+    /// Post a comment on a task
+    ///
+    /// # Arguments
+    /// * `author` - Address of the task creator or assignee
+    /// * `task_id` - ID of the task to comment on
+    /// * `text` - Comment body
+    pub fn add_comment(env: Env, author: Address, task_id: u64, text: String) {
+        author.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is a party to the task
+        if author != task.creator && task.assignee != Some(author.clone()) {
+            panic!("Only task creator or assignee can comment");
+        }
+
+        // Validate comment and thread size
+        if text.len() == 0 {
+            panic!("Comment cannot be empty");
+        }
+        if text.len() > MAX_COMMENT_LENGTH {
+            panic!("Comment is too long");
+        }
+        if task.comments.len() >= MAX_COMMENTS_PER_TASK {
+            panic!("Comment thread is full");
+        }
+
+        // Add comment to task
+        task.comments.push_back(TaskComment {
+            author,
+            posted_at: env.ledger().timestamp(),
+            text,
+        });
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
+
+    /// Get all comments on a task
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// Vector of the task's comments, oldest first
+    pub fn get_comments(env: Env, task_id: u64) -> Vec<TaskComment> {
+        Self::get_task(env, task_id).comments
+    }
+
     /// Assign a task to an applicant
     ///
     /// # Arguments
@@ -251,7 +759,7 @@ impl TaskMaster {
         let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
+            .get(&DataKey::Tasks)
             .unwrap_or(Map::new(&env));
         let mut task = tasks
             .get(task_id)
@@ -262,6 +770,7 @@ impl TaskMaster {
 
         // Check if task is in Created state
         Self::require_valid_state(&task, &[TaskStatus::Created]);
+        Self::require_no_commitment(&env, task_id);
 
         // Verify the applicant has actually applied
         let mut found_application = false;
@@ -275,26 +784,22 @@ impl TaskMaster {
             panic!("Applicant has not applied for this task");
         }
 
+        // Check the applicant can take on another task
+        Self::require_eligible_assignee(&env, &applicant);
+        Self::require_assignee_capacity(&env, &applicant);
+
         // Update task with assignee and change status
         task.assignee = Some(applicant.clone());
         task.status = TaskStatus::Assigned;
+        task.accept_by = Self::accept_deadline(&env, &task);
 
         // Store updated task
         tasks.set(task_id, task.clone());
-        env.storage().instance().set(&TASKS, &tasks);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
 
         // Update assigned tasks mapping
-        let mut assigned_tasks: Map<Address, Vec<u64>> = env
-            .storage()
-            .instance()
-            .get(&ASSIGNED_TASKS)
-            .unwrap_or(Map::new(&env));
-        let mut assignee_tasks = assigned_tasks
-            .get(applicant.clone())
-            .unwrap_or(Vec::new(&env));
-        assignee_tasks.push_back(task_id);
-        assigned_tasks.set(applicant.clone(), assignee_tasks);
-        env.storage().instance().set(&ASSIGNED_TASKS, &assigned_tasks);
+        Self::add_to_index(&env, &DataKey::AssignedTasks(applicant), task_id);
+        Self::fund_pledge(&env, task_id);
     }
 
     /// Create a new task with funding (without assigning)
@@ -303,7 +808,7 @@ impl TaskMaster {
     /// * `creator` - Address of the task creator
     /// * `title` - Task title
     /// * `description` - Detailed description of the task
-    /// * `github_link` - GitHub repository link (can be empty string)
+    /// * `github_link` - GitHub repository link (empty string for none)
     /// * `funding_amount` - Amount to fund the task (in stroops)
     /// * `deadline` - Unix timestamp for the task deadline
     ///
@@ -318,120 +823,413 @@ impl TaskMaster {
         funding_amount: i128,
         deadline: u64,
     ) -> u64 {
-        // Validate inputs
-        Self::validate_task_creation(&env, &title, &description, funding_amount, deadline);
+        let options = Self::default_task_options(&env);
+        Self::create_task_with_options(
+            env,
+            creator,
+            title,
+            description,
+            github_link,
+            funding_amount,
+            deadline,
+            options,
+        )
+    }
 
+    /// Create a new task with funding and additional options
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `title` - Task title
+    /// * `description` - Detailed description of the task
+    /// * `github_link` - GitHub repository link (empty string for none)
+    /// * `funding_amount` - Amount to fund the task (in stroops)
+    /// * `deadline` - Unix timestamp for the task deadline
+    /// * `options` - Optional task settings such as priority
+    ///
+    /// # Returns
+    /// The ID of the newly created task
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_task_with_options(
+        env: Env,
+        creator: Address,
+        title: String,
+        description: String,
+        github_link: String,
+        funding_amount: i128,
+        deadline: u64,
+        options: TaskOptions,
+    ) -> u64 {
         // Require authorization from creator
         creator.require_auth();
 
-        // Get current task ID and increment counter
-        let task_id = env
-            .storage()
-            .instance()
-            .get(&TASK_COUNTER)
-            .unwrap_or(1u64);
-        env.storage()
-            .instance()
-            .set(&TASK_COUNTER, &(task_id + 1));
-
-        let current_time = env.ledger().timestamp();
-
-        // Transfer funds from creator to contract
-        let token_address: Address = env
-            .storage()
-            .instance()
-            .get(&TOKEN)
-            .expect("Token not initialized");
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(&creator, &env.current_contract_address(), &funding_amount);
-
-        // Create new task
-        let task = Task {
-            id: task_id,
+        Self::create_funded_task(
+            &env,
+            creator,
             title,
             description,
             github_link,
             funding_amount,
             deadline,
-            creator: creator.clone(),
-            assignee: None, // Task starts unassigned
-            status: TaskStatus::Created,
-            created_at: current_time,
-            completed_at: None,
-            creator_approved: false,
-            assignee_approved: false,
-            applications: Vec::new(&env), // Initialize empty applications vector
-        };
-
-        // Store task
-        let mut tasks: Map<u64, Task> = env
-            .storage()
-            .instance()
-            .get(&TASKS)
-            .unwrap_or(Map::new(&env));
-        tasks.set(task_id, task.clone());
-        env.storage().instance().set(&TASKS, &tasks);
-
-        // Update user tasks mapping
-        let mut user_tasks: Map<Address, Vec<u64>> = env
-            .storage()
-            .instance()
-            .get(&USER_TASKS)
-            .unwrap_or(Map::new(&env));
-        let mut creator_tasks = user_tasks
-            .get(creator.clone())
-            .unwrap_or(Vec::new(&env));
-        creator_tasks.push_back(task_id);
-        user_tasks.set(creator.clone(), creator_tasks);
-        env.storage().instance().set(&USER_TASKS, &user_tasks);
-
-        task_id
+            options,
+            0,
+            false,
+        )
     }
 
-    /// Assign a task to a user (only if not already assigned)
+    /// Post a task whose funding is only escrowed once a worker takes it on
+    ///
+    /// The task is open for any worker to claim. The creator must approve this contract
+    /// to spend `funding_amount`, which is pulled when the task is assigned or claimed.
     ///
     /// # Arguments
     /// * `creator` - Address of the task creator
-    /// * `task_id` - ID of the task to assign
-    /// * `assignee` - Address of the user to assign the task to
-    pub fn assign_task(env: Env, creator: Address, task_id: u64, assignee: Address) {
+    /// * `title` - Task title
+    /// * `description` - Detailed description of the task
+    /// * `github_link` - GitHub repository link (empty string for none)
+    /// * `funding_amount` - Amount pledged to the task (in stroops)
+    /// * `deadline` - Unix timestamp for the task deadline
+    ///
+    /// # Returns
+    /// The ID of the newly created task
+    pub fn create_pledge_task(
+        env: Env,
+        creator: Address,
+        title: String,
+        description: String,
+        github_link: String,
+        funding_amount: i128,
+        deadline: u64,
+    ) -> u64 {
         creator.require_auth();
 
-        let mut tasks: Map<u64, Task> = env
-            .storage()
-            .instance()
-            .get(&TASKS)
-            .unwrap_or(Map::new(&env));
-        let mut task = tasks
-            .get(task_id)
-            .unwrap_or_else(|| panic!("Task not found"));
-
-        // Check if caller is the creator
-        Self::require_creator(&creator, &task);
-
-        // Check if task is in Created state (not assigned yet)
-        Self::require_valid_state(&task, &[TaskStatus::Created]);
+        let options = Self::default_task_options(&env);
+        Self::create_funded_task(
+            &env,
+            creator,
+            title,
+            description,
+            github_link,
+            funding_amount,
+            deadline,
+            options,
+            0,
+            true,
+        )
+    }
 
-        // Update task with assignee and change status
-        task.assignee = Some(assignee.clone());
-        task.status = TaskStatus::Assigned;
+    /// Create a task operated by a manager but funded by another account
+    ///
+    /// # Arguments
+    /// * `manager` - Address authorizing the creation
+    /// * `funder` - Address whose tokens fund the task; recorded as the creator
+    /// * `title` - Task title
+    /// * `description` - Detailed description of the task
+    /// * `github_link` - GitHub repository link (empty string for none)
+    /// * `funding_amount` - Amount to fund the task (in stroops)
+    /// * `deadline` - Unix timestamp for the task deadline
+    ///
+    /// # Returns
+    /// The ID of the newly created task
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_task_for(
+        env: Env,
+        manager: Address,
+        funder: Address,
+        title: String,
+        description: String,
+        github_link: String,
+        funding_amount: i128,
+        deadline: u64,
+    ) -> u64 {
+        // Both the operating manager and the paying funder must authorize
+        manager.require_auth();
+        funder.require_auth();
 
-        // Store updated task
-        tasks.set(task_id, task.clone());
-        env.storage().instance().set(&TASKS, &tasks);
+        let options = Self::default_task_options(&env);
+        Self::create_funded_task(
+            &env,
+            funder,
+            title,
+            description,
+            github_link,
+            funding_amount,
+            deadline,
+            options,
+            0,
+            false,
+        )
+    }
 
-        // Update assigned tasks mapping
-        let mut assigned_tasks: Map<Address, Vec<u64>> = env
+    /// Create a new task whose deadline is a duration from the current ledger time
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `title` - Task title
+    /// * `description` - Detailed description of the task
+    /// * `github_link` - GitHub repository link (empty string for none)
+    /// * `funding_amount` - Amount to fund the task (in stroops)
+    /// * `duration_secs` - Seconds from now until the task deadline
+    /// * `assignee` - Optional address to assign the task to straight away
+    ///
+    /// # Returns
+    /// The ID of the newly created task
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_task_in(
+        env: Env,
+        creator: Address,
+        title: String,
+        description: String,
+        github_link: String,
+        funding_amount: i128,
+        duration_secs: u64,
+        assignee: Option<Address>,
+    ) -> u64 {
+        creator.require_auth();
+
+        if duration_secs == 0 {
+            panic!("Duration must be positive");
+        }
+        let deadline = env.ledger().timestamp().saturating_add(duration_secs);
+
+        let options = Self::default_task_options(&env);
+        let task_id = Self::create_funded_task(
+            &env,
+            creator.clone(),
+            title,
+            description,
+            github_link,
+            funding_amount,
+            deadline,
+            options,
+            0,
+            false,
+        );
+        if let Some(assignee) = assignee {
+            Self::assign_task_to(&env, &creator, task_id, assignee);
+        }
+        task_id
+    }
+
+    /// Create a new task reserved for an assignee whose identity stays hidden until they reveal
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `title` - Task title
+    /// * `description` - Detailed description of the task
+    /// * `github_link` - GitHub repository link (empty string for none)
+    /// * `funding_amount` - Amount to fund the task (in stroops)
+    /// * `deadline` - Unix timestamp for the task deadline
+    /// * `assignee_commitment` - SHA-256 of the assignee's XDR-encoded address followed by a salt
+    ///
+    /// # Returns
+    /// The ID of the newly created task
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_task_committed(
+        env: Env,
+        creator: Address,
+        title: String,
+        description: String,
+        github_link: String,
+        funding_amount: i128,
+        deadline: u64,
+        assignee_commitment: BytesN<32>,
+    ) -> u64 {
+        creator.require_auth();
+
+        let options = Self::default_task_options(&env);
+        let task_id = Self::create_funded_task(
+            &env,
+            creator,
+            title,
+            description,
+            github_link,
+            funding_amount,
+            deadline,
+            options,
+            0,
+            false,
+        );
+
+        let mut commitments: Map<u64, BytesN<32>> = env
             .storage()
             .instance()
-            .get(&ASSIGNED_TASKS)
+            .get(&DataKey::Commitments)
             .unwrap_or(Map::new(&env));
-        let mut assignee_tasks = assigned_tasks
-            .get(assignee.clone())
-            .unwrap_or(Vec::new(&env));
-        assignee_tasks.push_back(task_id);
-        assigned_tasks.set(assignee.clone(), assignee_tasks);
-        env.storage().instance().set(&ASSIGNED_TASKS, &assigned_tasks);
+        commitments.set(task_id, assignee_commitment);
+        env.storage().instance().set(&DataKey::Commitments, &commitments);
+
+        task_id
+    }
+
+    /// Reveal as the committed assignee of a task and accept it
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the committed assignee
+    /// * `task_id` - ID of the committed task
+    /// * `salt` - Salt used when computing the commitment
+    pub fn reveal_and_accept(env: Env, assignee: Address, task_id: u64, salt: BytesN<32>) {
+        assignee.require_auth();
+
+        let mut commitments: Map<u64, BytesN<32>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Commitments)
+            .unwrap_or(Map::new(&env));
+        let commitment = commitments
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task has no assignee commitment"));
+
+        // Check the revealed address and salt match the commitment
+        let mut preimage = assignee.clone().to_xdr(&env);
+        preimage.append(&Bytes::from(salt));
+        if env.crypto().sha256(&preimage).to_bytes() != commitment {
+            panic!("Commitment does not match");
+        }
+
+        commitments.remove(task_id);
+        env.storage().instance().set(&DataKey::Commitments, &commitments);
+
+        let creator = Self::get_task_creator(env.clone(), task_id);
+        Self::assign_task_to(&env, &creator, task_id, assignee);
+    }
+
+    /// Create a new task offered to several candidates, the first of whom to claim it is assigned
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `title` - Task title
+    /// * `description` - Detailed description of the task
+    /// * `github_link` - GitHub repository link (empty string for none)
+    /// * `funding_amount` - Amount to fund the task (in stroops)
+    /// * `deadline` - Unix timestamp for the task deadline
+    /// * `candidates` - Addresses allowed to claim the task
+    ///
+    /// # Returns
+    /// The ID of the newly created task
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_task_with_candidates(
+        env: Env,
+        creator: Address,
+        title: String,
+        description: String,
+        github_link: String,
+        funding_amount: i128,
+        deadline: u64,
+        candidates: Vec<Address>,
+    ) -> u64 {
+        creator.require_auth();
+
+        if candidates.is_empty() {
+            panic!("Candidate list cannot be empty");
+        }
+
+        let options = Self::default_task_options(&env);
+        let task_id = Self::create_funded_task(
+            &env,
+            creator,
+            title,
+            description,
+            github_link,
+            funding_amount,
+            deadline,
+            options,
+            0,
+            false,
+        );
+
+        let mut offers: Map<u64, Vec<Address>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Candidates)
+            .unwrap_or(Map::new(&env));
+        offers.set(task_id, candidates);
+        env.storage().instance().set(&DataKey::Candidates, &offers);
+
+        task_id
+    }
+
+    /// Claim a task as one of its candidates
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the claiming candidate
+    /// * `task_id` - ID of the task offered to candidates
+    pub fn claim_candidate_task(env: Env, assignee: Address, task_id: u64) {
+        assignee.require_auth();
+
+        let mut offers: Map<u64, Vec<Address>> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Candidates)
+            .unwrap_or(Map::new(&env));
+        let candidates = offers
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task has no open candidate offer"));
+        if !candidates.contains(&assignee) {
+            panic!("Caller is not a candidate for this task");
+        }
+
+        offers.remove(task_id);
+        env.storage().instance().set(&DataKey::Candidates, &offers);
+
+        let creator = Self::get_task_creator(env.clone(), task_id);
+        Self::assign_task_to(&env, &creator, task_id, assignee);
+    }
+
+    /// Assign a task to a user (only if not already assigned)
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task to assign
+    /// * `assignee` - Address of the user to assign the task to
+    pub fn assign_task(env: Env, creator: Address, task_id: u64, assignee: Address) {
+        creator.require_auth();
+        Self::assign_task_to(&env, &creator, task_id, assignee);
+    }
+
+    /// Edit a task's details before the assignee starts work
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task to edit
+    /// * `title` - New task title
+    /// * `description` - New task description
+    /// * `github_link` - New GitHub repository link (empty string for none)
+    pub fn update_task_details(
+        env: Env,
+        creator: Address,
+        task_id: u64,
+        title: String,
+        description: String,
+        github_link: String,
+    ) {
+        creator.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the creator
+        Self::require_creator(&creator, &task);
+
+        // Scope is frozen once the assignee has started
+        Self::require_valid_state(&task, &[TaskStatus::Assigned]);
+
+        // Validate and apply the new details
+        Self::validate_task_details(&title, &description);
+        task.github_link = Self::normalize_github_link(&env, github_link);
+        task.title = title;
+        task.description = description;
+        Self::index_github_task(&env, task_id, task.github_link.is_some());
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
     }
 
     /// Mark a task as complete by the assignee
@@ -440,13 +1238,33 @@ impl TaskMaster {
     /// * `assignee` - Address of the assignee
     /// * `task_id` - ID of the task to complete
     pub fn complete_task(env: Env, assignee: Address, task_id: u64) {
+        Self::complete(&env, assignee, task_id, None);
+    }
+
+    /// Mark a task as complete, recording a hash of the delivered artifact
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the assignee
+    /// * `task_id` - ID of the task to complete
+    /// * `deliverable_hash` - SHA-256 hash of the deliverable, for the creator to verify against
+    pub fn complete_task_with_hash(
+        env: Env,
+        assignee: Address,
+        task_id: u64,
+        deliverable_hash: BytesN<32>,
+    ) {
+        Self::complete(&env, assignee, task_id, Some(deliverable_hash));
+    }
+
+    /// Mark a task as complete by the assignee, with an optional deliverable hash
+    fn complete(env: &Env, assignee: Address, task_id: u64, deliverable_hash: Option<BytesN<32>>) {
         assignee.require_auth();
 
         let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
         let mut task = tasks
             .get(task_id)
             .unwrap_or_else(|| panic!("Task not found"));
@@ -454,6 +1272,11 @@ impl TaskMaster {
         // Check if caller is the assignee
         Self::require_assignee(&assignee, &task);
 
+        // Repeated completion by the same assignee is a no-op so retries succeed
+        if task.status == TaskStatus::Completed {
+            return;
+        }
+
         // Check if task is in valid state for completion
         Self::require_valid_state(
             &task,
@@ -468,28 +1291,45 @@ impl TaskMaster {
             panic!("Task has expired");
         }
 
-        // Update task status and completion timestamp
+        // Check enough time has passed since work started (or since creation, if never started)
+        let worked = env.ledger().timestamp() - task.started_at.unwrap_or(task.created_at);
+        if worked < Self::get_min_work_time(env.clone()) {
+            panic!("Minimum work time not reached");
+        }
+
+        // Update task status and completion timestamp, withdrawing any pending cancellation
         task.status = TaskStatus::Completed;
+        task.cancel_requested_at = None;
         task.assignee_approved = true;
         task.completed_at = Some(env.ledger().timestamp());
+        task.deliverable_hash = deliverable_hash;
+
+        // Creator consented to payment at creation, so pay out immediately
+        // unless the task is awaiting arbitration or its oracle condition
+        if task.auto_release && !task.disputed && Self::oracle_condition_met(env, &task) {
+            task.creator_approved = true;
+            Self::release_checked_funds(env, task);
+            return;
+        }
 
         // Store updated task
         tasks.set(task_id, task);
-        env.storage().instance().set(&TASKS, &tasks);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
     }
 
-    /// Update task status to InProgress
+    /// Replace the completion proof of a task awaiting fund release
     ///
     /// # Arguments
     /// * `assignee` - Address of the assignee
-    /// * `task_id` - ID of the task to start
-    pub fn start_task(env: Env, assignee: Address, task_id: u64) {
+    /// * `task_id` - ID of the completed task
+    /// * `proof` - New deliverable reference (e.g. a pull request link)
+    pub fn update_completion_proof(env: Env, assignee: Address, task_id: u64, proof: String) {
         assignee.require_auth();
 
         let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
+            .get(&DataKey::Tasks)
             .unwrap_or(Map::new(&env));
         let mut task = tasks
             .get(task_id)
@@ -498,100 +1338,171 @@ impl TaskMaster {
         // Check if caller is the assignee
         Self::require_assignee(&assignee, &task);
 
-        // Check if task is in Assigned state
-        Self::require_valid_state(&task, &[TaskStatus::Assigned]);
+        // Proof can only change while the task awaits release
+        Self::require_valid_state(&task, &[TaskStatus::Completed]);
 
-        // Update task status
-        task.status = TaskStatus::InProgress;
+        if proof.is_empty() {
+            panic!("Proof cannot be empty");
+        }
+
+        task.completion_proof = Some(proof.clone());
 
         // Store updated task
         tasks.set(task_id, task);
-        env.storage().instance().set(&TASKS, &tasks);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+
+        CompletionProofUpdated {
+            task_id,
+            assignee,
+            proof,
+        }
+        .publish(&env);
     }
 
-    /// Release funds to the assignee after creator approval
+    /// Send this task's payout to a wallet other than the assignee's
     ///
     /// # Arguments
-    /// * `creator` - Address of the task creator
-    /// * `task_id` - ID of the task to release funds for
-    pub fn release_funds(env: Env, creator: Address, task_id: u64) {
-        creator.require_auth();
+    /// * `assignee` - Address of the assignee
+    /// * `task_id` - ID of the task
+    /// * `payout_addr` - Address that should receive the payout
+    pub fn set_payout_address(env: Env, assignee: Address, task_id: u64, payout_addr: Address) {
+        assignee.require_auth();
 
         let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
+            .get(&DataKey::Tasks)
             .unwrap_or(Map::new(&env));
         let mut task = tasks
             .get(task_id)
             .unwrap_or_else(|| panic!("Task not found"));
 
-        // Check if caller is the creator
-        Self::require_creator(&creator, &task);
-
-        // Check if task is in valid state for fund release
-        Self::require_valid_state(&task, &[TaskStatus::Completed]);
+        // Check if caller is the assignee
+        Self::require_assignee(&assignee, &task);
 
-        // Check if assignee has marked task as complete
-        if !task.assignee_approved {
-            panic!("Task must be marked complete by assignee");
-        }
+        // Payout address can only change before funds are released
+        Self::require_valid_state(
+            &task,
+            &[TaskStatus::Assigned, TaskStatus::InProgress, TaskStatus::Completed],
+        );
 
-        let assignee = task
-            .assignee
-            .clone()
-            .expect("Task must have an assignee");
+        task.payout_address = Some(payout_addr);
 
-        // Calculate platform fee (3% of funding amount)
-        let platform_fee = task.funding_amount * PLATFORM_FEE_PERCENTAGE as i128 / 100i128;
-        let assignee_amount = task.funding_amount - platform_fee;
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
 
-        // Update platform fees accumulator
-        let mut accumulated_fees: i128 = env
+    /// Send this task's refunds to a wallet other than the creator's
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task
+    /// * `refund_addr` - Address that should receive refunds
+    pub fn set_refund_address(env: Env, creator: Address, task_id: u64, refund_addr: Address) {
+        creator.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&PLATFORM_FEES)
-            .unwrap_or(0i128);
-        accumulated_fees += platform_fee;
-        env.storage().instance().set(&PLATFORM_FEES, &accumulated_fees);
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
 
-        // Update task status
-        task.status = TaskStatus::FundsReleased;
-        task.creator_approved = true;
+        // Check if caller is the creator
+        Self::require_creator(&creator, &task);
 
-        // Store updated task before transfer
-        tasks.set(task_id, task.clone());
-        env.storage().instance().set(&TASKS, &tasks);
+        // Refund address can only change while escrow is still held
+        Self::require_valid_state(
+            &task,
+            &[
+                TaskStatus::Created,
+                TaskStatus::Assigned,
+                TaskStatus::InProgress,
+                TaskStatus::Completed,
+                TaskStatus::Expired,
+            ],
+        );
 
-        // Get token client
-        let token_address: Address = env
+        task.refund_address = Some(refund_addr);
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
+
+    /// Update task status to InProgress
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the assignee
+    /// * `task_id` - ID of the task to start
+    pub fn start_task(env: Env, assignee: Address, task_id: u64) {
+        assignee.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TOKEN)
-            .expect("Token not initialized");
-        let token_client = token::Client::new(&env, &token_address);
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
 
-        // Transfer funds to assignee (after platform fee deduction)
-        token_client.transfer(
-            &env.current_contract_address(),
-            &assignee,
-            &assignee_amount,
-        );
+        // Check if caller is the assignee
+        Self::require_assignee(&assignee, &task);
+
+        // Check if task is in Assigned state
+        Self::require_valid_state(&task, &[TaskStatus::Assigned]);
+
+        // Check if all dependencies have finished
+        if !Self::get_blocking_tasks(env.clone(), task_id).is_empty() {
+            panic!("Task has unfinished dependencies");
+        }
+
+        // Update task status and record when work began
+        task.status = TaskStatus::InProgress;
+        task.started_at = Some(env.ledger().timestamp());
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
     }
 
-    /// Cancel a task and refund the creator
+    /// Release funds to the assignee after creator approval
     ///
     /// # Arguments
     /// * `creator` - Address of the task creator
-    /// * `task_id` - ID of the task to cancel
-    pub fn cancel_task(env: Env, creator: Address, task_id: u64) {
+    /// * `task_id` - ID of the task to release funds for
+    pub fn release_funds(env: Env, creator: Address, task_id: u64) {
+        Self::release_by_creator(&env, creator, task_id, None);
+    }
+
+    /// Release funds to the assignee and add a bonus paid by the creator
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task to release funds for
+    /// * `tip` - Extra amount sent from the creator to the assignee (no platform fee)
+    pub fn release_funds_with_tip(env: Env, creator: Address, task_id: u64, tip: i128) {
+        Self::release_by_creator(&env, creator, task_id, Some(tip));
+    }
+
+    /// Release funds on the creator's approval, adding an optional tip paid by the creator
+    fn release_by_creator(env: &Env, creator: Address, task_id: u64, tip: Option<i128>) {
         creator.require_auth();
 
-        let mut tasks: Map<u64, Task> = env
+        let tip = tip.unwrap_or(0);
+        if tip < 0 {
+            panic!("Tip cannot be negative");
+        }
+
+        let tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
         let mut task = tasks
             .get(task_id)
             .unwrap_or_else(|| panic!("Task not found"));
@@ -599,319 +1510,3878 @@ impl TaskMaster {
         // Check if caller is the creator
         Self::require_creator(&creator, &task);
 
-        // Check if task is in valid state for cancellation
-        Self::require_valid_state(
-            &task,
-            &[TaskStatus::Created, TaskStatus::Assigned, TaskStatus::InProgress],
-        );
+        // Pay the assignee and accrue the platform fee
+        task.creator_approved = true;
+        Self::release_task_funds(env, task.clone());
 
-        // Update task status
-        task.status = TaskStatus::Cancelled;
+        // The creator signed this release, so any fee-token charge is collected now
+        Self::collect_fee_token_debt(env, &creator);
 
-        // Store updated task before refund
-        tasks.set(task_id, task.clone());
-        env.storage().instance().set(&TASKS, &tasks);
+        // Tip goes straight from the creator to the assignee's payout address
+        if tip > 0 {
+            let assignee = task.assignee.clone().expect("Task must have an assignee");
+            let token_address: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .expect("Token not initialized");
+            let token_client = token::Client::new(env, &token_address);
+            token_client.transfer(&creator, &Self::payout_recipient(&task), &tip);
+            Self::record_financials(env, &creator, 0, tip, 0);
+            Self::record_financials(env, &assignee, tip, 0, 0);
+        }
+    }
 
-        // Refund creator
-        let token_address: Address = env
+    /// Let the assignee claim payment when the creator has not released it in time
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the assignee
+    /// * `task_id` - ID of the completed task
+    pub fn claim_after_timeout(env: Env, assignee: Address, task_id: u64) {
+        assignee.require_auth();
+
+        let task = Self::get_task(env.clone(), task_id);
+
+        // Check if caller is the assignee
+        Self::require_assignee(&assignee, &task);
+
+        // Check if task is awaiting release
+        Self::require_valid_state(&task, &[TaskStatus::Completed]);
+
+        // Check if the creator has had long enough to act
+        let completed_at = task.completed_at.expect("Task has no completion time");
+        let timeout = Self::get_release_timeout(env.clone());
+        if env.ledger().timestamp() < completed_at.saturating_add(timeout) {
+            panic!("Release timeout has not elapsed");
+        }
+
+        // Pay the assignee and accrue the platform fee
+        Self::release_task_funds(&env, task);
+    }
+
+    /// Set how long the creator has to release funds before the assignee may claim them
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `timeout` - Window in seconds after completion
+    pub fn set_release_timeout(env: Env, deployer: Address, timeout: u64) {
+        Self::require_deployer(&env, &deployer);
+
+        env.storage().instance().set(&DataKey::ReleaseTimeout, &timeout);
+    }
+
+    /// Set the maximum number of active tasks a single assignee may hold
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `cap` - Maximum `Assigned`/`InProgress` tasks per assignee (0 for unlimited)
+    pub fn set_max_active_assignments(env: Env, deployer: Address, cap: u32) {
+        Self::require_deployer(&env, &deployer);
+
+        env.storage().instance().set(&DataKey::MaxActive, &cap);
+    }
+
+    /// Set the minimum time between creation and a new task's deadline (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `duration` - Minimum task duration in seconds (0 to disable)
+    pub fn set_min_task_duration(env: Env, deployer: Address, duration: u64) {
+        Self::require_deployer(&env, &deployer);
+
+        env.storage().instance().set(&DataKey::MinDuration, &duration);
+    }
+
+    /// Get the minimum time between creation and a new task's deadline
+    ///
+    /// # Returns
+    /// The minimum task duration in seconds, or 0 if disabled
+    pub fn get_min_task_duration(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::MinDuration)
+            .unwrap_or(DEFAULT_MIN_TASK_DURATION)
+    }
+
+    /// Set the minimum time between starting and completing a task (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `min_work_time` - Minimum work time in seconds (0 to disable)
+    pub fn set_min_work_time(env: Env, deployer: Address, min_work_time: u64) {
+        Self::require_deployer(&env, &deployer);
+
+        env.storage().instance().set(&DataKey::MinWorkTime, &min_work_time);
+    }
+
+    /// Get the minimum time between starting and completing a task
+    ///
+    /// # Returns
+    /// The minimum work time in seconds, or 0 if disabled
+    pub fn get_min_work_time(env: Env) -> u64 {
+        env.storage().instance().get(&DataKey::MinWorkTime).unwrap_or(0u64)
+    }
+
+    /// Set the platform fee rate for new tasks of a priority level (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `priority` - Priority level the rate applies to
+    /// * `bps` - Fee rate in basis points (300 = 3%)
+    pub fn set_priority_fee_bps(env: Env, deployer: Address, priority: u32, bps: u32) {
+        Self::require_deployer(&env, &deployer);
+
+        if priority > MAX_TASK_PRIORITY {
+            panic!("Invalid priority");
+        }
+        if bps > BPS_DENOMINATOR {
+            panic!("Fee rate cannot exceed 100%");
+        }
+
+        let mut schedule: Map<u32, u32> = env
             .storage()
             .instance()
-            .get(&TOKEN)
-            .expect("Token not initialized");
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &creator,
-            &task.funding_amount,
-        );
+            .get(&DataKey::PriorityFees)
+            .unwrap_or(Map::new(&env));
+        schedule.set(priority, bps);
+        env.storage().instance().set(&DataKey::PriorityFees, &schedule);
     }
 
-    /// Handle expired tasks - mark as expired
+    /// Get the platform fee rate applied to new tasks of a priority level
     ///
     /// # Arguments
-    /// * `task_id` - ID of the expired task
-    pub fn mark_expired(env: Env, task_id: u64) {
+    /// * `priority` - Priority level
+    ///
+    /// # Returns
+    /// The configured fee rate in basis points, or the default 3% rate
+    pub fn get_priority_fee_bps(env: Env, priority: u32) -> u32 {
+        let schedule: Map<u32, u32> = env
+            .storage()
+            .instance()
+            .get(&DataKey::PriorityFees)
+            .unwrap_or(Map::new(&env));
+        schedule.get(priority).unwrap_or(DEFAULT_FEE_BPS)
+    }
+
+    /// Effective fee rate for every priority level, indexed by priority
+    fn get_priority_fee_schedule(env: &Env) -> Vec<u32> {
+        let mut schedule = Vec::new(env);
+        for priority in 0..=MAX_TASK_PRIORITY {
+            schedule.push_back(Self::get_priority_fee_bps(env.clone(), priority));
+        }
+        schedule
+    }
+
+    /// Set the smallest platform fee taken on a payout (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `min_fee` - Fee floor in token units, capped at the payout itself (0 to disable)
+    pub fn set_min_fee(env: Env, deployer: Address, min_fee: i128) {
+        Self::require_deployer(&env, &deployer);
+
+        if min_fee < 0 {
+            panic!("Minimum fee cannot be negative");
+        }
+
+        env.storage().instance().set(&DataKey::MinFee, &min_fee);
+    }
+
+    /// Get the smallest platform fee taken on a payout
+    ///
+    /// # Returns
+    /// The fee floor in token units, or 0 if disabled
+    pub fn get_min_fee(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::MinFee).unwrap_or(0i128)
+    }
+
+    /// Set whether new tasks must reference a GitHub link (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `required` - Whether creating a task without a link is rejected
+    pub fn set_require_github_link(env: Env, deployer: Address, required: bool) {
+        Self::require_deployer(&env, &deployer);
+
+        env.storage().instance().set(&DataKey::RequireLink, &required);
+    }
+
+    /// Get whether new tasks must reference a GitHub link
+    ///
+    /// # Returns
+    /// True if task creation requires a GitHub link
+    pub fn get_require_github_link(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::RequireLink).unwrap_or(false)
+    }
+
+    /// Set whether the deployer is barred from being assigned tasks (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `blocked` - Whether assigning or reassigning a task to the deployer is rejected
+    pub fn set_block_deployer_assignee(env: Env, deployer: Address, blocked: bool) {
+        Self::require_deployer(&env, &deployer);
+
+        env.storage().instance().set(&DataKey::BlockDeployer, &blocked);
+    }
+
+    /// Get whether the deployer is barred from being assigned tasks
+    ///
+    /// # Returns
+    /// True if tasks cannot be assigned to the deployer
+    pub fn get_block_deployer_assignee(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::BlockDeployer).unwrap_or(false)
+    }
+
+    /// Set whether marking tasks expired is limited to participants (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `restricted` - Whether only the creator, assignee or deployer may expire a task
+    pub fn set_restrict_expiry(env: Env, deployer: Address, restricted: bool) {
+        Self::require_deployer(&env, &deployer);
+
+        env.storage().instance().set(&DataKey::RestrictExpiry, &restricted);
+    }
+
+    /// Get whether marking tasks expired is restricted to participants
+    ///
+    /// # Returns
+    /// True if anonymous expiry is disabled
+    pub fn get_restrict_expiry(env: Env) -> bool {
+        env.storage().instance().get(&DataKey::RestrictExpiry).unwrap_or(false)
+    }
+
+    /// Switch the payment token used by new tasks (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `new_token` - Address of the new token contract
+    pub fn set_token(env: Env, deployer: Address, new_token: Address) {
+        Self::require_deployer(&env, &deployer);
+
+        // Balances in the current token must be settled before switching
+        if Self::locked_escrow(&env) > 0 {
+            panic!("Outstanding escrows in current token");
+        }
+        if Self::get_platform_fees(env.clone()) > 0 {
+            panic!("Unwithdrawn platform fees in current token");
+        }
+
+        env.storage().instance().set(&DataKey::Token, &new_token);
+    }
+
+    /// Set the yield vault that holds idle escrow (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `vault` - Vault contract for new task escrows, or None to keep escrow in the contract
+    pub fn set_vault(env: Env, deployer: Address, vault: Option<Address>) {
+        Self::require_deployer(&env, &deployer);
+
+        // Deposits must be withdrawn from the current vault before switching
+        let vaulted: i128 = env.storage().instance().get(&DataKey::VaultBalance).unwrap_or(0);
+        if vaulted > 0 {
+            panic!("Outstanding deposits in current vault");
+        }
+
+        match vault {
+            Some(vault) => env.storage().instance().set(&DataKey::Vault, &vault),
+            None => env.storage().instance().remove(&DataKey::Vault),
+        }
+    }
+
+    /// Give up on a vault that cannot return its deposits (only deployer can call)
+    ///
+    /// The deployer must first make good the lost deposits by transferring tokens to this
+    /// contract; the vault is then forgotten and every task's escrow is held here again.
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    pub fn write_off_vault(env: Env, deployer: Address) {
+        Self::require_deployer(&env, &deployer);
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        for (task_id, mut task) in tasks.clone().iter() {
+            if task.vault_deposit != 0 {
+                task.vault_deposit = 0;
+                tasks.set(task_id, task);
+            }
+        }
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        env.storage().instance().remove(&DataKey::VaultBalance);
+        env.storage().instance().remove(&DataKey::Vault);
+
+        // The contract's own balance must now cover everything it owes
+        let (_, _, solvent) = Self::check_solvency(env.clone());
+        if !solvent {
+            panic!("Vault deposits not covered");
+        }
+    }
+
+    /// Get the yield vault that holds idle escrow
+    ///
+    /// # Returns
+    /// The vault address, or None if escrow stays in the contract
+    pub fn get_vault(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Vault)
+    }
+
+    /// Sweep each release's platform fee straight to a treasury (only deployer can call)
+    ///
+    /// While set, release fees bypass the accumulator; cancellation and dispute fees still
+    /// accrue for manual withdrawal. Since the treasury receives fees without a withdrawal,
+    /// changing it needs signers to have approved a request naming the new treasury.
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `treasury` - Address receiving release fees, or None to accumulate them
+    pub fn set_auto_sweep_fees(env: Env, deployer: Address, treasury: Option<Address>) {
+        Self::require_deployer(&env, &deployer);
+
+        // Check and clear signer approvals when multi-sig is configured
+        Self::consume_withdrawal_approvals(&env, &WithdrawalAction::AutoSweep(treasury.clone()));
+
+        match treasury {
+            Some(treasury) => env.storage().instance().set(&DataKey::FeeTreasury, &treasury),
+            None => env.storage().instance().remove(&DataKey::FeeTreasury),
+        }
+    }
+
+    /// Get the treasury release fees are swept to
+    ///
+    /// # Returns
+    /// The treasury address, or None if fees accumulate for manual withdrawal
+    pub fn get_auto_sweep_fees(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::FeeTreasury)
+    }
+
+    /// Charge release fees in a separate token pulled from the creator (only deployer can call)
+    ///
+    /// While set, releases pay the assignee the whole escrow and charge the fee to the creator in
+    /// the fee token. A release the creator signs collects the fee at once; timeout claims and
+    /// auto-releases record it as a debt the creator must settle before creating more tasks.
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `fee_token` - Token contract fees are charged in, or None to deduct fees from payouts
+    pub fn set_fee_token(env: Env, deployer: Address, fee_token: Option<Address>) {
+        Self::require_deployer(&env, &deployer);
+
+        if Self::get_fee_token_fees(env.clone()) > 0 {
+            panic!("Unwithdrawn fees in current fee token");
+        }
+        let debts: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeTokenDebts)
+            .unwrap_or(Map::new(&env));
+        if !debts.is_empty() {
+            panic!("Unsettled debts in current fee token");
+        }
+
+        match fee_token {
+            Some(fee_token) => env.storage().instance().set(&DataKey::FeeToken, &fee_token),
+            None => env.storage().instance().remove(&DataKey::FeeToken),
+        }
+    }
+
+    /// Get the token release fees are charged in
+    ///
+    /// # Returns
+    /// The fee token, or None if fees are deducted from payouts
+    pub fn get_fee_token(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::FeeToken)
+    }
+
+    /// Get the platform fees accrued in the fee token
+    ///
+    /// # Returns
+    /// The unwithdrawn fee-token balance owed to the platform
+    pub fn get_fee_token_fees(env: Env) -> i128 {
+        env.storage().instance().get(&DataKey::FeeTokenFees).unwrap_or(0i128)
+    }
+
+    /// Get the fee-token fees a creator owes for releases they did not sign
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    ///
+    /// # Returns
+    /// The unsettled fee-token amount, or 0 if nothing is owed
+    pub fn get_fee_token_debt(env: Env, creator: Address) -> i128 {
+        let debts: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeTokenDebts)
+            .unwrap_or(Map::new(&env));
+        debts.get(creator).unwrap_or(0)
+    }
+
+    /// Pay the fee-token fees owed for timeout claims and auto-releases
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator settling their debt
+    pub fn settle_fee_token_debt(env: Env, creator: Address) {
+        creator.require_auth();
+
+        if Self::get_fee_token_debt(env.clone(), creator.clone()) <= 0 {
+            panic!("No fee token debt to settle");
+        }
+
+        Self::collect_fee_token_debt(&env, &creator);
+    }
+
+    /// Withdraw the platform fees accrued in the fee token (only deployer can call)
+    ///
+    /// With multi-sig configured, signers must have approved a request to pay the whole
+    /// fee-token balance to the deployer.
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    pub fn withdraw_fee_token_fees(env: Env, deployer: Address) {
+        Self::require_deployer(&env, &deployer);
+
+        let fees = Self::get_fee_token_fees(env.clone());
+        if fees <= 0 {
+            panic!("No platform fees to withdraw");
+        }
+
+        // Check and clear signer approvals when multi-sig is configured
+        let action = WithdrawalAction::FeeTokenFees(deployer.clone(), fees);
+        Self::consume_withdrawal_approvals(&env, &action);
+        let fee_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeToken)
+            .expect("Fee token not configured");
+
+        env.storage().instance().set(&DataKey::FeeTokenFees, &0i128);
+
+        let fee_client = token::Client::new(&env, &fee_token);
+        fee_client.transfer(&env.current_contract_address(), &deployer, &fees);
+    }
+
+    /// Set the fee charged on creator cancellations (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `bps` - Cancellation fee in basis points of the escrow (0 for free cancellation)
+    pub fn set_cancel_fee_bps(env: Env, deployer: Address, bps: u32) {
+        Self::require_deployer(&env, &deployer);
+
+        if bps > BPS_DENOMINATOR {
+            panic!("Fee rate cannot exceed 100%");
+        }
+
+        env.storage().instance().set(&DataKey::CancelFeeBps, &bps);
+    }
+
+    /// Get the fee charged on creator cancellations
+    ///
+    /// # Returns
+    /// The cancellation fee in basis points, or 0 if cancellation is free
+    pub fn get_cancel_fee_bps(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::CancelFeeBps).unwrap_or(0u32)
+    }
+
+    /// Get the maximum number of active tasks a single assignee may hold
+    ///
+    /// # Returns
+    /// The per-assignee cap, or 0 if unlimited
+    pub fn get_max_active_assignments(env: Env) -> u32 {
+        env.storage().instance().get(&DataKey::MaxActive).unwrap_or(0u32)
+    }
+
+    /// Get the window after completion before the assignee may claim payment
+    ///
+    /// # Returns
+    /// The release timeout in seconds
+    pub fn get_release_timeout(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::ReleaseTimeout)
+            .unwrap_or(DEFAULT_RELEASE_TIMEOUT)
+    }
+
+    /// Get every configurable contract parameter in one call
+    ///
+    /// # Returns
+    /// The current contract settings
+    pub fn get_settings(env: Env) -> Settings {
+        let storage = env.storage().instance();
+        Settings {
+            token: storage.get(&DataKey::Token).expect("Token not initialized"),
+            deployer: storage.get(&DataKey::Deployer).expect("Deployer not initialized"),
+            platform_fee_percentage: PLATFORM_FEE_PERCENTAGE,
+            cancel_fee_bps: Self::get_cancel_fee_bps(env.clone()),
+            release_timeout: Self::get_release_timeout(env.clone()),
+            min_task_duration: Self::get_min_task_duration(env.clone()),
+            max_active_assignments: Self::get_max_active_assignments(env.clone()),
+            require_github_link: Self::get_require_github_link(env.clone()),
+            withdrawal_threshold: storage.get(&DataKey::SignThreshold).unwrap_or(0u32),
+            vault: Self::get_vault(env.clone()),
+            min_fee: Self::get_min_fee(env.clone()),
+            block_deployer_assignee: Self::get_block_deployer_assignee(env.clone()),
+            restrict_expiry: Self::get_restrict_expiry(env.clone()),
+            fee_token: Self::get_fee_token(env.clone()),
+            dispute_fee_policy: Self::get_dispute_fee_policy(env.clone()),
+            min_work_time: Self::get_min_work_time(env.clone()),
+            fee_treasury: Self::get_auto_sweep_fees(env.clone()),
+            priority_fee_bps: Self::get_priority_fee_schedule(&env),
+        }
+    }
+
+    /// Preview the outcome of releasing funds for a task without mutating state
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to simulate the release for
+    ///
+    /// # Returns
+    /// The assignee, their payout, the platform fee and the resulting fee accumulator
+    pub fn simulate_release(env: Env, task_id: u64) -> ReleaseOutcome {
+        let task = Self::get_task(env.clone(), task_id);
+        Self::compute_release(&env, &task)
+    }
+
+    /// Break a task's funding down into fee, payout, and escrow components
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// The task's gross funding, platform fee, net payout, and escrow still held
+    pub fn get_task_funding_breakdown(env: Env, task_id: u64) -> FundingBreakdown {
+        let task = Self::get_task(env.clone(), task_id);
+        let platform_fee = Self::calculate_platform_fee(&env, task.funding_amount, task.fee_bps);
+
+        // A fee charged in the fee token leaves the whole funding for the assignee
+        let assignee_payout = if Self::get_fee_token(env.clone()).is_some() {
+            task.funding_amount
+        } else {
+            task.funding_amount - platform_fee
+        };
+        FundingBreakdown {
+            gross_funding: task.funding_amount,
+            platform_fee,
+            assignee_payout,
+            escrow_held: Self::remaining_escrow(&task),
+        }
+    }
+
+    /// Get the amount held in escrow for a task
+    ///
+    /// Summed over all tasks this matches the contract's token balance less accrued platform
+    /// fees, with any escrow deposited in the yield vault held there instead.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// The task's funding minus everything already paid out or refunded
+    pub fn get_task_escrow(env: Env, task_id: u64) -> i128 {
+        Self::remaining_escrow(&Self::get_task(env, task_id))
+    }
+
+    /// Check whether a task with the given inputs could be created, without creating it
+    ///
+    /// # Arguments
+    /// * `title` - Proposed task title
+    /// * `description` - Proposed task description
+    /// * `funding_amount` - Proposed funding amount
+    /// * `deadline` - Proposed deadline timestamp
+    ///
+    /// # Returns
+    /// Ok if creation would pass validation, otherwise the reason it would be rejected
+    pub fn validate_task(
+        env: Env,
+        title: String,
+        description: String,
+        funding_amount: i128,
+        deadline: u64,
+    ) -> Result<(), TaskError> {
+        Self::check_task_creation(&env, &title, &description, funding_amount, deadline)
+    }
+
+    /// Get the token amount a creator must have available to fund a new task
+    ///
+    /// Fees are quoted at the default platform rate, raised to the minimum fee.
+    ///
+    /// # Arguments
+    /// * `funding_amount` - Amount the task is to be funded with
+    /// * `fee_payer` - Whether the assignee or the creator bears the platform fee
+    ///
+    /// # Returns
+    /// The funding amount, plus the platform fee if the creator absorbs it
+    pub fn required_funding(env: Env, funding_amount: i128, fee_payer: FeePayer) -> i128 {
+        if funding_amount <= 0 {
+            panic!("Funding amount must be positive");
+        }
+
+        match fee_payer {
+            FeePayer::Assignee => funding_amount,
+            FeePayer::Creator => {
+                funding_amount + Self::calculate_platform_fee(&env, funding_amount, DEFAULT_FEE_BPS)
+            }
+        }
+    }
+
+    /// Preview a task's payout split under a hypothetical platform fee rate
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to preview
+    /// * `hypothetical_fee_bps` - Fee rate in basis points (300 = 3%)
+    ///
+    /// # Returns
+    /// Tuple of (assignee payout, platform fee) for the funds still in escrow
+    pub fn preview_release_with_fee(
+        env: Env,
+        task_id: u64,
+        hypothetical_fee_bps: u32,
+    ) -> (i128, i128) {
+        if hypothetical_fee_bps > BPS_DENOMINATOR {
+            panic!("Fee rate cannot exceed 100%");
+        }
+
+        let task = Self::get_task(env, task_id);
+        let escrowed = Self::remaining_escrow(&task);
+        let fee = escrowed * hypothetical_fee_bps as i128 / BPS_DENOMINATOR as i128;
+        (escrowed - fee, fee)
+    }
+
+    /// Cancel a task and refund the creator
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task to cancel
+    pub fn cancel_task(env: Env, creator: Address, task_id: u64) {
+        creator.require_auth();
+
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the creator
+        Self::require_creator(&creator, &task);
+
+        // Check if task is in valid state for cancellation
+        Self::require_valid_state(
+            &task,
+            &[TaskStatus::Created, TaskStatus::Assigned, TaskStatus::InProgress],
+        );
+        Self::require_not_disputed(&task);
+
+        Self::cancel_with_refund(&env, task);
+    }
+
+    /// Cancel a creator's assigned and in-progress tasks, refunding each
+    ///
+    /// Cancels at most 50 tasks per call; call again to continue with the rest.
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    ///
+    /// # Returns
+    /// Number of tasks cancelled
+    pub fn cancel_all(env: Env, creator: Address) -> u32 {
+        creator.require_auth();
+
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+
+        let mut cancelled = 0u32;
+        for task_id in Self::get_user_tasks(env.clone(), creator).iter() {
+            if cancelled >= MAX_CANCEL_BATCH {
+                break;
+            }
+            let task = match tasks.get(task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+            let active = matches!(task.status, TaskStatus::Assigned | TaskStatus::InProgress);
+            if !active || task.processing || task.disputed {
+                continue;
+            }
+            Self::cancel_with_refund(&env, task);
+            cancelled += 1;
+        }
+        cancelled
+    }
+
+    /// Cancel a task and re-post it with new parameters, netting the refund against the new funding
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task to cancel
+    /// * `params` - Title, description, link, funding, deadline and assignee of the new task
+    ///
+    /// # Returns
+    /// The ID of the new task
+    pub fn relist_task(env: Env, creator: Address, task_id: u64, params: RelistParams) -> u64 {
+        creator.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the creator
+        Self::require_creator(&creator, &task);
+
+        // Check if task is in valid state for cancellation
+        Self::require_valid_state(
+            &task,
+            &[TaskStatus::Created, TaskStatus::Assigned, TaskStatus::InProgress],
+        );
+        Self::require_not_disputed(&task);
+
+        // Cancel the old task, keeping its refund in the contract
+        let refund_amount = Self::settle_cancellation(&env, &mut task);
+        let refund_to = Self::refund_recipient(&task);
+        let options = TaskOptions {
+            priority: task.priority,
+            auto_release: task.auto_release,
+            depends_on: task.depends_on.clone(),
+            client_ref: None,
+            notify_on_release: task.notify_on_release,
+            oracle: task.oracle.clone(),
+            oracle_fn: task.oracle_fn.clone(),
+            expiry_action: task.expiry_action.clone(),
+            accept_window: task.accept_window,
+        };
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        Self::recall_from_vault(&env, task_id);
+
+        // Fund the new task from the refund, pulling only any shortfall
+        let new_task_id = Self::create_funded_task(
+            &env,
+            creator.clone(),
+            params.title,
+            params.description,
+            params.github_link,
+            params.funding_amount,
+            params.deadline,
+            options,
+            refund_amount.min(params.funding_amount),
+            false,
+        );
+
+        // Return whatever the new task did not absorb
+        let surplus = refund_amount - params.funding_amount;
+        if surplus > 0 {
+            let token_address: Address = env
+                .storage()
+                .instance()
+                .get(&DataKey::Token)
+                .expect("Token not initialized");
+            let token_client = token::Client::new(&env, &token_address);
+            token_client.transfer(&env.current_contract_address(), &refund_to, &surplus);
+        }
+
+        // Link the new task to the one it replaces
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut new_task = tasks
+            .get(new_task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+        new_task.relisted_from = Some(task_id);
+        tasks.set(new_task_id, new_task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+
+        if let Some(assignee) = params.assignee {
+            Self::assign_task_to(&env, &creator, new_task_id, assignee);
+        }
+
+        TaskCancelled {
+            task_id,
+            creator: creator.clone(),
+            refund_amount,
+        }
+        .publish(&env);
+        TaskRelisted {
+            task_id,
+            new_task_id,
+            creator,
+        }
+        .publish(&env);
+
+        new_task_id
+    }
+
+    /// Give the assignee notice that the creator intends to cancel a task
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task to cancel
+    pub fn request_cancel(env: Env, creator: Address, task_id: u64) {
+        creator.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the creator
+        Self::require_creator(&creator, &task);
+
+        // Only tasks with an assignee need notice
+        Self::require_valid_state(&task, &[TaskStatus::Assigned, TaskStatus::InProgress]);
+
+        if task.cancel_requested_at.is_some() {
+            panic!("Cancellation already requested");
+        }
+        task.cancel_requested_at = Some(env.ledger().timestamp());
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
+
+    /// Cancel a task and refund the creator once the notice period has passed
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task to cancel
+    pub fn finalize_cancel(env: Env, creator: Address, task_id: u64) {
+        creator.require_auth();
+
+        let task = Self::get_task(env.clone(), task_id);
+
+        // Check if caller is the creator
+        Self::require_creator(&creator, &task);
+
+        // Check the assignee has not salvaged the task in the meantime
+        Self::require_valid_state(&task, &[TaskStatus::Assigned, TaskStatus::InProgress]);
+        Self::require_not_disputed(&task);
+
+        let requested_at = task
+            .cancel_requested_at
+            .unwrap_or_else(|| panic!("Cancellation not requested"));
+        if env.ledger().timestamp() < requested_at.saturating_add(CANCEL_NOTICE_PERIOD) {
+            panic!("Cancellation notice period has not elapsed");
+        }
+
+        Self::cancel_with_refund(&env, task);
+    }
+
+    /// Give up an assigned task and refund the creator in full
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the task assignee
+    /// * `task_id` - ID of the task to abandon
+    pub fn assignee_cancel(env: Env, assignee: Address, task_id: u64) {
+        assignee.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the assignee
+        Self::require_assignee(&assignee, &task);
+
+        // Check if task is in valid state for cancellation
+        Self::require_valid_state(&task, &[TaskStatus::Assigned, TaskStatus::InProgress]);
+        Self::require_not_disputed(&task);
+
+        // Only refund what is still held in escrow for this task
+        let refund_amount = Self::remaining_escrow(&task);
+
+        // Update task status, remembering who gave up
+        task.status = TaskStatus::Cancelled;
+        task.abandoned = true;
+        task.released_so_far += refund_amount;
+        Self::record_financials(&env, &task.creator, 0, 0, -refund_amount);
+        Self::record_reputation(&env, &assignee, 0, 1);
+        Self::update_stats(&env, |stats| {
+            stats.active_tasks = stats.active_tasks.saturating_sub(1);
+            stats.total_refunded += refund_amount;
+        });
+
+        // Lock the task until the transfer completes
+        task.processing = true;
+
+        // Store updated task before refund
+        tasks.set(task_id, task.clone());
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        Self::recall_from_vault(&env, task_id);
+
+        // Refund creator
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &Self::refund_recipient(&task),
+            &refund_amount,
+        );
+        Self::finish_processing(&env, task_id);
+
+        TaskAbandoned {
+            task_id,
+            assignee,
+            refund_amount,
+        }
+        .publish(&env);
+    }
+
+    /// Handle expired tasks - mark as expired
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the expired task
+    pub fn mark_expired(env: Env, task_id: u64) {
+        if Self::get_restrict_expiry(env.clone()) {
+            panic!("Expiry is restricted to task participants");
+        }
+        Self::expire_task(&env, task_id);
+    }
+
+    /// Mark a task as expired on behalf of an identified caller
+    ///
+    /// # Arguments
+    /// * `caller` - Address marking the task; must be a participant when expiry is restricted
+    /// * `task_id` - ID of the expired task
+    pub fn mark_expired_by(env: Env, caller: Address, task_id: u64) {
+        caller.require_auth();
+
+        Self::require_expiry_caller(&env, &caller, task_id);
+        Self::expire_task(&env, task_id);
+    }
+
+    /// Check the caller may expire the task, which only matters when expiry is restricted
+    fn require_expiry_caller(env: &Env, caller: &Address, task_id: u64) {
+        if !Self::get_restrict_expiry(env.clone()) {
+            return;
+        }
+        let task = Self::get_task(env.clone(), task_id);
+        let deployer: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Deployer)
+            .expect("Deployer not initialized");
+        let participant = *caller == task.creator || Some(caller.clone()) == task.assignee;
+        if !participant && *caller != deployer {
+            panic!("Expiry is restricted to task participants");
+        }
+    }
+
+    /// Mark an overdue task as expired
+    fn expire_task(env: &Env, task_id: u64) {
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if task is actually expired
+        if env.ledger().timestamp() <= task.deadline {
+            panic!("Task is not expired");
+        }
+
+        // Check if task is in valid state for expiration handling
+        Self::require_valid_state(
+            &task,
+            &[
+                TaskStatus::Created,
+                TaskStatus::Assigned,
+                TaskStatus::InProgress,
+            ],
+        );
+        Self::require_not_disputed(&task);
+
+        // Mark as expired, then carry out the creator's chosen follow-up
+        Self::apply_expiry_action(env, &mut task);
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
+
+    /// Expire a task according to its expiry action
+    ///
+    /// Reassigned and reopened tasks get a fresh deadline of the same length as the original.
+    fn apply_expiry_action(env: &Env, task: &mut Task) {
+        task.status = TaskStatus::Expired;
+        task.accept_by = None;
+
+        let new_assignee = match &task.expiry_action {
+            ExpiryAction::Refund => return,
+            ExpiryAction::AutoReassignToBackup(backup) => {
+                // Nothing to hand over if the backup already holds the task
+                if task.assignee == Some(backup.clone()) {
+                    return;
+                }
+                // A backup at capacity cannot take the task, so it stays expired for the
+                // creator to reclaim or reassign
+                if !Self::has_assignee_capacity(env, backup) {
+                    return;
+                }
+                Some(backup.clone())
+            }
+            ExpiryAction::ReopenToPool => None,
+        };
+
+        if let Some(old_assignee) = task.assignee.clone() {
+            task.previous_assignees.push_back(old_assignee.clone());
+            Self::remove_from_index(env, &DataKey::AssignedTasks(old_assignee), task.id);
+        }
+        if let Some(new_assignee) = &new_assignee {
+            Self::add_to_index(env, &DataKey::AssignedTasks(new_assignee.clone()), task.id);
+        }
+
+        // Restart the task with a fresh deadline
+        let duration = task.deadline.saturating_sub(task.created_at);
+        task.status = if new_assignee.is_some() {
+            TaskStatus::Assigned
+        } else {
+            TaskStatus::Created
+        };
+        task.open_pool = new_assignee.is_none();
+        task.assignee = new_assignee;
+        task.payout_address = None;
+        task.reminded = false;
+        task.cancel_requested_at = None;
+        task.assignee_approved = false;
+        task.creator_approved = false;
+        task.completed_at = None;
+        task.started_at = None;
+        task.deadline = env.ledger().timestamp() + duration;
+        task.accept_by = if task.assignee.is_some() {
+            Self::accept_deadline(env, task)
+        } else {
+            None
+        };
+    }
+
+    /// Time by which a task assigned now must be started, if it has an acceptance window
+    fn accept_deadline(env: &Env, task: &Task) -> Option<u64> {
+        if task.accept_window == 0 {
+            return None;
+        }
+        Some(env.ledger().timestamp() + task.accept_window)
+    }
+
+    /// Lapse an assignment the assignee did not start within the acceptance window
+    ///
+    /// The task is expired and its expiry action carried out, so a refunding task can be
+    /// reclaimed or reassigned by the creator before the task deadline.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the unaccepted task
+    pub fn mark_unaccepted(env: Env, task_id: u64) {
+        if Self::get_restrict_expiry(env.clone()) {
+            panic!("Expiry is restricted to task participants");
+        }
+        Self::lapse_assignment(&env, task_id);
+    }
+
+    /// Lapse an unaccepted assignment on behalf of an identified caller
+    ///
+    /// # Arguments
+    /// * `caller` - Address lapsing the task; must be a participant when expiry is restricted
+    /// * `task_id` - ID of the unaccepted task
+    pub fn mark_unaccepted_by(env: Env, caller: Address, task_id: u64) {
+        caller.require_auth();
+
+        Self::require_expiry_caller(&env, &caller, task_id);
+        Self::lapse_assignment(&env, task_id);
+    }
+
+    /// Expire an assignment whose acceptance window has passed
+    fn lapse_assignment(env: &Env, task_id: u64) {
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Only assignments that were never started can lapse
+        Self::require_valid_state(&task, &[TaskStatus::Assigned]);
+        Self::require_not_processing(&task);
+        Self::require_not_disputed(&task);
+        match task.accept_by {
+            Some(accept_by) if env.ledger().timestamp() > accept_by => {}
+            _ => panic!("Acceptance window has not passed"),
+        }
+
+        Self::apply_expiry_action(env, &mut task);
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
+
+    /// Mark several overdue tasks as expired in one call, skipping any that are not eligible
+    ///
+    /// # Arguments
+    /// * `task_ids` - IDs of the tasks to expire (at most `MAX_KEEPER_BATCH`)
+    ///
+    /// # Returns
+    /// IDs of the tasks that were actually marked expired
+    pub fn mark_expired_batch(env: Env, task_ids: Vec<u64>) -> Vec<u64> {
+        if Self::get_restrict_expiry(env.clone()) {
+            panic!("Expiry is restricted to task participants");
+        }
+        if task_ids.len() > MAX_KEEPER_BATCH {
+            panic!("Batch too large");
+        }
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let now = env.ledger().timestamp();
+
+        let mut expired = Vec::new(&env);
+        for task_id in task_ids.iter() {
+            let mut task = match tasks.get(task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+            let active = [TaskStatus::Created, TaskStatus::Assigned, TaskStatus::InProgress]
+                .contains(&task.status);
+            if !active || task.processing || task.disputed || now <= task.deadline {
+                continue;
+            }
+
+            Self::apply_expiry_action(&env, &mut task);
+            tasks.set(task_id, task);
+            expired.push_back(task_id);
+        }
+
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        expired
+    }
+
+    /// Reclaim funds from expired task
+    ///
+    /// Anyone may reclaim a task once it is marked expired, or an overdue task that was never
+    /// started while expiry is unrestricted; otherwise the creator must authorize the reclaim.
+    /// Since the caller may be anyone, the refund always goes to the creator.
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the expired task
+    pub fn reclaim_expired_funds(env: Env, task_id: u64) {
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+        let creator = task.creator.clone();
+
+        // Check if task is expired, either marked or implicitly past its deadline
+        let overdue = env.ledger().timestamp() > task.deadline
+            && [TaskStatus::Created, TaskStatus::Assigned, TaskStatus::InProgress]
+                .contains(&task.status);
+        if task.status != TaskStatus::Expired && !overdue {
+            panic!("Task must be expired to reclaim funds");
+        }
+
+        // Skipping the mark would skip the task's expiry action, so only refunds may do so
+        if overdue && task.expiry_action != ExpiryAction::Refund {
+            panic!("Task must be marked expired to apply its expiry action");
+        }
+
+        // Cutting short work in progress, or bypassing restricted expiry, is the creator's call
+        if overdue
+            && (task.status == TaskStatus::InProgress || Self::get_restrict_expiry(env.clone()))
+        {
+            creator.require_auth();
+        }
+        Self::require_not_processing(&task);
+        Self::require_not_disputed(&task);
+
+        // Only refund what is still held in escrow for this task
+        let refund_amount = Self::remaining_escrow(&task);
+
+        // Update task status to cancelled, remembering it timed out
+        task.status = TaskStatus::Cancelled;
+        task.was_expired = true;
+        task.released_so_far += refund_amount;
+        Self::record_financials(&env, &creator, 0, 0, -refund_amount);
+        Self::update_stats(&env, |stats| {
+            stats.active_tasks = stats.active_tasks.saturating_sub(1);
+            stats.total_refunded += refund_amount;
+        });
+
+        // Lock the task until the transfer completes
+        task.processing = true;
+
+        // Store updated task
+        tasks.set(task_id, task.clone());
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        Self::recall_from_vault(&env, task_id);
+
+        // Refund creator
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &creator, &refund_amount);
+        Self::finish_processing(&env, task_id);
+
+        TaskExpiredRefunded {
+            task_id,
+            creator,
+            refund_amount,
+        }
+        .publish(&env);
+    }
+
+    /// Withdraw accumulated platform fees (only deployer can call)
+    ///
+    /// With multi-sig configured, signers must have approved a request to pay the whole
+    /// accumulated balance to the deployer.
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    pub fn withdraw_platform_fees(env: Env, deployer: Address) {
+        deployer.require_auth();
+
+        // Verify caller is the deployer
+        let stored_deployer: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Deployer)
+            .expect("Deployer not initialized");
+        
+        if stored_deployer != deployer {
+            panic!("Only deployer can withdraw platform fees");
+        }
+
+        // Get accumulated fees
+        let accumulated_fees: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PlatformFees)
+            .unwrap_or(0i128);
+
+        if accumulated_fees <= 0 {
+            panic!("No platform fees to withdraw");
+        }
+
+        // Check and clear signer approvals when multi-sig is configured
+        let action = WithdrawalAction::PlatformFees(deployer.clone(), accumulated_fees);
+        Self::consume_withdrawal_approvals(&env, &action);
+
+        // Reset platform fees accumulator
+        env.storage().instance().set(&DataKey::PlatformFees, &0i128);
+        Self::update_stats(&env, |stats| stats.total_fees_withdrawn += accumulated_fees);
+
+        // Transfer fees to deployer
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &deployer,
+            &accumulated_fees,
+        );
+    }
+
+    /// Withdraw part of the accumulated platform fees to a chosen recipient
+    ///
+    /// With multi-sig configured, signers must have approved a request for this recipient
+    /// and amount, so approvals given for one withdrawal cannot be spent on another.
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `recipient` - Address receiving the withdrawn fees
+    /// * `amount` - Amount of fees to withdraw
+    pub fn withdraw_platform_fees_to(
+        env: Env,
+        deployer: Address,
+        recipient: Address,
+        amount: i128,
+    ) {
+        Self::require_deployer(&env, &deployer);
+
+        if amount <= 0 {
+            panic!("Withdrawal amount must be positive");
+        }
+
+        // Validate against accumulated fees
+        let accumulated_fees: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PlatformFees)
+            .unwrap_or(0i128);
+        if amount > accumulated_fees {
+            panic!("Insufficient platform fees");
+        }
+
+        // Check and clear signer approvals when multi-sig is configured
+        let action = WithdrawalAction::PlatformFees(recipient.clone(), amount);
+        Self::consume_withdrawal_approvals(&env, &action);
+
+        // Decrement platform fees accumulator
+        env.storage()
+            .instance()
+            .set(&DataKey::PlatformFees, &(accumulated_fees - amount));
+        Self::update_stats(&env, |stats| stats.total_fees_withdrawn += amount);
+
+        // Transfer fees to recipient
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+    }
+
+    /// Recover tokens held outside of escrow and fee accounting (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `amount` - Amount to recover, at most the balance above all obligations
+    pub fn emergency_withdraw(env: Env, deployer: Address, amount: i128) {
+        Self::require_deployer(&env, &deployer);
+
+        if amount <= 0 {
+            panic!("Withdrawal amount must be positive");
+        }
+
+        // Never touch escrowed funds or unwithdrawn fees
+        let (held, obligations, _) = Self::check_solvency(env.clone());
+        if amount > held - obligations {
+            panic!("Amount exceeds recoverable balance");
+        }
+
+        // Transfer surplus to deployer
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(&env, &token_address);
+        token_client.transfer(&env.current_contract_address(), &deployer, &amount);
+    }
+
+    /// Propose a fee outflow for the signers to approve (only deployer can call)
+    ///
+    /// The outflow can only be carried out once enough signers approve this exact request.
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `action` - Withdrawal or treasury change to approve
+    ///
+    /// # Returns
+    /// ID of the new withdrawal request
+    pub fn propose_withdrawal(env: Env, deployer: Address, action: WithdrawalAction) -> u64 {
+        Self::require_deployer(&env, &deployer);
+
+        let request_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::WithdrawCounter)
+            .unwrap_or(1u64);
+        let mut requests: Map<u64, WithdrawalRequest> = env
+            .storage()
+            .instance()
+            .get(&DataKey::WithdrawRequests)
+            .unwrap_or(Map::new(&env));
+        requests.set(
+            request_id,
+            WithdrawalRequest {
+                id: request_id,
+                action,
+                approvals: Vec::new(&env),
+            },
+        );
+        env.storage().instance().set(&DataKey::WithdrawRequests, &requests);
+        env.storage()
+            .instance()
+            .set(&DataKey::WithdrawCounter, &(request_id + 1));
+
+        request_id
+    }
+
+    /// Approve a pending fee withdrawal request as one of the configured signers
+    ///
+    /// # Arguments
+    /// * `signer` - Address of the approving signer
+    /// * `request_id` - ID of the withdrawal request
+    pub fn approve_withdrawal(env: Env, signer: Address, request_id: u64) {
+        signer.require_auth();
+
+        let signers: Vec<Address> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeSigners)
+            .unwrap_or(Vec::new(&env));
+        if !signers.contains(&signer) {
+            panic!("Not an authorized signer");
+        }
+
+        let mut requests: Map<u64, WithdrawalRequest> = env
+            .storage()
+            .instance()
+            .get(&DataKey::WithdrawRequests)
+            .unwrap_or(Map::new(&env));
+        let mut request = requests
+            .get(request_id)
+            .unwrap_or_else(|| panic!("Withdrawal request not found"));
+        if request.approvals.contains(&signer) {
+            panic!("Signer already approved");
+        }
+
+        request.approvals.push_back(signer);
+        requests.set(request_id, request);
+        env.storage().instance().set(&DataKey::WithdrawRequests, &requests);
+    }
+
+    /// Drop a pending fee withdrawal request (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `request_id` - ID of the withdrawal request
+    pub fn cancel_withdrawal(env: Env, deployer: Address, request_id: u64) {
+        Self::require_deployer(&env, &deployer);
+
+        let mut requests: Map<u64, WithdrawalRequest> = env
+            .storage()
+            .instance()
+            .get(&DataKey::WithdrawRequests)
+            .unwrap_or(Map::new(&env));
+        if !requests.contains_key(request_id) {
+            panic!("Withdrawal request not found");
+        }
+
+        requests.remove(request_id);
+        env.storage().instance().set(&DataKey::WithdrawRequests, &requests);
+    }
+
+    /// Get a pending fee withdrawal request
+    ///
+    /// # Arguments
+    /// * `request_id` - ID of the withdrawal request
+    ///
+    /// # Returns
+    /// The request and its approvals, or None if it was carried out, cancelled or never made
+    pub fn get_withdrawal_request(env: Env, request_id: u64) -> Option<WithdrawalRequest> {
+        let requests: Map<u64, WithdrawalRequest> = env
+            .storage()
+            .instance()
+            .get(&DataKey::WithdrawRequests)
+            .unwrap_or(Map::new(&env));
+        requests.get(request_id)
+    }
+
+    /// Flag a task as disputed, freezing its funds until an arbiter resolves it
+    ///
+    /// # Arguments
+    /// * `caller` - Address of the task creator or assignee
+    /// * `task_id` - ID of the task to dispute
+    pub fn raise_dispute(env: Env, caller: Address, task_id: u64) {
+        caller.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is a party to the task
+        if caller != task.creator && task.assignee != Some(caller.clone()) {
+            panic!("Only task creator or assignee can raise a dispute");
+        }
+
+        // Check if task is in valid state for a dispute
+        Self::require_valid_state(
+            &task,
+            &[TaskStatus::Assigned, TaskStatus::InProgress, TaskStatus::Completed],
+        );
+
+        if task.disputed {
+            panic!("Task is already disputed");
+        }
+        task.disputed = true;
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+
+        DisputeRaised {
+            task_id,
+            raised_by: caller,
+        }
+        .publish(&env);
+    }
+
+    /// Force-resolve a stuck task (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `task_id` - ID of the task to resolve
+    /// * `outcome` - Pay the assignee, refund the creator, or split between them
+    pub fn admin_resolve(env: Env, deployer: Address, task_id: u64, outcome: AdminOutcome) {
+        Self::require_deployer(&env, &deployer);
+
+        Self::resolve_escrow(&env, deployer, task_id, outcome, true);
+    }
+
+    /// Settle a disputed task in favor of the assignee or the creator (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `task_id` - ID of the disputed task
+    /// * `pay_assignee` - True to release the escrow to the assignee, false to refund the creator
+    pub fn resolve_dispute(env: Env, deployer: Address, task_id: u64, pay_assignee: bool) {
+        Self::require_deployer(&env, &deployer);
+
+        if !Self::get_task(env.clone(), task_id).disputed {
+            panic!("Task is not disputed");
+        }
+
+        let outcome = if pay_assignee {
+            AdminOutcome::PayAssignee
+        } else {
+            AdminOutcome::RefundCreator
+        };
+        let charge_fee =
+            Self::get_dispute_fee_policy(env.clone()) == DisputeFeePolicy::FeeOnDisputedPayout;
+        Self::resolve_escrow(&env, deployer, task_id, outcome, charge_fee);
+    }
+
+    /// Set whether resolving a dispute in the assignee's favor takes a platform fee
+    /// (only deployer can call)
+    ///
+    /// # Arguments
+    /// * `deployer` - Address of the contract deployer
+    /// * `policy` - Fee policy applied by `resolve_dispute`
+    pub fn set_dispute_fee_policy(env: Env, deployer: Address, policy: DisputeFeePolicy) {
+        Self::require_deployer(&env, &deployer);
+
+        env.storage().instance().set(&DataKey::DisputeFee, &policy);
+    }
+
+    /// Get the fee policy applied when resolving disputes
+    ///
+    /// # Returns
+    /// The configured policy, `NoFeeOnRefund` by default
+    pub fn get_dispute_fee_policy(env: Env) -> DisputeFeePolicy {
+        env.storage()
+            .instance()
+            .get(&DataKey::DisputeFee)
+            .unwrap_or(DisputeFeePolicy::NoFeeOnRefund)
+    }
+
+    /// Get current accumulated platform fees
+    ///
+    /// # Returns
+    /// The total amount of accumulated platform fees
+    pub fn get_platform_fees(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::PlatformFees)
+            .unwrap_or(0i128)
+    }
+
+    /// Check whether the deployer could withdraw platform fees right now
+    ///
+    /// # Returns
+    /// True if fees have accrued and the contract holds enough to pay them out while solvent
+    pub fn has_withdrawable_fees(env: Env) -> bool {
+        let fees = Self::get_platform_fees(env.clone());
+        if fees <= 0 {
+            return false;
+        }
+
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(&env, &token_address);
+        let (_, _, solvent) = Self::check_solvency(env.clone());
+        solvent && token_client.balance(&env.current_contract_address()) >= fees
+    }
+
+    /// Compare the contract's token balance against what it owes
+    ///
+    /// Escrow owed is read from the running ledger totals rather than summed over every task.
+    ///
+    /// # Returns
+    /// Tuple of (token balance held, escrowed funds plus unwithdrawn fees,
+    /// whether the balance covers the obligations)
+    pub fn check_solvency(env: Env) -> (i128, i128, bool) {
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(&env, &token_address);
+        let vaulted: i128 = env.storage().instance().get(&DataKey::VaultBalance).unwrap_or(0);
+        let held = token_client.balance(&env.current_contract_address()) + vaulted;
+        let obligations = Self::get_platform_fees(env.clone()) + Self::locked_escrow(&env);
+
+        (held, obligations, held >= obligations)
+    }
+
+    /// Get contract-wide totals for analytics
+    ///
+    /// # Returns
+    /// Task counts, escrowed value and fee totals accumulated since deployment
+    pub fn get_stats(env: Env) -> Stats {
+        env.storage().instance().get(&DataKey::Stats).unwrap_or_default()
+    }
+
+    /// Get the running totals needed to reconcile the contract's books
+    ///
+    /// Total escrowed always equals paid plus refunded plus fees earned plus the escrow still
+    /// locked in open tasks. Fees charged in a separate fee token never come out of escrow, so
+    /// they are reported on their own and left out of that sum.
+    ///
+    /// # Returns
+    /// Cumulative escrow, payout, refund and fee totals since deployment
+    pub fn get_ledger_summary(env: Env) -> LedgerSummary {
+        let stats = Self::get_stats(env);
+        LedgerSummary {
+            total_escrowed: stats.total_escrowed,
+            total_paid: stats.total_paid,
+            total_refunded: stats.total_refunded,
+            total_fees_earned: stats.total_fees_earned,
+            total_fees_withdrawn: stats.total_fees_withdrawn,
+            fee_token_fees: stats.fee_token_fees,
+        }
+    }
+
+    /// Get the totals needed to compute the platform's realized take rate
+    ///
+    /// # Returns
+    /// Cumulative released volume (fees included) and the platform fees taken on it
+    pub fn get_take_rate(env: Env) -> (i128, i128) {
+        let stats = Self::get_stats(env);
+        (stats.released_volume, stats.release_fees)
+    }
+
+    /// Reassign an expired task to a new assignee
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the task to reassign
+    /// * `new_assignee` - Address of the new assignee
+    pub fn reassign_task(env: Env, creator: Address, task_id: u64, new_assignee: Address) {
+        creator.require_auth();
+        Self::reassign_task_to(&env, &creator, task_id, new_assignee, None);
+    }
+
+    /// Reassign all of a creator's expired tasks held by one assignee to another
+    ///
+    /// Reassigns at most `MAX_REASSIGN_BATCH` tasks per call; call again to continue with
+    /// the rest.
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `old_assignee` - Assignee whose expired tasks are handed over
+    /// * `new_assignee` - Address of the new assignee
+    /// * `new_deadline` - Unix timestamp for the new deadline of every reassigned task
+    ///
+    /// # Returns
+    /// IDs of the tasks that were reassigned
+    pub fn reassign_all_expired(
+        env: Env,
+        creator: Address,
+        old_assignee: Address,
+        new_assignee: Address,
+        new_deadline: u64,
+    ) -> Vec<u64> {
+        creator.require_auth();
+
+        Self::validate_deadline(&env, new_deadline);
+
+        let mut reassigned = Vec::new(&env);
+        for task_id in Self::get_assigned_tasks(env.clone(), old_assignee).iter() {
+            if reassigned.len() >= MAX_REASSIGN_BATCH {
+                break;
+            }
+            let task = Self::get_task(env.clone(), task_id);
+            if task.creator != creator || task.status != TaskStatus::Expired || task.disputed {
+                continue;
+            }
+            Self::reassign_task_to(
+                &env,
+                &creator,
+                task_id,
+                new_assignee.clone(),
+                Some(new_deadline),
+            );
+            reassigned.push_back(task_id);
+        }
+        reassigned
+    }
+
+    /// Reassign an expired task and give it a new deadline in one step
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the expired task
+    /// * `new_assignee` - Address of the new assignee
+    /// * `new_deadline` - Unix timestamp for the new task deadline
+    pub fn reassign_and_extend(
+        env: Env,
+        creator: Address,
+        task_id: u64,
+        new_assignee: Address,
+        new_deadline: u64,
+    ) {
+        creator.require_auth();
+
+        Self::validate_deadline(&env, new_deadline);
+
+        Self::reassign_task_to(&env, &creator, task_id, new_assignee, Some(new_deadline));
+    }
+
+    /// Return an expired task to the open pool with a new deadline so any worker can claim it
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `task_id` - ID of the expired task
+    /// * `new_deadline` - Unix timestamp for the new task deadline
+    pub fn reopen_to_pool(env: Env, creator: Address, task_id: u64, new_deadline: u64) {
+        creator.require_auth();
+
+        Self::validate_deadline(&env, new_deadline);
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the creator
+        Self::require_creator(&creator, &task);
+
+        // Check if task is expired
+        if task.status != TaskStatus::Expired {
+            panic!("Task must be expired to reopen");
+        }
+        Self::require_not_processing(&task);
+        Self::require_not_disputed(&task);
+
+        // Release the old assignee, if any
+        if let Some(old_assignee) = task.assignee.clone() {
+            task.previous_assignees.push_back(old_assignee.clone());
+            Self::remove_from_index(&env, &DataKey::AssignedTasks(old_assignee), task_id);
+        }
+
+        // Reset the task to open status
+        task.assignee = None;
+        task.payout_address = None;
+        task.reminded = false;
+        task.cancel_requested_at = None;
+        task.accept_by = None;
+        task.deliverable_hash = None;
+        task.status = TaskStatus::Created;
+        task.assignee_approved = false;
+        task.creator_approved = false;
+        task.completed_at = None;
+        task.started_at = None;
+        task.deadline = new_deadline;
+        task.open_pool = true;
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
+
+    /// Claim a task from the open pool as its assignee
+    ///
+    /// # Arguments
+    /// * `worker` - Address of the worker taking the task
+    /// * `task_id` - ID of the pooled task
+    pub fn claim_task(env: Env, worker: Address, task_id: u64) {
+        worker.require_auth();
+
+        let task = Self::get_task(env.clone(), task_id);
+        if !task.open_pool {
+            panic!("Task is not open for claiming");
+        }
+
+        Self::assign_task_to(&env, &task.creator, task_id, worker);
+    }
+
+    /// Hand the creator role and its refund rights over to another address
+    ///
+    /// # Arguments
+    /// * `current_creator` - Address of the current task creator
+    /// * `task_id` - ID of the task to transfer
+    /// * `new_creator` - Address taking over as creator
+    pub fn transfer_creatorship(
+        env: Env,
+        current_creator: Address,
+        task_id: u64,
+        new_creator: Address,
+    ) {
+        current_creator.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the creator
+        Self::require_creator(&current_creator, &task);
+
+        // Check the task still has funds or work outstanding
+        Self::require_valid_state(
+            &task,
+            &[
+                TaskStatus::Created,
+                TaskStatus::Assigned,
+                TaskStatus::InProgress,
+                TaskStatus::Completed,
+                TaskStatus::Approved,
+                TaskStatus::Expired,
+            ],
+        );
+
+        // Update creator and move the escrow still locked for the task; refunds follow the
+        // new creator rather than a wallet the previous creator chose
+        task.creator = new_creator.clone();
+        task.refund_address = None;
+        let escrowed = Self::remaining_escrow(&task);
+        Self::record_financials(&env, &current_creator, 0, 0, -escrowed);
+        Self::record_financials(&env, &new_creator, 0, 0, escrowed);
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+
+        // Move the task from the old creator's tasks to the new creator's
+        Self::remove_from_index(&env, &DataKey::UserTasks(current_creator), task_id);
+        Self::add_to_index(&env, &DataKey::UserTasks(new_creator), task_id);
+    }
+
+    /// Get task details by ID
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task to retrieve
+    ///
+    /// # Returns
+    /// The task details
+    pub fn get_task(env: Env, task_id: u64) -> Task {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"))
+    }
+
+    /// Get the creator of a task
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// The creator's address
+    pub fn get_task_creator(env: Env, task_id: u64) -> Address {
+        Self::get_task(env, task_id).creator
+    }
+
+    /// Get the assignee of a task
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// The assignee's address, or `None` if the task is unassigned
+    pub fn get_task_assignee(env: Env, task_id: u64) -> Option<Address> {
+        Self::get_task(env, task_id).assignee
+    }
+
+    /// Get the decimals of the token a task is funded in
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// Number of decimals used to display the task's funding amount
+    pub fn get_task_amount_decimals(env: Env, task_id: u64) -> u32 {
+        Self::get_task(env, task_id).token_decimals
+    }
+
+    /// Get the time left until a task's deadline
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// Seconds until the deadline, or 0 if it has passed
+    pub fn get_remaining_time(env: Env, task_id: u64) -> u64 {
+        let task = Self::get_task(env.clone(), task_id);
+        task.deadline.saturating_sub(env.ledger().timestamp())
+    }
+
+    /// Report which of a task's service-level windows have been breached
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// Flags for a lapsed acceptance window, a missed deadline, and a release left waiting
+    /// past the release timeout
+    pub fn get_task_sla_status(env: Env, task_id: u64) -> SlaStatus {
+        let task = Self::get_task(env.clone(), task_id);
+        let now = env.ledger().timestamp();
+
+        let open = [TaskStatus::Created, TaskStatus::Assigned, TaskStatus::InProgress]
+            .contains(&task.status);
+        let unaccepted = task.status == TaskStatus::Assigned
+            && task.accept_by.is_some_and(|accept_by| now > accept_by);
+        let release_overdue = task.status == TaskStatus::Completed
+            && task.completed_at.is_some_and(|completed_at| {
+                now >= completed_at.saturating_add(Self::get_release_timeout(env.clone()))
+            });
+
+        SlaStatus {
+            unaccepted,
+            overdue: open && now > task.deadline,
+            release_overdue,
+        }
+    }
+
+    /// Check whether the creator could still extend a task's deadline
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// True if the task is assigned or in progress and its deadline has not passed
+    pub fn can_extend_deadline(env: Env, task_id: u64) -> bool {
+        let task = Self::get_task(env.clone(), task_id);
+        let active = task.status == TaskStatus::Assigned || task.status == TaskStatus::InProgress;
+        active && env.ledger().timestamp() <= task.deadline
+    }
+
+    /// Get the dependencies of a task that have not finished yet
+    ///
+    /// # Arguments
+    /// * `task_id` - ID of the task
+    ///
+    /// # Returns
+    /// IDs of dependencies not in `Completed` or `FundsReleased` state
+    pub fn get_blocking_tasks(env: Env, task_id: u64) -> Vec<u64> {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        let mut blocking = Vec::new(&env);
+        for dependency_id in task.depends_on.iter() {
+            let finished = match tasks.get(dependency_id) {
+                Some(dependency) => {
+                    dependency.status == TaskStatus::Completed
+                        || dependency.status == TaskStatus::FundsReleased
+                }
+                None => false,
+            };
+            if !finished {
+                blocking.push_back(dependency_id);
+            }
+        }
+        blocking
+    }
+
+    /// Get all tasks created by a user
+    ///
+    /// Very prolific creators can exceed return size limits; use `get_user_tasks_paged` instead.
+    ///
+    /// # Arguments
+    /// * `user` - Address of the user
+    ///
+    /// # Returns
+    /// Vector of task IDs created by the user
+    pub fn get_user_tasks(env: Env, user: Address) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::UserTasks(user))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a page of the tasks created by a user
+    ///
+    /// # Arguments
+    /// * `user` - Address of the user
+    /// * `start` - Number of task IDs to skip
+    /// * `limit` - Maximum number of task IDs to return (capped at 50)
+    ///
+    /// # Returns
+    /// Slice of the task IDs created by the user, in creation order
+    pub fn get_user_tasks_paged(env: Env, user: Address, start: u32, limit: u32) -> Vec<u64> {
+        Self::page_ids(Self::get_user_tasks(env, user), start, limit)
+    }
+
+    /// Get a creator's completed tasks that are waiting for fund release
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    ///
+    /// # Returns
+    /// Vector of IDs of the creator's tasks in Completed status
+    pub fn get_pending_approvals(env: Env, creator: Address) -> Vec<u64> {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+
+        let mut pending = Vec::new(&env);
+        for task_id in Self::get_user_tasks(env.clone(), creator).iter() {
+            if let Some(task) = tasks.get(task_id) {
+                if task.status == TaskStatus::Completed {
+                    pending.push_back(task_id);
+                }
+            }
+        }
+        pending
+    }
+
+    /// Get a creator's tasks in a given status
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the task creator
+    /// * `status` - Status to filter by
+    /// * `start` - Number of matching tasks to skip
+    /// * `limit` - Maximum number of tasks to return (capped at 50)
+    ///
+    /// # Returns
+    /// The creator's tasks in `status`, in creation order
+    pub fn get_creator_tasks_by_status(
+        env: Env,
+        creator: Address,
+        status: TaskStatus,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Task> {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        let mut result = Vec::new(&env);
+        let mut skipped = 0u32;
+        for task_id in Self::get_user_tasks(env.clone(), creator).iter() {
+            if result.len() >= limit {
+                break;
+            }
+            if let Some(task) = tasks.get(task_id) {
+                if task.status != status {
+                    continue;
+                }
+                if skipped < start {
+                    skipped += 1;
+                } else {
+                    result.push_back(task);
+                }
+            }
+        }
+        result
+    }
+
+    /// Get the platform fees taken from a creator's released tasks
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the creator
+    ///
+    /// # Returns
+    /// Cumulative platform fees paid on the creator's payouts
+    pub fn get_creator_fees_paid(env: Env, creator: Address) -> i128 {
+        let creator_fees: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CreatorFees)
+            .unwrap_or(Map::new(&env));
+        creator_fees.get(creator).unwrap_or(0)
+    }
+
+    /// Get a user's lifetime payment totals
+    ///
+    /// # Arguments
+    /// * `user` - Address of the user
+    ///
+    /// # Returns
+    /// Tuple of (earned as assignee, spent as creator, currently locked in escrow)
+    pub fn get_user_financials(env: Env, user: Address) -> (i128, i128, i128) {
+        let financials: Map<Address, UserFinancials> = env
+            .storage()
+            .instance()
+            .get(&DataKey::UserFinancials)
+            .unwrap_or(Map::new(&env));
+        let totals = financials.get(user).unwrap_or_default();
+        (totals.earned, totals.spent, totals.locked)
+    }
+
+    /// Rate the creator of a finished task
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the task assignee
+    /// * `task_id` - ID of the released or cancelled task
+    /// * `score` - Rating from 1 to 5
+    pub fn rate_creator(env: Env, assignee: Address, task_id: u64, score: u32) {
+        assignee.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the assignee
+        Self::require_assignee(&assignee, &task);
+
+        // Only finished tasks can be rated
+        Self::require_valid_state(&task, &[TaskStatus::FundsReleased, TaskStatus::Cancelled]);
+
+        if score == 0 || score > MAX_RATING_SCORE {
+            panic!("Score must be between 1 and 5");
+        }
+        if task.creator_rated {
+            panic!("Task already rated");
+        }
+        task.creator_rated = true;
+
+        // Update creator's aggregate rating
+        let mut ratings: Map<Address, (u64, u64)> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CreatorRatings)
+            .unwrap_or(Map::new(&env));
+        let (total, count) = ratings.get(task.creator.clone()).unwrap_or((0, 0));
+        ratings.set(task.creator.clone(), (total + score as u64, count + 1));
+        env.storage().instance().set(&DataKey::CreatorRatings, &ratings);
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
+
+    /// Get the aggregate rating assignees have given a creator
+    ///
+    /// # Arguments
+    /// * `creator` - Address of the creator
+    ///
+    /// # Returns
+    /// Tuple of (sum of all scores, number of ratings)
+    pub fn get_creator_rating(env: Env, creator: Address) -> (u64, u64) {
+        let ratings: Map<Address, (u64, u64)> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CreatorRatings)
+            .unwrap_or(Map::new(&env));
+        ratings.get(creator).unwrap_or((0, 0))
+    }
+
+    /// Get a user's track record as an assignee
+    ///
+    /// # Arguments
+    /// * `user` - Address of the user
+    ///
+    /// # Returns
+    /// Counts of the user's paid-out and abandoned tasks
+    pub fn get_reputation(env: Env, user: Address) -> AssigneeReputation {
+        let reputations: Map<Address, AssigneeReputation> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Reputation)
+            .unwrap_or(Map::new(&env));
+        reputations.get(user).unwrap_or_default()
+    }
+
+    /// Get the share of an assignee's finished tasks that were paid out
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the assignee
+    ///
+    /// # Returns
+    /// Percentage (0-100) of completed tasks, or `u32::MAX` if the assignee has no history
+    pub fn get_success_rate(env: Env, assignee: Address) -> u32 {
+        let reputation = Self::get_reputation(env, assignee);
+        let total = reputation.completed as u64 + reputation.failed as u64;
+        if total == 0 {
+            return NO_TASK_HISTORY;
+        }
+        (reputation.completed as u64 * 100 / total) as u32
+    }
+
+    /// Get all tasks assigned to a user
+    ///
+    /// Very busy assignees can exceed return size limits; use `get_assigned_tasks_paged` instead.
+    ///
+    /// # Arguments
+    /// * `user` - Address of the user
+    ///
+    /// # Returns
+    /// Vector of task IDs assigned to the user
+    pub fn get_assigned_tasks(env: Env, user: Address) -> Vec<u64> {
+        env.storage()
+            .instance()
+            .get(&DataKey::AssignedTasks(user))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Get a page of the tasks assigned to a user
+    ///
+    /// # Arguments
+    /// * `user` - Address of the user
+    /// * `start` - Number of task IDs to skip
+    /// * `limit` - Maximum number of task IDs to return (capped at 50)
+    ///
+    /// # Returns
+    /// Slice of the task IDs assigned to the user, in assignment order
+    pub fn get_assigned_tasks_paged(env: Env, user: Address, start: u32, limit: u32) -> Vec<u64> {
+        Self::page_ids(Self::get_assigned_tasks(env, user), start, limit)
+    }
+
+    /// Get an assignee's tasks where the next action is theirs
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the assignee
+    ///
+    /// # Returns
+    /// IDs of the assignee's tasks still to be started (Assigned) or completed (InProgress),
+    /// leaving out any already past their deadline
+    pub fn get_assignee_actionable(env: Env, assignee: Address) -> Vec<u64> {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let now = env.ledger().timestamp();
+
+        let mut actionable = Vec::new(&env);
+        for task_id in Self::get_assigned_tasks(env.clone(), assignee).iter() {
+            if let Some(task) = tasks.get(task_id) {
+                let open = task.status == TaskStatus::Assigned
+                    || task.status == TaskStatus::InProgress;
+                if open && now <= task.deadline {
+                    actionable.push_back(task_id);
+                }
+            }
+        }
+        actionable
+    }
+
+    /// Summarize the work an assignee currently has on their plate
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the assignee
+    ///
+    /// # Returns
+    /// Tuple of (number of assigned or in-progress tasks, funding still escrowed for them)
+    pub fn get_assignee_workload(env: Env, assignee: Address) -> (u64, i128) {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+
+        let mut active = 0u64;
+        let mut escrowed = 0i128;
+        for task_id in Self::get_assigned_tasks(env.clone(), assignee).iter() {
+            if let Some(task) = tasks.get(task_id) {
+                if task.status == TaskStatus::Assigned || task.status == TaskStatus::InProgress {
+                    active += 1;
+                    escrowed += Self::remaining_escrow(&task);
+                }
+            }
+        }
+        (active, escrowed)
+    }
+
+    /// Get active tasks assigned to a user ordered for triage
+    ///
+    /// # Arguments
+    /// * `user` - Address of the assignee
+    ///
+    /// # Returns
+    /// IDs of the user's assigned or in-progress tasks, sorted by descending
+    /// priority and then by ascending deadline
+    pub fn get_assigned_tasks_by_priority(env: Env, user: Address) -> Vec<u64> {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut sorted: Vec<Task> = Vec::new(&env);
+
+        for task_id in Self::get_assigned_tasks(env.clone(), user).iter() {
+            let task = match tasks.get(task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+            if task.status != TaskStatus::Assigned && task.status != TaskStatus::InProgress {
+                continue;
+            }
+
+            // Insertion sort: find the first task that should come after this one
+            let mut index = sorted.len();
+            for (i, other) in sorted.iter().enumerate() {
+                if task.priority > other.priority
+                    || (task.priority == other.priority && task.deadline < other.deadline)
+                {
+                    index = i as u32;
+                    break;
+                }
+            }
+            sorted.insert(index, task);
+        }
+
+        let mut result = Vec::new(&env);
+        for task in sorted.iter() {
+            result.push_back(task.id);
+        }
+        result
+    }
+
+    /// Get an assignee's active tasks as a work queue, most urgent first
+    ///
+    /// # Arguments
+    /// * `assignee` - Address of the assignee
+    ///
+    /// # Returns
+    /// The assignee's assigned or in-progress tasks, sorted by ascending deadline
+    pub fn get_assignee_queue(env: Env, assignee: Address) -> Vec<Task> {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut queue: Vec<Task> = Vec::new(&env);
+
+        for task_id in Self::get_assigned_tasks(env.clone(), assignee).iter() {
+            let task = match tasks.get(task_id) {
+                Some(task) => task,
+                None => continue,
+            };
+            if task.status != TaskStatus::Assigned && task.status != TaskStatus::InProgress {
+                continue;
+            }
+
+            // Insertion sort: find the first task due after this one
+            let mut index = queue.len();
+            for (i, other) in queue.iter().enumerate() {
+                if task.deadline < other.deadline {
+                    index = i as u32;
+                    break;
+                }
+            }
+            queue.insert(index, task);
+        }
+        queue
+    }
+
+    /// Get active tasks whose deadline is approaching and whose assignee has not been reminded
+    ///
+    /// # Arguments
+    /// * `within_secs` - How far ahead of now a deadline counts as approaching
+    /// * `start` - Task ID to start scanning from (use the previous page's cursor)
+    /// * `limit` - Maximum number of tasks to return (capped at 50)
+    ///
+    /// # Returns
+    /// Assigned or in-progress tasks due within the window, in ID order, and a cursor for the
+    /// next page
+    pub fn get_tasks_needing_reminder(
+        env: Env,
+        within_secs: u64,
+        start: u64,
+        limit: u32,
+    ) -> TaskPage {
+        let now = env.ledger().timestamp();
+        let horizon = now.saturating_add(within_secs);
+
+        Self::scan_tasks(&env, start, limit, |task| {
+            let active =
+                task.status == TaskStatus::Assigned || task.status == TaskStatus::InProgress;
+            active && !task.reminded && task.deadline >= now && task.deadline <= horizon
+        })
+    }
+
+    /// Record that a task's assignee has been reminded of the deadline
+    ///
+    /// # Arguments
+    /// * `caller` - Address of the task creator or assignee
+    /// * `task_id` - ID of the task
+    pub fn mark_reminded(env: Env, caller: Address, task_id: u64) {
+        caller.require_auth();
+
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is a party to the task
+        if caller != task.creator && task.assignee != Some(caller.clone()) {
+            panic!("Only task creator or assignee can mark a reminder");
+        }
+
+        // Only tasks still being worked on have a deadline to be reminded of
+        Self::require_valid_state(&task, &[TaskStatus::Assigned, TaskStatus::InProgress]);
+
+        task.reminded = true;
+
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+    }
+
+    /// Get tasks that reference a GitHub link
+    ///
+    /// # Arguments
+    /// * `start` - Number of linked tasks to skip
+    /// * `limit` - Maximum number of tasks to return (capped at 50)
+    ///
+    /// # Returns
+    /// Linked tasks in ID order
+    pub fn get_github_tasks(env: Env, start: u32, limit: u32) -> Vec<Task> {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let linked_tasks: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::GithubTasks)
+            .unwrap_or(Vec::new(&env));
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(linked_tasks.len());
+
+        let mut result = Vec::new(&env);
+        for index in start..end {
+            if let Some(task) = tasks.get(linked_tasks.get_unchecked(index)) {
+                result.push_back(task);
+            }
+        }
+        result
+    }
+
+    /// Get disputed tasks awaiting arbitration
+    ///
+    /// # Arguments
+    /// * `start` - Task ID to start scanning from (use the previous page's cursor)
+    /// * `limit` - Maximum number of tasks to return (capped at 50)
+    ///
+    /// # Returns
+    /// Disputed tasks in ID order and a cursor for the next page
+    pub fn get_disputed_tasks(env: Env, start: u64, limit: u32) -> TaskPage {
+        Self::scan_tasks(&env, start, limit, |task| task.disputed)
+    }
+
+    /// Get tasks completed within a time range
+    ///
+    /// # Arguments
+    /// * `from` - Start of the range (inclusive, Unix timestamp)
+    /// * `to` - End of the range (inclusive, Unix timestamp)
+    /// * `start` - Task ID to start scanning from (use the previous page's cursor)
+    /// * `limit` - Maximum number of tasks to return (capped at 50)
+    ///
+    /// # Returns
+    /// Tasks whose `completed_at` falls in `[from, to]` and a cursor for the next page
+    pub fn get_tasks_completed_between(
+        env: Env,
+        from: u64,
+        to: u64,
+        start: u64,
+        limit: u32,
+    ) -> TaskPage {
+        if from > to {
+            panic!("Invalid time range");
+        }
+
+        Self::scan_tasks(&env, start, limit, |task| match task.completed_at {
+            Some(completed_at) => completed_at >= from && completed_at <= to,
+            None => false,
+        })
+    }
+
+    /// Get total number of tasks
+    pub fn get_task_count(env: Env) -> u64 {
+        env.storage()
+            .instance()
+            .get(&DataKey::TaskCounter)
+            .unwrap_or(1u64)
+            - 1
+    }
+
+    /// Get the IDs of existing tasks in a range
+    ///
+    /// # Arguments
+    /// * `start` - First task ID of the range (inclusive)
+    /// * `end` - Last task ID of the range (exclusive, span capped at 100 IDs)
+    ///
+    /// # Returns
+    /// Vector of task IDs in the range that exist, in ascending order
+    pub fn get_task_ids_in_range(env: Env, start: u64, end: u64) -> Vec<u64> {
+        if start > end {
+            panic!("Invalid range");
+        }
+
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let end = end.min(start.saturating_add(MAX_SCAN_COUNT));
+
+        let mut ids = Vec::new(&env);
+        for task_id in start..end {
+            if tasks.contains_key(task_id) {
+                ids.push_back(task_id);
+            }
+        }
+        ids
+    }
+
+    /// Get the most recently created tasks
+    ///
+    /// # Arguments
+    /// * `limit` - Maximum number of tasks to return (capped at 10)
+    ///
+    /// # Returns
+    /// Vector of the newest tasks, ordered by descending ID
+    pub fn get_recent_tasks(env: Env, limit: u32) -> Vec<Task> {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(&env));
+        let limit = limit.min(MAX_RECENT_TASKS);
+
+        let mut result = Vec::new(&env);
+        let mut task_id = Self::get_task_count(env.clone());
+        let scan_end = task_id.saturating_sub(MAX_SCAN_COUNT);
+
+        while task_id > scan_end && result.len() < limit {
+            if let Some(task) = tasks.get(task_id) {
+                result.push_back(task);
+            }
+            task_id -= 1;
+        }
+
+        result
+    }
+
+    // Helper functions
+
+    /// Validate, fund and store a new task on behalf of an already authorized creator
+    ///
+    /// `prefunded` is escrow the contract already holds for the creator; only the rest is pulled.
+    #[allow(clippy::too_many_arguments)]
+    fn create_funded_task(
+        env: &Env,
+        creator: Address,
+        title: String,
+        description: String,
+        github_link: String,
+        funding_amount: i128,
+        deadline: u64,
+        options: TaskOptions,
+        prefunded: i128,
+        pledged: bool,
+    ) -> u64 {
+        // A retried request returns the task it already created instead of funding another
+        let mut client_refs: Map<(Address, String), u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::ClientRefs)
+            .unwrap_or(Map::new(env));
+        if let Some(client_ref) = &options.client_ref {
+            if let Some(existing_id) = client_refs.get((creator.clone(), client_ref.clone())) {
+                return existing_id;
+            }
+        }
+
+        // Validate inputs
+        Self::validate_task_creation(env, &title, &description, funding_amount, deadline);
+        let github_link = Self::normalize_github_link(env, github_link);
+        if Self::get_fee_token_debt(env.clone(), creator.clone()) > 0 {
+            panic!("Outstanding fee token debt");
+        }
+        if options.priority > MAX_TASK_PRIORITY {
+            panic!("Invalid priority");
+        }
+        if options.oracle.is_some() && options.oracle_fn.is_none() {
+            panic!("Oracle function required");
+        }
+        if let ExpiryAction::AutoReassignToBackup(backup) = &options.expiry_action {
+            if *backup == creator {
+                panic!("Creator cannot be the backup assignee");
+            }
+            Self::require_eligible_assignee(env, backup);
+        }
+
+        // Validate that all dependencies exist
+        let existing_tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        for dependency_id in options.depends_on.iter() {
+            if !existing_tasks.contains_key(dependency_id) {
+                panic!("Dependency task not found");
+            }
+        }
+
+        // Get current task ID and increment counter
+        let task_id = env
+            .storage()
+            .instance()
+            .get(&DataKey::TaskCounter)
+            .unwrap_or(1u64);
+        env.storage()
+            .instance()
+            .set(&DataKey::TaskCounter, &(task_id + 1));
+
+        // Surface a corrupted counter instead of overwriting an existing task
+        if existing_tasks.contains_key(task_id) {
+            panic!("Task ID collision");
+        }
+
+        let current_time = env.ledger().timestamp();
+
+        // Transfer funds from creator to contract, less any escrow already held for them
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(env, &token_address);
+        let shortfall = funding_amount - prefunded;
+        if shortfall > 0 && !pledged {
+            if token_client.balance(&creator) < shortfall {
+                panic!("Insufficient balance to fund task");
+            }
+            token_client.transfer(&creator, &env.current_contract_address(), &shortfall);
+        }
+        let token_decimals = token_client.decimals();
+        let escrowed = if pledged { 0 } else { funding_amount };
+        Self::record_financials(env, &creator, 0, 0, escrowed);
+        Self::update_stats(env, |stats| {
+            stats.total_tasks += 1;
+            stats.total_escrowed += escrowed;
+            stats.active_tasks += 1;
+        });
+
+        // Create new task
+        let task = Task {
+            id: task_id,
+            title,
+            description,
+            github_link,
+            funding_amount,
+            deadline,
+            creator: creator.clone(),
+            assignee: None, // Task starts unassigned
+            status: TaskStatus::Created,
+            created_at: current_time,
+            completed_at: None,
+            creator_approved: false,
+            assignee_approved: false,
+            applications: Vec::new(env), // Initialize empty applications vector
+            priority: options.priority,
+            auto_release: options.auto_release,
+            depends_on: options.depends_on,
+            released_so_far: 0,
+            completion_proof: None,
+            token_decimals,
+            was_expired: false,
+            comments: Vec::new(env),
+            abandoned: false,
+            previous_assignees: Vec::new(env),
+            disputed: false,
+            payout_address: None,
+            processing: false,
+            cancel_requested_at: None,
+            creator_rated: false,
+            notify_on_release: options.notify_on_release,
+            vault_deposit: 0,
+            relisted_from: None,
+            reminded: false,
+            oracle: options.oracle,
+            oracle_fn: options.oracle_fn,
+            open_pool: pledged,
+            refund_address: None,
+            fee_bps: Self::get_priority_fee_bps(env.clone(), options.priority),
+            expiry_action: options.expiry_action,
+            deliverable_hash: None,
+            started_at: None,
+            pledged,
+            accept_window: options.accept_window,
+            accept_by: None,
+        };
+
+        // Store task
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        tasks.set(task_id, task.clone());
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        if !pledged {
+            Self::deposit_to_vault(env, task_id, funding_amount);
+        }
+        if task.github_link.is_some() {
+            Self::index_github_task(env, task_id, true);
+        }
+
+        // Update user tasks mapping
+        Self::add_to_index(env, &DataKey::UserTasks(creator.clone()), task_id);
+
+        // Remember the idempotency key for retries
+        if let Some(client_ref) = options.client_ref {
+            client_refs.set((creator, client_ref), task_id);
+            env.storage().instance().set(&DataKey::ClientRefs, &client_refs);
+        }
+
+        task_id
+    }
+
+    /// Scan tasks by ID collecting those matching a predicate
+    ///
+    /// Examines at most `MAX_SCAN_COUNT` task IDs per call so queries stay within
+    /// resource limits; the returned cursor resumes where the scan stopped.
+    fn scan_tasks(
+        env: &Env,
+        start: u64,
+        limit: u32,
+        predicate: impl Fn(&Task) -> bool,
+    ) -> TaskPage {
+        let tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        let next_id: u64 = env
+            .storage()
+            .instance()
+            .get(&DataKey::TaskCounter)
+            .unwrap_or(1u64);
+        let limit = limit.min(MAX_PAGE_SIZE);
+
+        let mut result = Vec::new(env);
+        let mut task_id = start.max(1);
+        let scan_end = task_id.saturating_add(MAX_SCAN_COUNT).min(next_id);
+
+        while task_id < scan_end && result.len() < limit {
+            if let Some(task) = tasks.get(task_id) {
+                if predicate(&task) {
+                    result.push_back(task);
+                }
+            }
+            task_id += 1;
+        }
+
+        TaskPage {
+            tasks: result,
+            next_cursor: if task_id < next_id { Some(task_id) } else { None },
+        }
+    }
+
+    /// Options applied when a task is created without explicit settings
+    fn default_task_options(env: &Env) -> TaskOptions {
+        TaskOptions {
+            priority: 0,
+            auto_release: false,
+            depends_on: Vec::new(env),
+            client_ref: None,
+            notify_on_release: false,
+            oracle: None,
+            oracle_fn: None,
+            expiry_action: ExpiryAction::Refund,
+            accept_window: 0,
+        }
+    }
+
+    /// Validate task creation parameters
+    fn validate_task_creation(
+        env: &Env,
+        title: &String,
+        description: &String,
+        funding_amount: i128,
+        deadline: u64,
+    ) {
+        if let Err(error) =
+            Self::check_task_creation(env, title, description, funding_amount, deadline)
+        {
+            Self::reject_task(error);
+        }
+    }
+
+    /// Validate a deadline being set on an existing task
+    fn validate_deadline(env: &Env, deadline: u64) {
+        if let Err(error) = Self::check_deadline(env, deadline) {
+            Self::reject_task(error);
+        }
+    }
+
+    /// Panic with the message for a task validation failure
+    fn reject_task(error: TaskError) -> ! {
+        match error {
+            TaskError::EmptyTitle => panic!("Title cannot be empty"),
+            TaskError::EmptyDescription => panic!("Description cannot be empty"),
+            TaskError::NonPositiveFunding => panic!("Funding amount must be positive"),
+            TaskError::FundingTooLarge => panic!("Funding amount too large for fee calculation"),
+            TaskError::DeadlineInPast => panic!("Deadline must be in the future"),
+            TaskError::DeadlineTooSoon => {
+                panic!("Deadline is sooner than the minimum task duration")
+            }
+        }
+    }
+
+    /// Check the inputs for a new task, returning the first reason it would be rejected
+    fn check_task_creation(
+        env: &Env,
+        title: &String,
+        description: &String,
+        funding_amount: i128,
+        deadline: u64,
+    ) -> Result<(), TaskError> {
+        if title.len() == 0 {
+            return Err(TaskError::EmptyTitle);
+        }
+        if description.len() == 0 {
+            return Err(TaskError::EmptyDescription);
+        }
+        if funding_amount <= 0 {
+            return Err(TaskError::NonPositiveFunding);
+        }
+        if funding_amount > MAX_FUNDING_AMOUNT {
+            return Err(TaskError::FundingTooLarge);
+        }
+        Self::check_deadline(env, deadline)
+    }
+
+    /// Check a deadline is in the future and leaves at least the minimum task duration
+    fn check_deadline(env: &Env, deadline: u64) -> Result<(), TaskError> {
+        if deadline <= env.ledger().timestamp() {
+            return Err(TaskError::DeadlineInPast);
+        }
+        let min_duration = Self::get_min_task_duration(env.clone());
+        if deadline < env.ledger().timestamp().saturating_add(min_duration) {
+            return Err(TaskError::DeadlineTooSoon);
+        }
+        Ok(())
+    }
+
+    /// Assign a created task on behalf of an already authorized creator
+    fn assign_task_to(env: &Env, creator: &Address, task_id: u64, assignee: Address) {
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check if caller is the creator
+        Self::require_creator(creator, &task);
+
+        // Check if task is in Created state (not assigned yet)
+        Self::require_valid_state(&task, &[TaskStatus::Created]);
+        Self::require_no_commitment(env, task_id);
+
+        // Check the assignee can take on another task
+        Self::require_eligible_assignee(env, &assignee);
+        Self::require_assignee_capacity(env, &assignee);
+
+        // Update task with assignee and change status
+        task.assignee = Some(assignee.clone());
+        task.status = TaskStatus::Assigned;
+        task.accept_by = Self::accept_deadline(env, &task);
+        task.open_pool = false;
+
+        // Store updated task
+        tasks.set(task_id, task.clone());
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+
+        // Update assigned tasks mapping
+        Self::add_to_index(env, &DataKey::AssignedTasks(assignee.clone()), task_id);
+        Self::fund_pledge(env, task_id);
+    }
+
+    /// Escrow a pledge task's funding from the creator's allowance once a worker commits
+    fn fund_pledge(env: &Env, task_id: u64) {
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+        if !task.pledged {
+            return;
+        }
+
+        task.pledged = false;
+        Self::record_financials(env, &task.creator, 0, 0, task.funding_amount);
+        Self::update_stats(env, |stats| stats.total_escrowed += task.funding_amount);
+        tasks.set(task_id, task.clone());
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+
+        // Pull the funds under the allowance the creator granted this contract
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(env, &token_address);
+        let contract = env.current_contract_address();
+        token_client.transfer_from(&contract, &task.creator, &contract, &task.funding_amount);
+        Self::deposit_to_vault(env, task_id, task.funding_amount);
+    }
+
+    /// Reassign an expired task on behalf of an already authorized creator, optionally
+    /// moving its deadline
+    fn reassign_task_to(
+        env: &Env,
+        creator: &Address,
+        task_id: u64,
+        new_assignee: Address,
+        new_deadline: Option<u64>,
+    ) {
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+
+        // Check the caller is the current creator, which may differ after a creatorship transfer
+        Self::require_creator(creator, &task);
+
+        // Check if task is expired
+        if task.status != TaskStatus::Expired {
+            panic!("Task must be expired to reassign");
+        }
+        Self::require_not_disputed(&task);
+
+        // Check the task is actually moving to someone else
+        let old_assignee = task
+            .assignee
+            .clone()
+            .expect("Task must have an assignee");
+        if old_assignee == new_assignee {
+            panic!("Cannot reassign to current assignee");
+        }
+
+        // Check the new assignee can take on another task
+        Self::require_eligible_assignee(env, &new_assignee);
+        Self::require_assignee_capacity(env, &new_assignee);
+
+        // Update assignee and reset status
+        task.previous_assignees.push_back(old_assignee.clone());
+        task.assignee = Some(new_assignee.clone());
+        task.payout_address = None;
+        task.reminded = false;
+        task.cancel_requested_at = None;
+        task.status = TaskStatus::Assigned;
+        task.accept_by = Self::accept_deadline(env, &task);
+        task.assignee_approved = false;
+        task.creator_approved = false;
+        task.completed_at = None;
+        task.started_at = None;
+        if let Some(new_deadline) = new_deadline {
+            task.deadline = new_deadline;
+        }
+
+        // Store updated task
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+
+        // Move the task from the old assignee's tasks to the new assignee's
+        Self::remove_from_index(env, &DataKey::AssignedTasks(old_assignee), task_id);
+        Self::add_to_index(env, &DataKey::AssignedTasks(new_assignee), task_id);
+    }
+
+    /// Validate the descriptive fields of a task
+    fn validate_task_details(title: &String, description: &String) {
+        if title.len() == 0 {
+            panic!("Title cannot be empty");
+        }
+        if description.len() == 0 {
+            panic!("Description cannot be empty");
+        }
+    }
+
+    /// Store an empty link as None and require other links to be http(s) URLs
+    fn normalize_github_link(env: &Env, github_link: String) -> Option<String> {
+        if github_link.is_empty() {
+            if Self::get_require_github_link(env.clone()) {
+                panic!("GitHub link required");
+            }
+            return None;
+        }
+
+        let link = github_link.to_bytes();
+        let has_scheme = |scheme: &[u8]| {
+            let scheme = Bytes::from_slice(env, scheme);
+            link.len() > scheme.len() && link.slice(0..scheme.len()) == scheme
+        };
+        if !has_scheme(b"https://") && !has_scheme(b"http://") {
+            panic!("Invalid GitHub link");
+        }
+        Some(github_link)
+    }
+
+    /// Pay the assignee of a completed task and accrue the platform fee
+    fn release_task_funds(env: &Env, task: Task) {
+        // Validate the task is ready for release before consulting its oracle
+        Self::compute_release(env, &task);
+        if !Self::oracle_condition_met(env, &task) {
+            panic!("Oracle condition not met");
+        }
+        Self::release_checked_funds(env, task);
+    }
+
+    /// Pay out a task whose oracle condition has already been checked
+    fn release_checked_funds(env: &Env, mut task: Task) {
+        // Compute the fee split (validates the task is ready for release)
+        let outcome = Self::compute_release(env, &task);
+
+        // With a separate fee token the assignee receives the whole escrow and the creator
+        // owes the fee in the fee token instead
+        let released = Self::remaining_escrow(&task);
+        let fee_token = Self::get_fee_token(env.clone());
+        let payout = outcome.assignee_amount;
+
+        // Update platform fees accumulator unless the fee goes straight to the treasury
+        let treasury = Self::get_auto_sweep_fees(env.clone());
+        if fee_token.is_none() && treasury.is_none() {
+            env.storage()
+                .instance()
+                .set(&DataKey::PlatformFees, &outcome.accumulated_fees);
+        }
+
+        // Update task status and mark the escrow as fully paid out
+        task.status = TaskStatus::FundsReleased;
+        task.released_so_far += released;
+        Self::record_financials(env, &task.creator, 0, released, -released);
+        Self::record_creator_fee(env, &task.creator, outcome.platform_fee);
+        Self::record_financials(env, &outcome.assignee, payout, 0, 0);
+        Self::record_reputation(env, &outcome.assignee, 1, 0);
+        Self::update_stats(env, |stats| {
+            stats.active_tasks = stats.active_tasks.saturating_sub(1);
+            stats.completed_tasks += 1;
+            stats.released_volume += released;
+            stats.release_fees += outcome.platform_fee;
+            stats.total_paid += payout;
+            if fee_token.is_some() {
+                stats.fee_token_fees += outcome.platform_fee;
+            } else {
+                stats.total_fees_earned += outcome.platform_fee;
+                if treasury.is_some() {
+                    stats.total_fees_withdrawn += outcome.platform_fee;
+                }
+            }
+        });
+
+        // Lock the task until the transfer completes
+        task.processing = true;
+
+        // Store updated task before transfer
+        let task_id = task.id;
+        let creator = task.creator.clone();
+        let recipient = Self::payout_recipient(&task);
+        let notify = task.notify_on_release;
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        tasks.set(task.id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        Self::recall_from_vault(env, task_id);
+
+        // Get token client
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(env, &token_address);
+
+        // Transfer funds to assignee (after platform fee deduction)
+        token_client.transfer(&env.current_contract_address(), &recipient, &payout);
+
+        // Charge the fee to the creator in the fee token; the release may not carry their
+        // signature, so it is recorded as a debt until they settle it
+        if fee_token.is_some() {
+            if outcome.platform_fee > 0 {
+                let mut debts: Map<Address, i128> = env
+                    .storage()
+                    .instance()
+                    .get(&DataKey::FeeTokenDebts)
+                    .unwrap_or(Map::new(env));
+                let owed = debts.get(creator.clone()).unwrap_or(0);
+                debts.set(creator.clone(), owed + outcome.platform_fee);
+                env.storage().instance().set(&DataKey::FeeTokenDebts, &debts);
+            }
+        } else if let Some(treasury) = treasury {
+            // Sweep the fee to the treasury in the same transaction
+            if outcome.platform_fee > 0 {
+                token_client.transfer(
+                    &env.current_contract_address(),
+                    &treasury,
+                    &outcome.platform_fee,
+                );
+            }
+        }
+        Self::finish_processing(env, task_id);
+
+        // Notify the recipient; a failing callback must not undo the payout
+        if notify {
+            let _ = env.try_invoke_contract::<(), InvokeError>(
+                &recipient,
+                &Symbol::new(env, RELEASE_CALLBACK),
+                (task_id, payout).into_val(env),
+            );
+        }
+    }
+
+    /// Pull a creator's outstanding fee-token debt into the fee balance or treasury
+    fn collect_fee_token_debt(env: &Env, creator: &Address) {
+        let mut debts: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeTokenDebts)
+            .unwrap_or(Map::new(env));
+        let debt = debts.get(creator.clone()).unwrap_or(0);
+        if debt <= 0 {
+            return;
+        }
+
+        // Clear the debt before transferring
+        debts.remove(creator.clone());
+        env.storage().instance().set(&DataKey::FeeTokenDebts, &debts);
+
+        let fee_token: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::FeeToken)
+            .expect("Fee token not configured");
+        let fee_recipient = Self::get_auto_sweep_fees(env.clone())
+            .unwrap_or_else(|| env.current_contract_address());
+        if fee_recipient == env.current_contract_address() {
+            let fee_token_fees = Self::get_fee_token_fees(env.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::FeeTokenFees, &(fee_token_fees + debt));
+        }
+
+        let fee_client = token::Client::new(env, &fee_token);
+        fee_client.transfer(creator, &fee_recipient, &debt);
+    }
+
+    /// Ask a task's oracle whether its release condition holds (always true without an oracle)
+    fn oracle_condition_met(env: &Env, task: &Task) -> bool {
+        match (&task.oracle, &task.oracle_fn) {
+            (Some(oracle), Some(oracle_fn)) => {
+                env.invoke_contract::<bool>(oracle, oracle_fn, (task.id,).into_val(env))
+            }
+            _ => true,
+        }
+    }
+
+    /// Compute the fee split for releasing a task's funds
+    fn compute_release(env: &Env, task: &Task) -> ReleaseOutcome {
+        // Check if task is in valid state for fund release
+        Self::require_valid_state(task, &[TaskStatus::Completed]);
+
+        // Check if assignee has marked task as complete
+        if !task.assignee_approved {
+            panic!("Task must be marked complete by assignee");
+        }
+
+        // Disputed funds stay in escrow until the dispute is resolved
+        Self::require_not_disputed(task);
+
+        let assignee = task
+            .assignee
+            .clone()
+            .expect("Task must have an assignee");
+
+        // Calculate platform fee on the amount still in escrow
+        let escrowed = Self::remaining_escrow(task);
+        let (platform_fee, net_amount) = Self::split_payout(env, escrowed, task.fee_bps);
+
+        let accumulated_fees: i128 = env
+            .storage()
+            .instance()
+            .get(&DataKey::PlatformFees)
+            .unwrap_or(0i128);
+
+        // A fee charged in the fee token is neither deducted from the payout nor accumulated
+        if Self::get_fee_token(env.clone()).is_some() {
+            return ReleaseOutcome {
+                assignee,
+                assignee_amount: escrowed,
+                platform_fee,
+                accumulated_fees,
+            };
+        }
+
+        // A swept fee goes straight to the treasury instead of the accumulator
+        let accrued_fee = if Self::get_auto_sweep_fees(env.clone()).is_some() {
+            0
+        } else {
+            platform_fee
+        };
+
+        ReleaseOutcome {
+            assignee,
+            assignee_amount: net_amount,
+            platform_fee,
+            accumulated_fees: accumulated_fees + accrued_fee,
+        }
+    }
+
+    /// Require a sufficiently approved request for exactly this fee outflow and use it up
+    fn consume_withdrawal_approvals(env: &Env, action: &WithdrawalAction) {
+        let threshold: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::SignThreshold)
+            .unwrap_or(0u32);
+        if threshold == 0 {
+            return;
+        }
+
+        let mut requests: Map<u64, WithdrawalRequest> = env
+            .storage()
+            .instance()
+            .get(&DataKey::WithdrawRequests)
+            .unwrap_or(Map::new(env));
+        let approved = requests
+            .values()
+            .iter()
+            .find(|request| request.action == *action && request.approvals.len() >= threshold);
+        let request = approved.unwrap_or_else(|| panic!("Insufficient withdrawal approvals"));
+
+        requests.remove(request.id);
+        env.storage().instance().set(&DataKey::WithdrawRequests, &requests);
+    }
+
+    /// Platform fee owed on a payout at the task's fee rate, raised to the minimum fee
+    fn calculate_platform_fee(env: &Env, amount: i128, fee_bps: u32) -> i128 {
+        let percentage_fee = amount
+            .checked_mul(fee_bps as i128)
+            .unwrap_or_else(|| panic!("Funding amount too large for fee calculation"))
+            / BPS_DENOMINATOR as i128;
+        let min_fee = Self::get_min_fee(env.clone()).min(amount);
+        percentage_fee.max(min_fee)
+    }
+
+    /// Split a payout into the platform fee and the assignee's share
+    ///
+    /// The two parts always add back up to `amount` so no funds are stranded in the contract.
+    fn split_payout(env: &Env, amount: i128, fee_bps: u32) -> (i128, i128) {
+        let platform_fee = Self::calculate_platform_fee(env, amount, fee_bps);
+        let assignee_amount = amount
+            .checked_sub(platform_fee)
+            .unwrap_or_else(|| panic!("Funding amount too large for fee calculation"));
+        if platform_fee < 0 || assignee_amount < 0 || platform_fee + assignee_amount != amount {
+            panic!("Fee split does not preserve funding");
+        }
+        (platform_fee, assignee_amount)
+    }
+
+    /// Apply an update to the contract-wide statistics
+    fn update_stats(env: &Env, update: impl FnOnce(&mut Stats)) {
+        let mut stats: Stats = env.storage().instance().get(&DataKey::Stats).unwrap_or_default();
+        update(&mut stats);
+        env.storage().instance().set(&DataKey::Stats, &stats);
+    }
+
+    /// Adjust a user's running payment totals
+    fn record_financials(env: &Env, user: &Address, earned: i128, spent: i128, locked: i128) {
+        let mut financials: Map<Address, UserFinancials> = env
+            .storage()
+            .instance()
+            .get(&DataKey::UserFinancials)
+            .unwrap_or(Map::new(env));
+        let mut totals = financials.get(user.clone()).unwrap_or_default();
+        totals.earned += earned;
+        totals.spent += spent;
+        totals.locked += locked;
+        financials.set(user.clone(), totals);
+        env.storage().instance().set(&DataKey::UserFinancials, &financials);
+    }
+
+    /// Add to the platform fees a creator has paid on payouts
+    fn record_creator_fee(env: &Env, creator: &Address, fee: i128) {
+        if fee == 0 {
+            return;
+        }
+        let mut creator_fees: Map<Address, i128> = env
+            .storage()
+            .instance()
+            .get(&DataKey::CreatorFees)
+            .unwrap_or(Map::new(env));
+        let paid = creator_fees.get(creator.clone()).unwrap_or(0);
+        creator_fees.set(creator.clone(), paid + fee);
+        env.storage().instance().set(&DataKey::CreatorFees, &creator_fees);
+    }
+
+    /// Adjust a user's assignee track record
+    fn record_reputation(env: &Env, user: &Address, completed: u32, failed: u32) {
+        let mut reputations: Map<Address, AssigneeReputation> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Reputation)
+            .unwrap_or(Map::new(env));
+        let mut reputation = reputations.get(user.clone()).unwrap_or_default();
+        reputation.completed += completed;
+        reputation.failed += failed;
+        reputations.set(user.clone(), reputation);
+        env.storage().instance().set(&DataKey::Reputation, &reputations);
+    }
+
+    /// Pay out and/or refund a task's remaining escrow on the deployer's ruling
+    fn resolve_escrow(
+        env: &Env,
+        deployer: Address,
+        task_id: u64,
+        outcome: AdminOutcome,
+        charge_fee: bool,
+    ) {
         let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
         let mut task = tasks
             .get(task_id)
             .unwrap_or_else(|| panic!("Task not found"));
 
-        // Check if task is actually expired
-        if env.ledger().timestamp() <= task.deadline {
-            panic!("Task is not expired");
-        }
-
-        // Check if task is in valid state for expiration handling
+        // Only tasks that have not reached a terminal state can be resolved
         Self::require_valid_state(
             &task,
             &[
                 TaskStatus::Created,
                 TaskStatus::Assigned,
                 TaskStatus::InProgress,
+                TaskStatus::Completed,
+                TaskStatus::Approved,
+                TaskStatus::Expired,
             ],
         );
 
-        // Mark as expired
-        task.status = TaskStatus::Expired;
-
-        // Store updated task
-        tasks.set(task_id, task);
-        env.storage().instance().set(&TASKS, &tasks);
-    }
+        // Work out how the remaining escrow is divided
+        let escrowed = Self::remaining_escrow(&task);
+        let assignee_gross = match outcome {
+            AdminOutcome::PayAssignee => escrowed,
+            AdminOutcome::RefundCreator => 0,
+            AdminOutcome::Split(percentage) => {
+                if percentage > 100 {
+                    panic!("Split percentage cannot exceed 100");
+                }
+                escrowed * percentage as i128 / 100i128
+            }
+        };
+        let refund_amount = escrowed - assignee_gross;
+        let (platform_fee, assignee_amount) = if charge_fee {
+            Self::split_payout(env, assignee_gross, task.fee_bps)
+        } else {
+            (0, assignee_gross)
+        };
 
-    /// Reclaim funds from expired task
-    ///
-    /// # Arguments
-    /// * `creator` - Address of the task creator
-    /// * `task_id` - ID of the expired task
-    pub fn reclaim_expired_funds(env: Env, creator: Address, task_id: u64) {
-        creator.require_auth();
+        if assignee_gross > 0 && task.assignee.is_none() {
+            panic!("Task must have an assignee");
+        }
 
-        let mut tasks: Map<u64, Task> = env
+        // Update platform fees accumulator
+        let accumulated_fees: i128 = env
             .storage()
             .instance()
-            .get(&TASKS)
-            .unwrap_or(Map::new(&env));
-        let mut task = tasks
-            .get(task_id)
-            .unwrap_or_else(|| panic!("Task not found"));
-
-        // Check if caller is the creator
-        Self::require_creator(&creator, &task);
+            .get(&DataKey::PlatformFees)
+            .unwrap_or(0i128);
+        env.storage()
+            .instance()
+            .set(&DataKey::PlatformFees, &(accumulated_fees + platform_fee));
 
-        // Check if task is expired
-        if task.status != TaskStatus::Expired {
-            panic!("Task must be expired to reclaim funds");
+        // Update task status, settling any dispute
+        task.disputed = false;
+        task.status = if assignee_gross > 0 {
+            TaskStatus::FundsReleased
+        } else {
+            TaskStatus::Cancelled
+        };
+        Self::update_stats(env, |stats| {
+            stats.total_fees_earned += platform_fee;
+            stats.active_tasks = stats.active_tasks.saturating_sub(1);
+            if assignee_gross > 0 {
+                stats.completed_tasks += 1;
+            }
+            stats.total_paid += assignee_amount;
+            stats.total_refunded += refund_amount;
+        });
+        task.released_so_far += escrowed;
+        Self::record_financials(env, &task.creator, 0, assignee_gross, -escrowed);
+        Self::record_creator_fee(env, &task.creator, platform_fee);
+        if let Some(assignee) = &task.assignee {
+            Self::record_financials(env, assignee, assignee_amount, 0, 0);
         }
 
-        // Update task status to cancelled
-        task.status = TaskStatus::Cancelled;
+        // Lock the task until the transfer completes
+        task.processing = true;
 
-        // Store updated task
+        // Store updated task before transfers
         tasks.set(task_id, task.clone());
-        env.storage().instance().set(&TASKS, &tasks);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        Self::recall_from_vault(env, task_id);
 
-        // Refund creator
         let token_address: Address = env
             .storage()
             .instance()
-            .get(&TOKEN)
+            .get(&DataKey::Token)
             .expect("Token not initialized");
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &creator,
-            &task.funding_amount,
-        );
+        let token_client = token::Client::new(env, &token_address);
+        if assignee_amount > 0 {
+            let recipient = Self::payout_recipient(&task);
+            token_client.transfer(&env.current_contract_address(), &recipient, &assignee_amount);
+        }
+        if refund_amount > 0 {
+            let refund_to = Self::refund_recipient(&task);
+            token_client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        }
+        Self::finish_processing(env, task_id);
+
+        AdminResolved {
+            task_id,
+            deployer,
+            outcome,
+            assignee_amount,
+            refund_amount,
+            platform_fee,
+        }
+        .publish(env);
     }
 
-    /// Withdraw accumulated platform fees (only deployer can call)
-    ///
-    /// # Arguments
-    /// * `deployer` - Address of the contract deployer
-    pub fn withdraw_platform_fees(env: Env, deployer: Address) {
-        deployer.require_auth();
+    /// Address that receives a task's payout: the override if set, else the assignee
+    fn payout_recipient(task: &Task) -> Address {
+        match &task.payout_address {
+            Some(payout_address) => payout_address.clone(),
+            None => task.assignee.clone().expect("Task must have an assignee"),
+        }
+    }
 
-        // Verify caller is the deployer
-        let stored_deployer: Address = env
+    /// Address that receives a task's refunds: the override if set, else the creator
+    fn refund_recipient(task: &Task) -> Address {
+        task.refund_address.clone().unwrap_or_else(|| task.creator.clone())
+    }
+
+    /// Cancel a task and refund its remaining escrow (less any cancellation fee) to the creator
+    fn cancel_with_refund(env: &Env, mut task: Task) {
+        let refund_amount = Self::settle_cancellation(env, &mut task);
+
+        // Lock the task until the transfer completes
+        task.processing = true;
+
+        // Store updated task before refund
+        let task_id = task.id;
+        let creator = task.creator.clone();
+        let refund_to = Self::refund_recipient(&task);
+        let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&DEPLOYER)
-            .expect("Deployer not initialized");
-        
-        if stored_deployer != deployer {
-            panic!("Only deployer can withdraw platform fees");
-        }
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+        Self::recall_from_vault(env, task_id);
 
-        // Get accumulated fees
-        let accumulated_fees: i128 = env
+        // Refund creator
+        let token_address: Address = env
             .storage()
             .instance()
-            .get(&PLATFORM_FEES)
-            .unwrap_or(0i128);
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(env, &token_address);
+        token_client.transfer(
+            &env.current_contract_address(),
+            &refund_to,
+            &refund_amount,
+        );
+        Self::finish_processing(env, task_id);
 
-        if accumulated_fees <= 0 {
-            panic!("No platform fees to withdraw");
+        TaskCancelled {
+            task_id,
+            creator,
+            refund_amount,
         }
+        .publish(env);
+    }
 
-        // Reset platform fees accumulator
-        env.storage().instance().set(&PLATFORM_FEES, &0i128);
+    /// Mark a task cancelled and settle its escrow, returning the amount owed back to the creator
+    fn settle_cancellation(env: &Env, task: &mut Task) -> i128 {
+        // Only refund what is still held in escrow for this task, less any cancellation fee
+        let escrowed = Self::remaining_escrow(task);
+        let cancel_fee_bps = Self::get_cancel_fee_bps(env.clone());
+        let cancel_fee = escrowed * cancel_fee_bps as i128 / BPS_DENOMINATOR as i128;
 
-        // Transfer fees to deployer
+        // Update platform fees accumulator
+        if cancel_fee > 0 {
+            let accumulated_fees = Self::get_platform_fees(env.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::PlatformFees, &(accumulated_fees + cancel_fee));
+        }
+
+        // Update task status
+        task.status = TaskStatus::Cancelled;
+        task.released_so_far += escrowed;
+        Self::record_financials(env, &task.creator, 0, cancel_fee, -escrowed);
+        Self::update_stats(env, |stats| {
+            stats.total_fees_earned += cancel_fee;
+            stats.active_tasks = stats.active_tasks.saturating_sub(1);
+            stats.total_refunded += escrowed - cancel_fee;
+        });
+
+        escrowed - cancel_fee
+    }
+
+    /// Deposit a new task's escrow into the configured vault
+    ///
+    /// Failures are ignored so an unavailable vault leaves the escrow in the contract. Only
+    /// what the vault actually pulled is booked as deposited.
+    fn deposit_to_vault(env: &Env, task_id: u64, amount: i128) {
+        let vault: Address = match env.storage().instance().get(&DataKey::Vault) {
+            Some(vault) => vault,
+            None => return,
+        };
         let token_address: Address = env
             .storage()
             .instance()
-            .get(&TOKEN)
+            .get(&DataKey::Token)
             .expect("Token not initialized");
-        let token_client = token::Client::new(&env, &token_address);
-        token_client.transfer(
-            &env.current_contract_address(),
-            &deployer,
-            &accumulated_fees,
+
+        let token_client = token::Client::new(env, &token_address);
+        let balance_before = token_client.balance(&env.current_contract_address());
+
+        // Allow the vault to pull the escrow from this contract
+        env.authorize_as_current_contract(vec![
+            env,
+            InvokerContractAuthEntry::Contract(SubContractInvocation {
+                context: ContractContext {
+                    contract: token_address.clone(),
+                    fn_name: Symbol::new(env, "transfer"),
+                    args: (env.current_contract_address(), vault.clone(), amount).into_val(env),
+                },
+                sub_invocations: Vec::new(env),
+            }),
+        ]);
+        let deposited = env.try_invoke_contract::<(), InvokeError>(
+            &vault,
+            &Symbol::new(env, "deposit"),
+            (env.current_contract_address(), amount).into_val(env),
         );
-    }
+        let pulled = balance_before - token_client.balance(&env.current_contract_address());
+        if !matches!(deposited, Ok(Ok(()))) || pulled <= 0 {
+            return;
+        }
 
-    /// Get current accumulated platform fees
-    ///
-    /// # Returns
-    /// The total amount of accumulated platform fees
-    pub fn get_platform_fees(env: Env) -> i128 {
+        let mut tasks: Map<u64, Task> = env
+            .storage()
+            .instance()
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+        task.vault_deposit = pulled;
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
+
+        let vaulted: i128 = env.storage().instance().get(&DataKey::VaultBalance).unwrap_or(0);
         env.storage()
             .instance()
-            .get(&PLATFORM_FEES)
-            .unwrap_or(0i128)
+            .set(&DataKey::VaultBalance, &(vaulted + pulled));
     }
 
-    /// Reassign an expired task to a new assignee
+    /// Withdraw a task's escrow from the vault so it can be paid out
     ///
-    /// # Arguments
-    /// * `creator` - Address of the task creator
-    /// * `task_id` - ID of the task to reassign
-    /// * `new_assignee` - Address of the new assignee
-    pub fn reassign_task(env: Env, creator: Address, task_id: u64, new_assignee: Address) {
-        creator.require_auth();
-
+    /// The vault bookkeeping is only cleared once the tokens are back in the contract.
+    fn recall_from_vault(env: &Env, task_id: u64) {
         let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&TASKS)
-            .unwrap_or(Map::new(&env));
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
         let mut task = tasks
             .get(task_id)
             .unwrap_or_else(|| panic!("Task not found"));
+        let amount = task.vault_deposit;
+        if amount == 0 {
+            return;
+        }
 
-        // Check if caller is the creator
-        Self::require_creator(&creator, &task);
+        let vault: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Vault)
+            .expect("Vault not configured");
+        let token_address: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .expect("Token not initialized");
+        let token_client = token::Client::new(env, &token_address);
 
-        // Check if task is expired
-        if task.status != TaskStatus::Expired {
-            panic!("Task must be expired to reassign");
+        // Check the vault actually returned the escrow, not just that the call succeeded
+        let balance_before = token_client.balance(&env.current_contract_address());
+        let withdrawn = env.try_invoke_contract::<(), InvokeError>(
+            &vault,
+            &Symbol::new(env, "withdraw"),
+            (env.current_contract_address(), amount).into_val(env),
+        );
+        let received = token_client.balance(&env.current_contract_address()) - balance_before;
+        if !matches!(withdrawn, Ok(Ok(()))) || received < amount {
+            panic!("Vault withdrawal failed");
         }
 
-        // Update assignee and reset status
-        let old_assignee = task
-            .assignee
-            .clone()
-            .expect("Task must have an assignee");
-        task.assignee = Some(new_assignee.clone());
-        task.status = TaskStatus::Assigned;
-        task.assignee_approved = false;
-        task.creator_approved = false;
-        task.completed_at = None;
-
-        // Store updated task
+        task.vault_deposit = 0;
         tasks.set(task_id, task);
-        env.storage().instance().set(&TASKS, &tasks);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
 
-        // Update assigned tasks mapping
-        let mut assigned_tasks: Map<Address, Vec<u64>> = env
-            .storage()
+        let vaulted: i128 = env.storage().instance().get(&DataKey::VaultBalance).unwrap_or(0);
+        env.storage()
             .instance()
-            .get(&ASSIGNED_TASKS)
-            .unwrap_or(Map::new(&env));
+            .set(&DataKey::VaultBalance, &(vaulted - amount));
+    }
+
+    /// Slice a page out of a list of task IDs
+    fn page_ids(ids: Vec<u64>, start: u32, limit: u32) -> Vec<u64> {
+        let start = start.min(ids.len());
+        let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(ids.len());
+        ids.slice(start..end)
+    }
 
-        // Remove from old assignee's tasks
-        if let Some(mut old_tasks) = assigned_tasks.get(old_assignee.clone()) {
-            if let Some(index) = old_tasks.iter().position(|id| id == task_id) {
-                old_tasks.remove(index as u32);
-                assigned_tasks.set(old_assignee.clone(), old_tasks);
+    /// Add or remove a task in the ID-ordered index of tasks that reference a GitHub link
+    fn index_github_task(env: &Env, task_id: u64, linked: bool) {
+        let mut linked_tasks: Vec<u64> = env
+            .storage()
+            .instance()
+            .get(&DataKey::GithubTasks)
+            .unwrap_or(Vec::new(env));
+        match (linked_tasks.first_index_of(task_id), linked) {
+            (None, true) => {
+                let position = linked_tasks.iter().position(|id| id > task_id);
+                match position {
+                    Some(position) => linked_tasks.insert(position as u32, task_id),
+                    None => linked_tasks.push_back(task_id),
+                }
+            }
+            (Some(position), false) => {
+                linked_tasks.remove(position);
             }
+            _ => return,
         }
+        env.storage().instance().set(&DataKey::GithubTasks, &linked_tasks);
+    }
 
-        // Add to new assignee's tasks
-        let mut new_tasks = assigned_tasks
-            .get(new_assignee.clone())
-            .unwrap_or(Vec::new(&env));
-        new_tasks.push_back(task_id);
-        assigned_tasks.set(new_assignee.clone(), new_tasks);
+    /// Append a task to a user's task index
+    fn add_to_index(env: &Env, key: &DataKey, task_id: u64) {
+        let mut user_tasks: Vec<u64> = env.storage().instance().get(key).unwrap_or(Vec::new(env));
+        user_tasks.push_back(task_id);
+        env.storage().instance().set(key, &user_tasks);
+    }
 
-        env.storage().instance().set(&ASSIGNED_TASKS, &assigned_tasks);
+    /// Remove a task from a user's task index, deleting the entry once empty
+    fn remove_from_index(env: &Env, key: &DataKey, task_id: u64) {
+        let user_tasks: Option<Vec<u64>> = env.storage().instance().get(key);
+        if let Some(mut user_tasks) = user_tasks {
+            if let Some(position) = user_tasks.iter().position(|id| id == task_id) {
+                user_tasks.remove(position as u32);
+                if user_tasks.is_empty() {
+                    env.storage().instance().remove(key);
+                } else {
+                    env.storage().instance().set(key, &user_tasks);
+                }
+            }
+        }
     }
 
-    /// Get task details by ID
-    ///
-    /// # Arguments
-    /// * `task_id` - ID of the task to retrieve
-    ///
-    /// # Returns
-    /// The task details
-    pub fn get_task(env: Env, task_id: u64) -> Task {
-        let tasks: Map<u64, Task> = env
+    /// Reject assigning a task that is reserved for a committed assignee
+    fn require_no_commitment(env: &Env, task_id: u64) {
+        let commitments: Map<u64, BytesN<32>> = env
             .storage()
             .instance()
-            .get(&TASKS)
-            .unwrap_or(Map::new(&env));
-        tasks
-            .get(task_id)
-            .unwrap_or_else(|| panic!("Task not found"))
+            .get(&DataKey::Commitments)
+            .unwrap_or(Map::new(env));
+        if commitments.contains_key(task_id) {
+            panic!("Task is reserved for a committed assignee");
+        }
     }
 
-    /// Get all tasks created by a user
-    ///
-    /// # Arguments
-    /// * `user` - Address of the user
-    ///
-    /// # Returns
-    /// Vector of task IDs created by the user
-    pub fn get_user_tasks(env: Env, user: Address) -> Vec<u64> {
-        let user_tasks: Map<Address, Vec<u64>> = env
-            .storage()
-            .instance()
-            .get(&USER_TASKS)
-            .unwrap_or(Map::new(&env));
-        user_tasks.get(user).unwrap_or(Vec::new(&env))
+    /// Reject operations on a task whose escrow transfer has not finished
+    fn require_not_processing(task: &Task) {
+        if task.processing {
+            panic!("Task is being processed");
+        }
     }
 
-    /// Get all tasks assigned to a user
-    ///
-    /// # Arguments
-    /// * `user` - Address of the user
-    ///
-    /// # Returns
-    /// Vector of task IDs assigned to the user
-    pub fn get_assigned_tasks(env: Env, user: Address) -> Vec<u64> {
-        let assigned_tasks: Map<Address, Vec<u64>> = env
+    /// Reject changes to a task whose funds are frozen by an open dispute
+    fn require_not_disputed(task: &Task) {
+        if task.disputed {
+            panic!("Task is under dispute");
+        }
+    }
+
+    /// Clear a task's processing lock once its transfers have completed
+    fn finish_processing(env: &Env, task_id: u64) {
+        let mut tasks: Map<u64, Task> = env
             .storage()
             .instance()
-            .get(&ASSIGNED_TASKS)
-            .unwrap_or(Map::new(&env));
-        assigned_tasks.get(user).unwrap_or(Vec::new(&env))
+            .get(&DataKey::Tasks)
+            .unwrap_or(Map::new(env));
+        let mut task = tasks
+            .get(task_id)
+            .unwrap_or_else(|| panic!("Task not found"));
+        task.processing = false;
+        tasks.set(task_id, task);
+        env.storage().instance().set(&DataKey::Tasks, &tasks);
     }
 
-    /// Get total number of tasks
-    pub fn get_task_count(env: Env) -> u64 {
-        env.storage()
-            .instance()
-            .get(&TASK_COUNTER)
-            .unwrap_or(1u64)
-            - 1
+    /// Escrow still locked across all open tasks, from the running totals
+    fn locked_escrow(env: &Env) -> i128 {
+        let stats = Self::get_stats(env.clone());
+        stats.total_escrowed - stats.total_paid - stats.total_refunded - stats.total_fees_earned
     }
 
-    // Helper functions
+    /// Amount of a task's funding still held in escrow by the contract
+    fn remaining_escrow(task: &Task) -> i128 {
+        // Nothing has been escrowed for a pledge yet
+        if task.pledged {
+            return 0;
+        }
+        let remaining = task.funding_amount - task.released_so_far;
+        if remaining < 0 {
+            panic!("Task escrow is overdrawn");
+        }
+        remaining
+    }
 
-    /// Validate task creation parameters
-    fn validate_task_creation(
-        env: &Env,
-        title: &String,
-        description: &String,
-        funding_amount: i128,
-        deadline: u64,
-    ) {
-        if title.len() == 0 {
-            panic!("Title cannot be empty");
+    /// Count the tasks an assignee is currently working on
+    fn count_active_assignments(env: &Env, assignee: &Address) -> u32 {
+        let (active, _) = Self::get_assignee_workload(env.clone(), assignee.clone());
+        active as u32
+    }
+
+    /// Whether an assignee has room for another active task
+    fn has_assignee_capacity(env: &Env, assignee: &Address) -> bool {
+        let cap = Self::get_max_active_assignments(env.clone());
+        cap == 0 || Self::count_active_assignments(env, assignee) < cap
+    }
+
+    /// Check that an assignee has room for another active task
+    fn require_assignee_capacity(env: &Env, assignee: &Address) {
+        if !Self::has_assignee_capacity(env, assignee) {
+            panic!("Assignee at capacity");
         }
-        if description.len() == 0 {
-            panic!("Description cannot be empty");
+    }
+
+    /// Reject the deployer as an assignee when the self-dealing policy is enabled
+    fn require_eligible_assignee(env: &Env, assignee: &Address) {
+        if !Self::get_block_deployer_assignee(env.clone()) {
+            return;
         }
-        if funding_amount <= 0 {
-            panic!("Funding amount must be positive");
+        let deployer: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Deployer)
+            .expect("Deployer not initialized");
+        if deployer == *assignee {
+            panic!("Deployer cannot be an assignee");
         }
-        if deadline <= env.ledger().timestamp() {
-            panic!("Deadline must be in the future");
+    }
+
+    /// Check that the caller is the authorized contract deployer
+    fn require_deployer(env: &Env, deployer: &Address) {
+        deployer.require_auth();
+
+        let stored_deployer: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Deployer)
+            .expect("Deployer not initialized");
+
+        if stored_deployer != *deployer {
+            panic!("Only deployer can perform this action");
         }
     }
 
@@ -926,12 +5396,16 @@ impl TaskMaster {
     fn require_assignee(assignee: &Address, task: &Task) {
         match &task.assignee {
             Some(addr) if *addr == *assignee => {},
+            _ if task.previous_assignees.contains(assignee) => {
+                panic!("Task has been reassigned")
+            }
             _ => panic!("Only task assignee can perform this action"),
         }
     }
 
     /// Check if task is in valid state
     fn require_valid_state(task: &Task, valid_states: &[TaskStatus]) {
+        Self::require_not_processing(task);
         if !valid_states.contains(&task.status) {
             panic!("Task is not in valid state for this operation");
         }
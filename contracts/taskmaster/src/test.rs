@@ -2,12 +2,17 @@
 extern crate std;
 
 use soroban_sdk::{
+    contract, contractimpl, symbol_short,
     testutils::{Address as _, Ledger},
-    token, Address, Env, String as SorobanString, Vec,
+    token, xdr::ToXdr, Address, Bytes, BytesN, Env, Map, String as SorobanString, Vec,
 };
 
 // Import from the contract module
-use crate::contract::{TaskMaster, TaskMasterClient, TaskStatus};
+use crate::contract::{
+    AdminOutcome, AssigneeReputation, DataKey, DisputeFeePolicy, ExpiryAction, FeePayer,
+    FundingBreakdown, LedgerSummary, RelistParams, Settings, SlaStatus, Stats, Task, TaskError,
+    TaskMaster, TaskMasterClient, TaskOptions, TaskStatus, WithdrawalAction,
+};
 
 // Mock token contract for testing
 fn create_token_contract<'a>(e: &Env, admin: &Address) -> (token::Client<'a>, token::StellarAssetClient<'a>) {
@@ -23,7 +28,10 @@ fn create_taskmaster_client<'a>(e: &Env) -> (TaskMasterClient<'a>, token::Client
     let contract_id = e.register(TaskMaster, ());
     let client = TaskMasterClient::new(e, &contract_id);
 
-    client.initialize(&token_client.address, &admin);
+    client.initialize(&token_client.address, &admin, &Vec::new(e), &0);
+
+    // Tests use short deadlines; the default minimum duration has its own test
+    client.set_min_task_duration(&admin, &0);
 
     (client, token_client, token_admin_client, admin)
 }
@@ -32,6 +40,88 @@ fn mint_tokens(token_admin_client: &token::StellarAssetClient, to: &Address, amo
     token_admin_client.mint(to, &amount);
 }
 
+// Modify a stored task directly, bypassing the contract's entry points
+fn update_stored_task(
+    e: &Env,
+    client: &TaskMasterClient,
+    task_id: u64,
+    update: impl FnOnce(&mut Task),
+) {
+    e.as_contract(&client.address, || {
+        let key = DataKey::Tasks;
+        let mut tasks: Map<u64, Task> = e.storage().instance().get(&key).unwrap();
+        let mut task = tasks.get(task_id).unwrap();
+        update(&mut task);
+        tasks.set(task_id, task);
+        e.storage().instance().set(&key, &tasks);
+    });
+}
+
+// Overwrite the stored next task ID, as a faulty migration might
+fn set_task_counter(e: &Env, client: &TaskMasterClient, next_id: u64) {
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::TaskCounter, &next_id);
+    });
+}
+
+fn default_options(e: &Env) -> TaskOptions {
+    TaskOptions {
+        priority: 0,
+        auto_release: false,
+        depends_on: Vec::new(e),
+        client_ref: None,
+        notify_on_release: false,
+        oracle: None,
+        oracle_fn: None,
+        expiry_action: ExpiryAction::Refund,
+        accept_window: 0,
+    }
+}
+
+// Create a funded task with options and assign it straight away
+fn create_assigned_task_with_options(
+    e: &Env,
+    client: &TaskMasterClient,
+    creator: &Address,
+    assignee: &Address,
+    funding_amount: i128,
+    deadline: u64,
+    options: &TaskOptions,
+) -> u64 {
+    let task_id = client.create_task_with_options(
+        creator,
+        &SorobanString::from_str(e, "Test Task"),
+        &SorobanString::from_str(e, "Test Description"),
+        &SorobanString::from_str(e, ""),
+        &funding_amount,
+        &deadline,
+        options,
+    );
+    client.assign_task(creator, &task_id, assignee);
+    task_id
+}
+
+// Create a funded task and assign it straight away
+fn create_assigned_task(
+    e: &Env,
+    client: &TaskMasterClient,
+    creator: &Address,
+    assignee: &Address,
+    funding_amount: i128,
+    deadline: u64,
+) -> u64 {
+    let task_id = client.create_task(
+        creator,
+        &SorobanString::from_str(e, "Test Task"),
+        &SorobanString::from_str(e, "Test Description"),
+        &SorobanString::from_str(e, ""),
+        &funding_amount,
+        &deadline,
+    );
+    client.assign_task(creator, &task_id, assignee);
+    task_id
+}
+
 #[test]
 fn test_initialize() {
     let e = Env::default();
@@ -43,7 +133,7 @@ fn test_initialize() {
     let client = TaskMasterClient::new(&e, &contract_id);
 
     // Should initialize successfully
-    client.initialize(&token_client.address, &admin);
+    client.initialize(&token_client.address, &admin, &Vec::new(&e), &0);
 
     // Verify task counter is set to 1
     assert_eq!(client.get_task_count(), 0);
@@ -63,9 +153,9 @@ fn test_initialize_twice_fails() {
     let contract_id = e.register(TaskMaster, ());
     let client = TaskMasterClient::new(&e, &contract_id);
 
-    client.initialize(&token_client.address, &admin);
+    client.initialize(&token_client.address, &admin, &Vec::new(&e), &0);
     // Should panic on second initialization
-    client.initialize(&token_client.address, &admin);
+    client.initialize(&token_client.address, &admin, &Vec::new(&e), &0);
 }
 
 #[test]
@@ -89,11 +179,11 @@ fn test_create_task() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &deadline,
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     assert_eq!(task_id, 1);
 
@@ -140,11 +230,11 @@ fn test_create_task_with_github_link() {
         &creator,
         &title,
         &description,
-        &github_link,
+        &github_link.clone().unwrap(),
         &funding_amount,
         &deadline,
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     let task = client.get_task(&task_id);
     assert_eq!(task.github_link, github_link);
@@ -158,7 +248,6 @@ fn test_create_task_empty_title_fails() {
 
     let (client, _token_client, token_admin_client, _) = create_taskmaster_client(&e);
     let creator = Address::generate(&e);
-    let assignee = Address::generate(&e);
 
     mint_tokens(&token_admin_client, &creator, 10_000_000);
 
@@ -169,10 +258,9 @@ fn test_create_task_empty_title_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
 }
 
@@ -184,7 +272,6 @@ fn test_create_task_empty_description_fails() {
 
     let (client, _token_client, token_admin_client, _) = create_taskmaster_client(&e);
     let creator = Address::generate(&e);
-    let assignee = Address::generate(&e);
 
     mint_tokens(&token_admin_client, &creator, 10_000_000);
 
@@ -195,10 +282,9 @@ fn test_create_task_empty_description_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
 }
 
@@ -210,7 +296,6 @@ fn test_create_task_zero_funding_fails() {
 
     let (client, _token_client, token_admin_client, _) = create_taskmaster_client(&e);
     let creator = Address::generate(&e);
-    let assignee = Address::generate(&e);
 
     mint_tokens(&token_admin_client, &creator, 10_000_000);
 
@@ -221,10 +306,9 @@ fn test_create_task_zero_funding_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &0i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
 }
 
@@ -236,7 +320,6 @@ fn test_create_task_past_deadline_fails() {
 
     let (client, _token_client, token_admin_client, _) = create_taskmaster_client(&e);
     let creator = Address::generate(&e);
-    let assignee = Address::generate(&e);
 
     mint_tokens(&token_admin_client, &creator, 10_000_000);
 
@@ -247,10 +330,9 @@ fn test_create_task_past_deadline_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp().saturating_sub(86400)), // Past deadline by 1 day
-        &assignee,
     );
 }
 
@@ -272,11 +354,11 @@ fn test_start_task() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Start the task
     client.start_task(&assignee, &task_id);
@@ -303,11 +385,11 @@ fn test_complete_task() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Start and complete the task
     client.start_task(&assignee, &task_id);
@@ -338,14 +420,15 @@ fn test_complete_task_invalid_state_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
-    // Complete task twice should fail
+    // Completing a task whose funds were released should fail
     client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
     client.complete_task(&assignee, &task_id);
 }
 
@@ -368,11 +451,11 @@ fn test_release_funds() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Complete the task
     client.complete_task(&assignee, &task_id);
@@ -414,11 +497,11 @@ fn test_release_funds_without_completion_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Try to release funds without completion
     client.release_funds(&creator, &task_id);
@@ -443,11 +526,11 @@ fn test_cancel_task() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Cancel the task
     client.cancel_task(&creator, &task_id);
@@ -478,11 +561,11 @@ fn test_cancel_completed_task_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Complete the task
     client.complete_task(&assignee, &task_id);
@@ -510,11 +593,11 @@ fn test_mark_expired() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &deadline,
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Advance time past deadline
     e.ledger().with_mut(|li| {
@@ -547,11 +630,11 @@ fn test_mark_expired_before_deadline_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Try to mark as expired before deadline
     client.mark_expired(&task_id);
@@ -577,11 +660,11 @@ fn test_reclaim_expired_funds() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &deadline,
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Advance time past deadline
     e.ledger().with_mut(|li| {
@@ -592,7 +675,7 @@ fn test_reclaim_expired_funds() {
     client.mark_expired(&task_id);
 
     // Reclaim funds
-    client.reclaim_expired_funds(&creator, &task_id);
+    client.reclaim_expired_funds(&task_id);
 
     let task = client.get_task(&task_id);
     assert_eq!(task.status, TaskStatus::Cancelled);
@@ -621,11 +704,11 @@ fn test_reassign_task() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &deadline,
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Advance time past deadline
     e.ledger().with_mut(|li| {
@@ -670,11 +753,11 @@ fn test_reassign_non_expired_task_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Try to reassign non-expired task
     client.reassign_task(&creator, &task_id, &new_assignee);
@@ -700,21 +783,21 @@ fn test_get_user_tasks() {
         &creator,
         &title1,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee1,
     );
+    client.assign_task(&creator, &task_id1, &assignee1);
 
     let task_id2 = client.create_task(
         &creator,
         &title2,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &2_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee2,
     );
+    client.assign_task(&creator, &task_id2, &assignee2);
 
     let user_tasks = client.get_user_tasks(&creator);
     assert_eq!(user_tasks.len(), 2);
@@ -741,21 +824,21 @@ fn test_get_assigned_tasks() {
         &creator,
         &title1,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id1, &assignee);
 
     let task_id2 = client.create_task(
         &creator,
         &title2,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &2_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id2, &assignee);
 
     let assigned_tasks = client.get_assigned_tasks(&assignee);
     assert_eq!(assigned_tasks.len(), 2);
@@ -784,11 +867,11 @@ fn test_complete_task_lifecycle() {
         &creator,
         &title,
         &description,
-        &github_link,
+        &github_link.clone().unwrap(),
         &funding_amount,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     let task = client.get_task(&task_id);
     assert_eq!(task.status, TaskStatus::Assigned);
@@ -837,11 +920,11 @@ fn test_complete_expired_task_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &deadline,
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Advance time past deadline
     e.ledger().with_mut(|li| {
@@ -870,15 +953,15 @@ fn test_task_count() {
 
     // Create 3 tasks
     for _ in 0..3 {
-        client.create_task(
+        let task_id = client.create_task(
             &creator,
             &title,
             &description,
-            &None,
+            &SorobanString::from_str(&e, ""),
             &1_000_000i128,
             &(e.ledger().timestamp() + 86400),
-            &assignee,
         );
+        client.assign_task(&creator, &task_id, &assignee);
     }
 
     assert_eq!(client.get_task_count(), 3);
@@ -903,11 +986,11 @@ fn test_withdraw_platform_fees() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Complete and release funds to generate platform fees
     client.complete_task(&assignee, &task_id);
@@ -947,11 +1030,11 @@ fn test_withdraw_platform_fees_unauthorized_fails() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Complete and release funds to generate platform fees
     client.complete_task(&assignee, &task_id);
@@ -992,21 +1075,21 @@ fn test_multiple_tasks_platform_fees() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &1_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id1, &assignee);
 
     let task_id2 = client.create_task(
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &2_000_000i128,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id2, &assignee);
 
     // Complete both tasks
     client.complete_task(&assignee, &task_id1);
@@ -1050,11 +1133,11 @@ fn test_platform_fee_small_amount() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Complete and release funds
     client.complete_task(&assignee, &task_id);
@@ -1095,11 +1178,11 @@ fn test_platform_fee_large_amount() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Complete and release funds
     client.complete_task(&assignee, &task_id);
@@ -1139,11 +1222,11 @@ fn test_multiple_platform_fee_withdrawals() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount1,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id1, &assignee);
 
     // Complete and release funds for first task
     client.complete_task(&assignee, &task_id1);
@@ -1167,11 +1250,11 @@ fn test_multiple_platform_fee_withdrawals() {
         &creator,
         &title2,
         &description2,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount2,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id2, &assignee);
 
     // Complete and release funds for second task
     client.complete_task(&assignee, &task_id2);
@@ -1216,11 +1299,11 @@ fn test_platform_fee_accumulation_many_tasks() {
             &creator,
             &title,
             &description,
-            &None,
+            &SorobanString::from_str(&e, ""),
             &funding_amount,
             &(e.ledger().timestamp() + 86400),
-            &assignee,
         );
+        client.assign_task(&creator, &task_id, &assignee);
         task_ids.push_back(task_id);
     }
 
@@ -1270,11 +1353,11 @@ fn test_no_platform_fee_for_cancelled_task() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Cancel the task
     client.cancel_task(&creator, &task_id);
@@ -1306,11 +1389,11 @@ fn test_no_platform_fee_for_expired_task() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount,
         &deadline,
-        &assignee,
     );
+    client.assign_task(&creator, &task_id, &assignee);
 
     // Advance time past deadline
     e.ledger().with_mut(|li| {
@@ -1321,7 +1404,7 @@ fn test_no_platform_fee_for_expired_task() {
     client.mark_expired(&task_id);
 
     // Reclaim expired funds
-    client.reclaim_expired_funds(&creator, &task_id);
+    client.reclaim_expired_funds(&task_id);
 
     // Verify no platform fees were charged
     assert_eq!(client.get_platform_fees(), 0);
@@ -1366,11 +1449,11 @@ fn test_platform_fee_calculation_precision() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount1,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id1, &assignee);
     client.complete_task(&assignee, &task_id1);
     client.release_funds(&creator, &task_id1);
 
@@ -1379,11 +1462,11 @@ fn test_platform_fee_calculation_precision() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount2,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id2, &assignee);
     client.complete_task(&assignee, &task_id2);
     client.release_funds(&creator, &task_id2);
 
@@ -1392,11 +1475,11 @@ fn test_platform_fee_calculation_precision() {
         &creator,
         &title,
         &description,
-        &None,
+        &SorobanString::from_str(&e, ""),
         &funding_amount3,
         &(e.ledger().timestamp() + 86400),
-        &assignee,
     );
+    client.assign_task(&creator, &task_id3, &assignee);
     client.complete_task(&assignee, &task_id3);
     client.release_funds(&creator, &task_id3);
 
@@ -1422,4 +1505,6019 @@ fn test_platform_fee_calculation_precision() {
     let total_expected_assignee_amount = expected_assignee_amount1 + expected_assignee_amount2 + expected_assignee_amount3;
     
     assert_eq!(token_client.balance(&assignee), total_expected_assignee_amount);
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_simulate_release_matches_release() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    let amounts = [100i128, 333i128, 1_000_000i128, 10_000_000_000i128];
+    mint_tokens(&token_admin_client, &creator, 20_000_000_000);
+
+    for funding_amount in amounts {
+        let task_id = create_assigned_task(
+            &e,
+            &client,
+            &creator,
+            &assignee,
+            funding_amount,
+            e.ledger().timestamp() + 86400,
+        );
+        client.complete_task(&assignee, &task_id);
+
+        let balance_before = token_client.balance(&assignee);
+        let outcome = client.simulate_release(&task_id);
+
+        // Simulation must not change anything
+        assert_eq!(client.get_task(&task_id).status, TaskStatus::Completed);
+        assert_eq!(outcome.assignee, assignee);
+
+        client.release_funds(&creator, &task_id);
+
+        assert_eq!(
+            token_client.balance(&assignee) - balance_before,
+            outcome.assignee_amount
+        );
+        assert_eq!(outcome.assignee_amount + outcome.platform_fee, funding_amount);
+        assert_eq!(client.get_platform_fees(), outcome.accumulated_fees);
+    }
+}
+
+#[test]
+#[should_panic(expected = "Task is not in valid state for this operation")]
+fn test_simulate_release_before_completion_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    client.simulate_release(&task_id);
+}
+
+#[test]
+fn test_assigned_tasks_by_priority_ordering() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let now = e.ledger().timestamp();
+    let mut options = default_options(&e);
+
+    options.priority = 0;
+    let low = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        now + 1000,
+        &options,
+    );
+    options.priority = 3;
+    let urgent_late = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        now + 5000,
+        &options,
+    );
+    let urgent_soon = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        now + 2000,
+        &options,
+    );
+    let finished = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        now + 100,
+        &options,
+    );
+    options.priority = 1;
+    let medium = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        now + 500,
+        &options,
+    );
+
+    // Completed tasks drop out of the queue, in-progress ones stay
+    client.complete_task(&assignee, &finished);
+    client.start_task(&assignee, &medium);
+
+    assert_eq!(client.get_task(&urgent_soon).priority, 3);
+
+    let ordered = client.get_assigned_tasks_by_priority(&assignee);
+    assert_eq!(ordered.len(), 4);
+    assert_eq!(ordered.get(0).unwrap(), urgent_soon);
+    assert_eq!(ordered.get(1).unwrap(), urgent_late);
+    assert_eq!(ordered.get(2).unwrap(), medium);
+    assert_eq!(ordered.get(3).unwrap(), low);
+}
+
+#[test]
+#[should_panic(expected = "Invalid priority")]
+fn test_create_task_invalid_priority_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.priority = 4;
+    create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        e.ledger().timestamp() + 1000,
+        &options,
+    );
+}
+
+#[test]
+fn test_auto_release_pays_on_completion() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    let mut options = default_options(&e);
+    options.auto_release = true;
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+        &options,
+    );
+
+    client.complete_task(&assignee, &task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::FundsReleased);
+    assert!(task.creator_approved);
+    assert!(task.completed_at.is_some());
+
+    let platform_fee = funding_amount * 3i128 / 100i128;
+    assert_eq!(token_client.balance(&assignee), funding_amount - platform_fee);
+    assert_eq!(client.get_platform_fees(), platform_fee);
+}
+
+#[test]
+fn test_without_auto_release_completion_waits_for_release() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+    );
+
+    client.complete_task(&assignee, &task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Completed);
+    assert!(!task.auto_release);
+    assert_eq!(token_client.balance(&assignee), 0);
+    assert_eq!(token_client.balance(&client.address), funding_amount);
+
+    client.release_funds(&creator, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::FundsReleased);
+}
+
+#[test]
+#[should_panic(expected = "Release timeout has not elapsed")]
+fn test_claim_after_timeout_too_early_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    client.set_release_timeout(&admin, &3600);
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp += 3599;
+    });
+
+    client.claim_after_timeout(&assignee, &task_id);
+}
+
+#[test]
+fn test_claim_after_timeout_pays_assignee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    client.set_release_timeout(&admin, &3600);
+    assert_eq!(client.get_release_timeout(), 3600);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp += 3600;
+    });
+
+    client.claim_after_timeout(&assignee, &task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::FundsReleased);
+    assert!(!task.creator_approved);
+
+    let platform_fee = funding_amount * 3i128 / 100i128;
+    assert_eq!(token_client.balance(&assignee), funding_amount - platform_fee);
+    assert_eq!(client.get_platform_fees(), platform_fee);
+}
+
+#[test]
+#[should_panic(expected = "Task has unfinished dependencies")]
+fn test_start_task_with_unmet_dependency_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let dependency = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+
+    let mut options = default_options(&e);
+    options.depends_on.push_back(dependency);
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        deadline,
+        &options,
+    );
+
+    assert_eq!(client.get_blocking_tasks(&task_id), Vec::from_array(&e, [dependency]));
+    client.start_task(&assignee, &task_id);
+}
+
+#[test]
+fn test_start_task_after_dependencies_finish() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let completed_dep = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let released_dep = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+
+    let mut options = default_options(&e);
+    options.depends_on.push_back(completed_dep);
+    options.depends_on.push_back(released_dep);
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        deadline,
+        &options,
+    );
+    assert_eq!(client.get_blocking_tasks(&task_id).len(), 2);
+
+    client.complete_task(&assignee, &completed_dep);
+    client.complete_task(&assignee, &released_dep);
+    client.release_funds(&creator, &released_dep);
+
+    assert!(client.get_blocking_tasks(&task_id).is_empty());
+    client.start_task(&assignee, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::InProgress);
+}
+
+#[test]
+#[should_panic(expected = "Dependency task not found")]
+fn test_create_task_with_missing_dependency_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.depends_on.push_back(42);
+    create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        100_000,
+        e.ledger().timestamp() + 86400,
+        &options,
+    );
+}
+
+// Set up a contract whose fee withdrawals need 2 of 3 signer approvals
+fn create_multisig_taskmaster_client<'a>(
+    e: &Env,
+) -> (TaskMasterClient<'a>, token::StellarAssetClient<'a>, Address, Vec<Address>) {
+    let admin = Address::generate(e);
+    let (_token_client, token_admin_client) = create_token_contract(e, &admin);
+    let contract_id = e.register(TaskMaster, ());
+    let client = TaskMasterClient::new(e, &contract_id);
+
+    let signers = Vec::from_array(
+        e,
+        [Address::generate(e), Address::generate(e), Address::generate(e)],
+    );
+    client.initialize(&token_admin_client.address, &admin, &signers, &2);
+
+    // Generate some platform fees
+    let creator = Address::generate(e);
+    let assignee = Address::generate(e);
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    let task_id = create_assigned_task(
+        e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    (client, token_admin_client, admin, signers)
+}
+
+#[test]
+#[should_panic(expected = "Insufficient withdrawal approvals")]
+fn test_multisig_withdrawal_single_approval_insufficient() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_admin_client, admin, signers) = create_multisig_taskmaster_client(&e);
+
+    let action = WithdrawalAction::PlatformFees(admin.clone(), 30_000);
+    let request_id = client.propose_withdrawal(&admin, &action);
+    client.approve_withdrawal(&signers.get(0).unwrap(), &request_id);
+    client.withdraw_platform_fees(&admin);
+}
+
+#[test]
+fn test_multisig_withdrawal_with_two_approvals() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_admin_client, admin, signers) = create_multisig_taskmaster_client(&e);
+    let token_client = token::Client::new(&e, &token_admin_client.address);
+    let expected_platform_fee = 1_000_000i128 * 3i128 / 100i128;
+
+    let action = WithdrawalAction::PlatformFees(admin.clone(), expected_platform_fee);
+    let request_id = client.propose_withdrawal(&admin, &action);
+    client.approve_withdrawal(&signers.get(0).unwrap(), &request_id);
+    client.approve_withdrawal(&signers.get(2).unwrap(), &request_id);
+    assert_eq!(client.get_withdrawal_request(&request_id).unwrap().approvals.len(), 2);
+
+    client.withdraw_platform_fees(&admin);
+
+    assert_eq!(token_client.balance(&admin), expected_platform_fee);
+    assert_eq!(client.get_platform_fees(), 0);
+    // The request is used up by the withdrawal
+    assert_eq!(client.get_withdrawal_request(&request_id), None);
+}
+
+#[test]
+#[should_panic(expected = "Not an authorized signer")]
+fn test_multisig_non_signer_approval_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_admin_client, admin, _signers) = create_multisig_taskmaster_client(&e);
+
+    let action = WithdrawalAction::PlatformFees(admin.clone(), 30_000);
+    let request_id = client.propose_withdrawal(&admin, &action);
+    client.approve_withdrawal(&Address::generate(&e), &request_id);
+}
+
+#[test]
+fn test_get_tasks_completed_between() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let early = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let middle = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let late = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let unfinished = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+
+    let start = e.ledger().timestamp();
+    client.complete_task(&assignee, &early);
+    e.ledger().with_mut(|li| {
+        li.timestamp = start + 1000;
+    });
+    client.complete_task(&assignee, &middle);
+    client.release_funds(&creator, &middle);
+    e.ledger().with_mut(|li| {
+        li.timestamp = start + 2000;
+    });
+    client.complete_task(&assignee, &late);
+
+    let in_range = client
+        .get_tasks_completed_between(&(start + 500), &(start + 2000), &0, &10)
+        .tasks;
+    assert_eq!(in_range.len(), 2);
+    assert_eq!(in_range.get(0).unwrap().id, middle);
+    assert_eq!(in_range.get(1).unwrap().id, late);
+
+    let all = client.get_tasks_completed_between(&start, &(start + 2000), &0, &10);
+    assert_eq!(all.tasks.len(), 3);
+    assert!(all.tasks.iter().all(|task| task.id != unfinished));
+    assert_eq!(all.next_cursor, None);
+
+    let limited = client.get_tasks_completed_between(&start, &(start + 2000), &0, &1);
+    assert_eq!(limited.tasks.len(), 1);
+    assert_eq!(limited.tasks.get(0).unwrap().id, early);
+    assert_eq!(limited.next_cursor, Some(early + 1));
+}
+
+#[test]
+fn test_cancel_after_partial_release_refunds_remaining() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    let partial = 400_000i128;
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+    );
+
+    // Simulate a partial payout leaving the contract
+    token_client.transfer(&client.address, &assignee, &partial);
+    update_stored_task(&e, &client, task_id, |task| task.released_so_far = partial);
+
+    client.cancel_task(&creator, &task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Cancelled);
+    assert_eq!(task.released_so_far, funding_amount);
+    assert_eq!(
+        token_client.balance(&creator),
+        10_000_000 - funding_amount + (funding_amount - partial)
+    );
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_reclaim_after_partial_release_refunds_remaining() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    let partial = 250_000i128;
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, funding_amount, deadline);
+
+    token_client.transfer(&client.address, &assignee, &partial);
+    update_stored_task(&e, &client, task_id, |task| task.released_so_far = partial);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+    client.reclaim_expired_funds(&task_id);
+
+    assert_eq!(
+        token_client.balance(&creator),
+        10_000_000 - funding_amount + (funding_amount - partial)
+    );
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_get_task_parties() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let open_task = client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Open Task"),
+        &SorobanString::from_str(&e, "Nobody assigned yet"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000i128,
+        &(e.ledger().timestamp() + 86400),
+    );
+    assert_eq!(client.get_task_creator(&open_task), creator);
+    assert_eq!(client.get_task_assignee(&open_task), None);
+
+    let assigned_task = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    assert_eq!(client.get_task_creator(&assigned_task), creator);
+    assert_eq!(client.get_task_assignee(&assigned_task), Some(assignee));
+}
+
+#[test]
+#[should_panic(expected = "Task not found")]
+fn test_get_task_creator_missing_task_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, _token_admin_client, _admin) = create_taskmaster_client(&e);
+
+    client.get_task_creator(&99);
+}
+
+#[test]
+#[should_panic(expected = "Task not found")]
+fn test_get_task_assignee_missing_task_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, _token_admin_client, _admin) = create_taskmaster_client(&e);
+
+    client.get_task_assignee(&99);
+}
+
+#[test]
+fn test_create_task_for_funder_receives_refund() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let manager = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &treasury, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    let task_id = client.create_task_for(
+        &manager,
+        &treasury,
+        &SorobanString::from_str(&e, "Delegated Task"),
+        &SorobanString::from_str(&e, "Funded by the treasury"),
+        &SorobanString::from_str(&e, ""),
+        &funding_amount,
+        &(e.ledger().timestamp() + 86400),
+    );
+
+    // Funds come from the treasury, which is recorded as creator
+    assert_eq!(client.get_task_creator(&task_id), treasury);
+    assert_eq!(token_client.balance(&treasury), 10_000_000 - funding_amount);
+    assert!(client.get_user_tasks(&treasury).contains(&task_id));
+    assert!(client.get_user_tasks(&manager).is_empty());
+
+    client.cancel_task(&treasury, &task_id);
+
+    assert_eq!(token_client.balance(&treasury), 10_000_000);
+    assert_eq!(token_client.balance(&manager), 0);
+}
+
+#[test]
+#[should_panic(expected = "Only task creator can perform this action")]
+fn test_create_task_for_manager_cannot_cancel() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let manager = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &treasury, 10_000_000);
+
+    let task_id = client.create_task_for(
+        &manager,
+        &treasury,
+        &SorobanString::from_str(&e, "Delegated Task"),
+        &SorobanString::from_str(&e, "Funded by the treasury"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000i128,
+        &(e.ledger().timestamp() + 86400),
+    );
+
+    client.cancel_task(&manager, &task_id);
+}
+
+#[test]
+fn test_scan_cursor_respects_scan_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 1_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let first = create_assigned_task(&e, &client, &creator, &assignee, 1_000, deadline);
+    let second = create_assigned_task(&e, &client, &creator, &assignee, 1_000, deadline);
+
+    // Leave a wide gap of unused IDs so the next task lands beyond the scan cap
+    set_task_counter(&e, &client, 150);
+    let far = create_assigned_task(&e, &client, &creator, &assignee, 1_000, deadline);
+    assert_eq!(far, 150);
+
+    client.complete_task(&assignee, &first);
+    client.complete_task(&assignee, &second);
+    client.complete_task(&assignee, &far);
+    let now = e.ledger().timestamp();
+
+    // First page stops after examining 100 IDs
+    let page = client.get_tasks_completed_between(&now, &now, &1, &50);
+    assert_eq!(page.tasks.len(), 2);
+    assert_eq!(page.next_cursor, Some(101));
+
+    // Resuming from the cursor picks up the remaining match
+    let page = client.get_tasks_completed_between(&now, &now, &101, &50);
+    assert_eq!(page.tasks.len(), 1);
+    assert_eq!(page.tasks.get(0).unwrap().id, far);
+    assert_eq!(page.next_cursor, None);
+
+    // A limit reached mid-window returns a cursor just past the last match
+    let page = client.get_tasks_completed_between(&now, &now, &1, &1);
+    assert_eq!(page.tasks.len(), 1);
+    assert_eq!(page.next_cursor, Some(second));
+}
+
+#[test]
+fn test_update_completion_proof() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    assert_eq!(client.get_task(&task_id).completion_proof, None);
+
+    let wrong = SorobanString::from_str(&e, "https://github.com/example/repo/pull/1");
+    let fixed = SorobanString::from_str(&e, "https://github.com/example/repo/pull/2");
+    client.update_completion_proof(&assignee, &task_id, &wrong);
+    client.update_completion_proof(&assignee, &task_id, &fixed);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.completion_proof, Some(fixed));
+    assert_eq!(task.status, TaskStatus::Completed);
+}
+
+#[test]
+#[should_panic(expected = "Task is not in valid state for this operation")]
+fn test_update_completion_proof_after_release_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    client.update_completion_proof(
+        &assignee,
+        &task_id,
+        &SorobanString::from_str(&e, "https://github.com/example/repo/pull/3"),
+    );
+}
+
+#[test]
+fn test_admin_resolve_pays_assignee_from_in_progress() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+    );
+    client.start_task(&assignee, &task_id);
+
+    client.admin_resolve(&admin, &task_id, &AdminOutcome::PayAssignee);
+
+    let platform_fee = funding_amount * 3i128 / 100i128;
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::FundsReleased);
+    assert_eq!(token_client.balance(&assignee), funding_amount - platform_fee);
+    assert_eq!(client.get_platform_fees(), platform_fee);
+    assert_eq!(token_client.balance(&client.address), platform_fee);
+}
+
+#[test]
+fn test_admin_resolve_refunds_creator_from_expired() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    client.admin_resolve(&admin, &task_id, &AdminOutcome::RefundCreator);
+
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Cancelled);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+    assert_eq!(token_client.balance(&assignee), 0);
+    assert_eq!(client.get_platform_fees(), 0);
+}
+
+#[test]
+fn test_admin_resolve_split_from_completed() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+
+    client.admin_resolve(&admin, &task_id, &AdminOutcome::Split(60));
+
+    let assignee_gross = funding_amount * 60 / 100;
+    let platform_fee = assignee_gross * 3i128 / 100i128;
+    assert_eq!(token_client.balance(&assignee), assignee_gross - platform_fee);
+    assert_eq!(
+        token_client.balance(&creator),
+        10_000_000 - funding_amount + (funding_amount - assignee_gross)
+    );
+    assert_eq!(client.get_platform_fees(), platform_fee);
+}
+
+#[test]
+#[should_panic(expected = "Only deployer can perform this action")]
+fn test_admin_resolve_non_deployer_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    client.admin_resolve(&creator, &task_id, &AdminOutcome::RefundCreator);
+}
+
+#[test]
+fn test_get_task_amount_decimals() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    // Stellar asset contracts always use 7 decimals
+    assert_eq!(token_client.decimals(), 7);
+    assert_eq!(client.get_task_amount_decimals(&task_id), 7);
+    assert_eq!(client.get_task(&task_id).token_decimals, 7);
+}
+
+#[test]
+fn test_withdraw_platform_fees_to_recipient() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let recipient = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    let platform_fee = 1_000_000i128 * 3i128 / 100i128;
+    client.withdraw_platform_fees_to(&admin, &recipient, &10_000);
+
+    assert_eq!(token_client.balance(&recipient), 10_000);
+    assert_eq!(token_client.balance(&admin), 0);
+    assert_eq!(client.get_platform_fees(), platform_fee - 10_000);
+
+    // The remainder can still be withdrawn to the deployer
+    client.withdraw_platform_fees(&admin);
+    assert_eq!(token_client.balance(&admin), platform_fee - 10_000);
+    assert_eq!(client.get_platform_fees(), 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient platform fees")]
+fn test_withdraw_platform_fees_to_exceeding_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    client.withdraw_platform_fees_to(&admin, &Address::generate(&e), &30_001);
+}
+
+#[test]
+fn test_expired_refund_distinguishable_from_cancellation() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let cancelled = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let expired = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    client.cancel_task(&creator, &cancelled);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&expired);
+    client.reclaim_expired_funds(&expired);
+
+    let cancelled_task = client.get_task(&cancelled);
+    let expired_task = client.get_task(&expired);
+    assert_eq!(cancelled_task.status, TaskStatus::Cancelled);
+    assert_eq!(expired_task.status, TaskStatus::Cancelled);
+    assert!(!cancelled_task.was_expired);
+    assert!(expired_task.was_expired);
+}
+
+#[test]
+fn test_is_initialized() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let (token_client, _) = create_token_contract(&e, &admin);
+    let contract_id = e.register(TaskMaster, ());
+    let client = TaskMasterClient::new(&e, &contract_id);
+
+    assert!(!client.is_initialized());
+
+    client.initialize(&token_client.address, &admin, &Vec::new(&e), &0);
+
+    assert!(client.is_initialized());
+}
+
+#[test]
+fn test_assignee_capacity_frees_up_after_completion() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    client.set_max_active_assignments(&admin, &2);
+    assert_eq!(client.get_max_active_assignments(), 2);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let first = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let second = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    client.start_task(&assignee, &second);
+
+    // Completing a task frees a slot
+    client.complete_task(&assignee, &first);
+    let third = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    assert_eq!(client.get_task_assignee(&third), Some(assignee));
+}
+
+#[test]
+#[should_panic(expected = "Assignee at capacity")]
+fn test_assign_beyond_capacity_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    client.set_max_active_assignments(&admin, &2);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+}
+
+#[test]
+#[should_panic(expected = "Assignee at capacity")]
+fn test_reassign_to_assignee_at_capacity_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let busy = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    client.set_max_active_assignments(&admin, &1);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    create_assigned_task(&e, &client, &creator, &busy, 100_000, deadline + 86400);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+    client.reassign_task(&creator, &task_id, &busy);
+}
+
+#[test]
+fn test_release_funds_with_tip() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_000_000i128;
+    let tip = 250_000i128;
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+
+    client.release_funds_with_tip(&creator, &task_id, &tip);
+
+    // Fee is charged on the escrowed funding only
+    let platform_fee = funding_amount * 3i128 / 100i128;
+    assert_eq!(client.get_platform_fees(), platform_fee);
+    assert_eq!(token_client.balance(&assignee), funding_amount - platform_fee + tip);
+    assert_eq!(token_client.balance(&creator), 10_000_000 - funding_amount - tip);
+    assert_eq!(token_client.balance(&client.address), platform_fee);
+}
+
+#[test]
+#[should_panic(expected = "Tip cannot be negative")]
+fn test_release_funds_with_negative_tip_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+
+    client.release_funds_with_tip(&creator, &task_id, &-1);
+}
+
+#[test]
+fn test_get_user_financials() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    assert_eq!(client.get_user_financials(&creator), (0, 0, 0));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let released_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let cancelled_id = create_assigned_task(&e, &client, &creator, &assignee, 2_000_000, deadline);
+    create_assigned_task(&e, &client, &creator, &assignee, 3_000_000, deadline);
+    assert_eq!(client.get_user_financials(&creator), (0, 0, 6_000_000));
+
+    client.complete_task(&assignee, &released_id);
+    client.release_funds_with_tip(&creator, &released_id, &100_000);
+    client.cancel_task(&creator, &cancelled_id);
+
+    // Assignee earns the payout after the 3% fee plus the tip
+    assert_eq!(client.get_user_financials(&creator), (0, 1_100_000, 3_000_000));
+    assert_eq!(client.get_user_financials(&assignee), (1_070_000, 0, 0));
+}
+
+#[test]
+fn test_get_user_financials_after_expiry_and_admin_split() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let expired_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let split_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        2_000_000,
+        deadline + 86400,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&expired_id);
+    client.reclaim_expired_funds(&expired_id);
+    client.admin_resolve(&admin, &split_id, &AdminOutcome::Split(50));
+
+    assert_eq!(client.get_user_financials(&creator), (0, 1_000_000, 0));
+    assert_eq!(client.get_user_financials(&assignee), (970_000, 0, 0));
+}
+
+#[test]
+fn test_reassign_to_current_assignee_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    let result = client.try_reassign_task(&creator, &task_id, &assignee);
+    assert!(result.is_err());
+
+    // The rejected reassignment leaves the task and assigned list untouched
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Expired);
+    assert_eq!(task.assignee, Some(assignee.clone()));
+    let assigned = client.get_assigned_tasks(&assignee);
+    assert_eq!(assigned.len(), 1);
+    assert_eq!(assigned.get(0).unwrap(), task_id);
+}
+
+#[test]
+fn test_check_solvency() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let released_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    create_assigned_task(&e, &client, &creator, &assignee, 2_000_000, deadline);
+
+    client.complete_task(&assignee, &released_id);
+    client.release_funds(&creator, &released_id);
+
+    // Remaining escrow plus the 3% fee retained from the release
+    assert_eq!(client.check_solvency(), (2_030_000, 2_030_000, true));
+}
+
+#[test]
+fn test_check_solvency_detects_shortfall() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    // Drain part of the escrow out from under the books
+    token_client.transfer(&client.address, &Address::generate(&e), &500_000);
+
+    assert_eq!(client.check_solvency(), (500_000, 1_000_000, false));
+}
+
+#[test]
+fn test_create_task_github_link_normalization() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let unlinked_id = client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &deadline,
+    );
+    let link = SorobanString::from_str(&e, "https://github.com/example/repo");
+    let linked_id = client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &link,
+        &1_000_000,
+        &deadline,
+    );
+
+    assert_eq!(client.get_task(&unlinked_id).github_link, None);
+    assert_eq!(client.get_task(&linked_id).github_link, Some(link));
+}
+
+#[test]
+#[should_panic(expected = "Invalid GitHub link")]
+fn test_create_task_malformed_github_link_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, "github.com/example/repo"),
+        &1_000_000,
+        &(e.ledger().timestamp() + 86400),
+    );
+}
+
+#[test]
+fn test_transfer_creatorship() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let new_creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let released_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let cancelled_id = create_assigned_task(&e, &client, &creator, &assignee, 2_000_000, deadline);
+
+    client.transfer_creatorship(&creator, &released_id, &new_creator);
+    client.transfer_creatorship(&creator, &cancelled_id, &new_creator);
+
+    assert_eq!(client.get_task_creator(&released_id), new_creator);
+    assert_eq!(client.get_user_tasks(&creator).len(), 0);
+    let new_tasks = client.get_user_tasks(&new_creator);
+    assert_eq!(new_tasks.len(), 2);
+    assert_eq!(new_tasks.get(0).unwrap(), released_id);
+    assert_eq!(new_tasks.get(1).unwrap(), cancelled_id);
+
+    // The new creator holds release and refund rights
+    client.complete_task(&assignee, &released_id);
+    client.release_funds(&new_creator, &released_id);
+    client.cancel_task(&new_creator, &cancelled_id);
+
+    assert_eq!(token_client.balance(&new_creator), 2_000_000);
+    assert_eq!(client.get_task(&released_id).status, TaskStatus::FundsReleased);
+    assert_eq!(client.get_user_financials(&creator), (0, 0, 0));
+    assert_eq!(client.get_user_financials(&new_creator), (0, 1_000_000, 0));
+}
+
+#[test]
+#[should_panic(expected = "Only task creator can perform this action")]
+fn test_old_creator_cannot_cancel_after_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let new_creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.transfer_creatorship(&creator, &task_id, &new_creator);
+
+    client.cancel_task(&creator, &task_id);
+}
+
+#[test]
+#[should_panic(expected = "Task is not in valid state for this operation")]
+fn test_transfer_creatorship_terminal_task_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let new_creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.cancel_task(&creator, &task_id);
+
+    client.transfer_creatorship(&creator, &task_id, &new_creator);
+}
+
+#[test]
+fn test_add_comment() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    let question = SorobanString::from_str(&e, "Which branch should I target?");
+    let answer = SorobanString::from_str(&e, "Open the PR against main");
+    client.add_comment(&assignee, &task_id, &question);
+    e.ledger().with_mut(|li| {
+        li.timestamp += 60;
+    });
+    client.add_comment(&creator, &task_id, &answer);
+
+    let comments = client.get_comments(&task_id);
+    assert_eq!(comments.len(), 2);
+    let first = comments.get(0).unwrap();
+    assert_eq!(first.author, assignee);
+    assert_eq!(first.text, question);
+    let second = comments.get(1).unwrap();
+    assert_eq!(second.author, creator);
+    assert_eq!(second.text, answer);
+    assert_eq!(second.posted_at, first.posted_at + 60);
+}
+
+#[test]
+#[should_panic(expected = "Only task creator or assignee can comment")]
+fn test_add_comment_unrelated_party_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    client.add_comment(&stranger, &task_id, &SorobanString::from_str(&e, "Hello"));
+}
+
+#[test]
+fn test_assignee_cancel() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.start_task(&assignee, &task_id);
+
+    client.assignee_cancel(&assignee, &task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Cancelled);
+    assert!(task.abandoned);
+    assert!(!task.was_expired);
+
+    // Full refund, no platform fee
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+    assert_eq!(client.get_platform_fees(), 0);
+    assert_eq!(
+        client.get_reputation(&assignee),
+        AssigneeReputation {
+            completed: 0,
+            failed: 1,
+        }
+    );
+}
+
+#[test]
+#[should_panic(expected = "Only task assignee can perform this action")]
+fn test_assignee_cancel_by_creator_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    client.assignee_cancel(&creator, &task_id);
+}
+
+#[test]
+fn test_create_task_in() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = 1_000;
+    });
+    let task_id = client.create_task_in(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &86400,
+        &Some(assignee.clone()),
+    );
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.deadline, 1_000 + 86400);
+    assert_eq!(task.status, TaskStatus::Assigned);
+    assert_eq!(task.assignee, Some(assignee.clone()));
+    assert_eq!(client.get_assigned_tasks(&assignee).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Duration must be positive")]
+fn test_create_task_in_zero_duration_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    client.create_task_in(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &0,
+        &None,
+    );
+}
+
+#[test]
+fn test_get_recent_tasks() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 100_000_000);
+
+    assert_eq!(client.get_recent_tasks(&10).len(), 0);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    for _ in 0..12 {
+        client.create_task(
+            &creator,
+            &SorobanString::from_str(&e, "Test Task"),
+            &SorobanString::from_str(&e, "Test Description"),
+            &SorobanString::from_str(&e, ""),
+            &1_000_000,
+            &deadline,
+        );
+    }
+
+    let recent = client.get_recent_tasks(&3);
+    assert_eq!(recent.len(), 3);
+    assert_eq!(recent.get(0).unwrap().id, 12);
+    assert_eq!(recent.get(1).unwrap().id, 11);
+    assert_eq!(recent.get(2).unwrap().id, 10);
+
+    // Requests beyond the cap return the 10 newest tasks
+    let capped = client.get_recent_tasks(&50);
+    assert_eq!(capped.len(), 10);
+    assert_eq!(capped.get(0).unwrap().id, 12);
+    assert_eq!(capped.get(9).unwrap().id, 3);
+}
+
+#[test]
+#[should_panic(expected = "Task has been reassigned")]
+fn test_complete_task_after_reassignment_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let old_assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &old_assignee, 1_000_000, deadline);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+    client.reassign_task(&creator, &task_id, &new_assignee);
+    assert_eq!(client.get_task(&task_id).previous_assignees.len(), 1);
+
+    client.complete_task(&old_assignee, &task_id);
+}
+
+#[test]
+fn test_native_xlm_lifecycle() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    // Stellar Asset Contract standing in for the native XLM wrapper
+    let deployer = Address::generate(&e);
+    let native = e.register_stellar_asset_contract_v2(Address::generate(&e));
+    let xlm_client = token::Client::new(&e, &native.address());
+    let xlm_admin_client = token::StellarAssetClient::new(&e, &native.address());
+    let client = TaskMasterClient::new(&e, &e.register(TaskMaster, ()));
+    client.initialize(&native.address(), &deployer, &Vec::new(&e), &0);
+
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    mint_tokens(&xlm_admin_client, &creator, 500_000_000);
+
+    // 10 XLM in stroops
+    let funding_amount = 100_000_000i128;
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+    );
+    assert_eq!(client.get_task_amount_decimals(&task_id), 7);
+    assert_eq!(xlm_client.balance(&client.address), funding_amount);
+
+    client.start_task(&assignee, &task_id);
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    let platform_fee = funding_amount * 3 / 100;
+    assert_eq!(xlm_client.balance(&assignee), funding_amount - platform_fee);
+    assert_eq!(client.check_solvency(), (platform_fee, platform_fee, true));
+
+    client.withdraw_platform_fees(&deployer);
+    assert_eq!(xlm_client.balance(&deployer), platform_fee);
+    assert_eq!(xlm_client.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Insufficient balance to fund task")]
+fn test_create_task_insufficient_balance_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 999_999);
+
+    client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(e.ledger().timestamp() + 86400),
+    );
+}
+
+#[test]
+fn test_reassign_and_extend() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let old_assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &old_assignee, 1_000_000, deadline);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    let new_deadline = deadline + 86400;
+    client.reassign_and_extend(&creator, &task_id, &new_assignee, &new_deadline);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Assigned);
+    assert_eq!(task.assignee, Some(new_assignee.clone()));
+    assert_eq!(task.deadline, new_deadline);
+    assert_eq!(client.get_assigned_tasks(&old_assignee).len(), 0);
+    assert_eq!(client.get_assigned_tasks(&new_assignee).len(), 1);
+
+    // The new assignee can work within the extended deadline
+    client.start_task(&new_assignee, &task_id);
+    client.complete_task(&new_assignee, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Completed);
+}
+
+#[test]
+#[should_panic(expected = "Deadline must be in the future")]
+fn test_reassign_and_extend_past_deadline_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let old_assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &old_assignee, 1_000_000, deadline);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    client.reassign_and_extend(&creator, &task_id, &new_assignee, &deadline);
+}
+
+#[test]
+fn test_min_task_duration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    assert_eq!(client.get_min_task_duration(), 0);
+    client.set_min_task_duration(&admin, &3600);
+    assert_eq!(client.get_min_task_duration(), 3600);
+
+    let now = e.ledger().timestamp();
+    let too_soon = client.try_create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(now + 3599),
+    );
+    assert!(too_soon.is_err());
+
+    let task_id = client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(now + 3600),
+    );
+    assert_eq!(client.get_task(&task_id).deadline, now + 3600);
+}
+
+#[test]
+fn test_default_min_task_duration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let creator = Address::generate(&e);
+    let (token_client, token_admin_client) = create_token_contract(&e, &admin);
+    let contract_id = e.register(TaskMaster, ());
+    let client = TaskMasterClient::new(&e, &contract_id);
+    client.initialize(&token_client.address, &admin, &Vec::new(&e), &0);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // A fresh deployment rejects deadlines less than an hour away
+    assert_eq!(client.get_min_task_duration(), 3600);
+    let now = e.ledger().timestamp();
+    let title = SorobanString::from_str(&e, "Test Task");
+    let description = SorobanString::from_str(&e, "Test Description");
+    let link = SorobanString::from_str(&e, "");
+    assert!(client
+        .try_create_task(&creator, &title, &description, &link, &1_000_000, &(now + 3599))
+        .is_err());
+    client.create_task(&creator, &title, &description, &link, &1_000_000, &(now + 3600));
+}
+
+#[test]
+#[should_panic(expected = "Deadline is sooner than the minimum task duration")]
+fn test_create_task_inside_min_duration_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    client.set_min_task_duration(&admin, &3600);
+
+    client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(e.ledger().timestamp() + 60),
+    );
+}
+
+#[test]
+fn test_get_assignee_workload() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    assert_eq!(client.get_assignee_workload(&assignee), (0, 0));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let first_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let second_id = create_assigned_task(&e, &client, &creator, &assignee, 2_000_000, deadline);
+    create_assigned_task(&e, &client, &creator, &assignee, 3_000_000, deadline);
+    client.start_task(&assignee, &second_id);
+
+    assert_eq!(client.get_assignee_workload(&assignee), (3, 6_000_000));
+
+    client.complete_task(&assignee, &first_id);
+    assert_eq!(client.get_assignee_workload(&assignee), (2, 5_000_000));
+
+    client.release_funds(&creator, &first_id);
+    client.complete_task(&assignee, &second_id);
+    assert_eq!(client.get_assignee_workload(&assignee), (1, 3_000_000));
+}
+
+#[test]
+fn test_require_github_link() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let link = SorobanString::from_str(&e, "https://github.com/example/repo");
+    let no_link = SorobanString::from_str(&e, "");
+    let title = SorobanString::from_str(&e, "Test Task");
+    let description = SorobanString::from_str(&e, "Test Description");
+
+    // Disabled by default: both forms are accepted
+    assert!(!client.get_require_github_link());
+    client.create_task(&creator, &title, &description, &no_link, &1_000_000, &deadline);
+    client.create_task(&creator, &title, &description, &link, &1_000_000, &deadline);
+
+    client.set_require_github_link(&admin, &true);
+    assert!(client.get_require_github_link());
+    let unlinked =
+        client.try_create_task(&creator, &title, &description, &no_link, &1_000_000, &deadline);
+    assert!(unlinked.is_err());
+    let task_id = client.create_task(&creator, &title, &description, &link, &1_000_000, &deadline);
+    assert_eq!(client.get_task(&task_id).github_link, Some(link));
+}
+
+#[test]
+#[should_panic(expected = "GitHub link required")]
+fn test_create_task_without_required_github_link_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    client.set_require_github_link(&admin, &true);
+
+    client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(e.ledger().timestamp() + 86400),
+    );
+}
+
+#[test]
+fn test_get_disputed_tasks() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let first_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let third_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    client.raise_dispute(&creator, &first_id);
+    client.complete_task(&assignee, &third_id);
+    client.raise_dispute(&assignee, &third_id);
+
+    let disputed = client.get_disputed_tasks(&1, &10);
+    assert_eq!(disputed.tasks.len(), 2);
+    assert_eq!(disputed.tasks.get(0).unwrap().id, first_id);
+    assert_eq!(disputed.tasks.get(1).unwrap().id, third_id);
+    assert_eq!(disputed.next_cursor, None);
+
+    let paged = client.get_disputed_tasks(&2, &10);
+    assert_eq!(paged.tasks.len(), 1);
+    assert_eq!(paged.tasks.get(0).unwrap().id, third_id);
+
+    // A limit reached early returns a cursor to resume from
+    let first_page = client.get_disputed_tasks(&1, &1);
+    assert_eq!(first_page.tasks.len(), 1);
+    assert_eq!(first_page.next_cursor, Some(first_id + 1));
+}
+
+#[test]
+fn test_get_disputed_tasks_pages_past_scan_cap() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // Leave a wide gap of unused IDs so the disputed task lands beyond the scan cap
+    let deadline = e.ledger().timestamp() + 86400;
+    set_task_counter(&e, &client, 150);
+    let far = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.raise_dispute(&creator, &far);
+
+    let page = client.get_disputed_tasks(&1, &10);
+    assert_eq!(page.tasks.len(), 0);
+    assert_eq!(page.next_cursor, Some(101));
+
+    let page = client.get_disputed_tasks(&101, &10);
+    assert_eq!(page.tasks.get(0).unwrap().id, far);
+    assert_eq!(page.next_cursor, None);
+}
+
+#[test]
+#[should_panic(expected = "Task is under dispute")]
+fn test_release_funds_while_disputed_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.raise_dispute(&assignee, &task_id);
+
+    client.release_funds(&creator, &task_id);
+}
+
+#[test]
+fn test_complete_task_twice_is_noop() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    let completed_at = client.get_task(&task_id).completed_at;
+
+    e.ledger().with_mut(|li| {
+        li.timestamp += 60;
+    });
+    client.complete_task(&assignee, &task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Completed);
+    assert_eq!(task.completed_at, completed_at);
+}
+
+#[test]
+fn test_release_funds_to_payout_address() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let cold_wallet = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let redirected_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let default_id = create_assigned_task(&e, &client, &creator, &assignee, 2_000_000, deadline);
+
+    client.set_payout_address(&assignee, &redirected_id, &cold_wallet);
+    assert_eq!(client.get_task(&redirected_id).payout_address, Some(cold_wallet.clone()));
+
+    client.complete_task(&assignee, &redirected_id);
+    client.release_funds(&creator, &redirected_id);
+    assert_eq!(token_client.balance(&cold_wallet), 970_000);
+    assert_eq!(token_client.balance(&assignee), 0);
+
+    // Without an override the assignee is paid directly
+    client.complete_task(&assignee, &default_id);
+    client.release_funds(&creator, &default_id);
+    assert_eq!(token_client.balance(&assignee), 1_940_000);
+    assert_eq!(token_client.balance(&cold_wallet), 970_000);
+}
+
+#[test]
+#[should_panic(expected = "Task is not in valid state for this operation")]
+fn test_set_payout_address_after_release_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    client.set_payout_address(&assignee, &task_id, &Address::generate(&e));
+}
+
+#[test]
+fn test_preview_release_with_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let funding_amount = 1_234_567i128;
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        funding_amount,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+
+    for bps in [0u32, 150, 300, 500, 10_000] {
+        let fee = funding_amount * bps as i128 / 10_000;
+        assert_eq!(
+            client.preview_release_with_fee(&task_id, &bps),
+            (funding_amount - fee, fee)
+        );
+    }
+
+    // The current 3% rate matches the simulated release
+    let outcome = client.simulate_release(&task_id);
+    assert_eq!(
+        client.preview_release_with_fee(&task_id, &300),
+        (outcome.assignee_amount, outcome.platform_fee)
+    );
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Completed);
+}
+
+#[test]
+#[should_panic(expected = "Fee rate cannot exceed 100%")]
+fn test_preview_release_with_excessive_fee_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    client.preview_release_with_fee(&task_id, &10_001);
+}
+
+#[test]
+fn test_release_funds_clears_processing_lock() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let released_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let cancelled_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    client.complete_task(&assignee, &released_id);
+    client.release_funds(&creator, &released_id);
+    client.cancel_task(&creator, &cancelled_id);
+
+    let released = client.get_task(&released_id);
+    assert_eq!(released.status, TaskStatus::FundsReleased);
+    assert!(!released.processing);
+    assert!(!client.get_task(&cancelled_id).processing);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+}
+
+#[test]
+#[should_panic(expected = "Task is being processed")]
+fn test_operation_on_processing_task_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    // Simulate a transfer still in flight on this task
+    update_stored_task(&e, &client, task_id, |task| {
+        task.processing = true;
+    });
+
+    client.cancel_task(&creator, &task_id);
+}
+
+#[test]
+fn test_get_pending_approvals() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let other_creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    mint_tokens(&token_admin_client, &other_creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let released_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let pending_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let other_id = create_assigned_task(
+        &e,
+        &client,
+        &other_creator,
+        &assignee,
+        1_000_000,
+        deadline,
+    );
+
+    client.complete_task(&assignee, &released_id);
+    client.complete_task(&assignee, &pending_id);
+    client.complete_task(&assignee, &other_id);
+    client.release_funds(&creator, &released_id);
+
+    let pending = client.get_pending_approvals(&creator);
+    assert_eq!(pending.len(), 1);
+    assert_eq!(pending.get(0).unwrap(), pending_id);
+}
+
+#[test]
+fn test_update_task_details() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    let title = SorobanString::from_str(&e, "Fix login bug");
+    let description = SorobanString::from_str(&e, "Users on Safari cannot sign in");
+    let link = SorobanString::from_str(&e, "https://github.com/example/repo/issues/1");
+    client.update_task_details(&creator, &task_id, &title, &description, &link);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.title, title);
+    assert_eq!(task.description, description);
+    assert_eq!(task.github_link, Some(link));
+    assert_eq!(task.status, TaskStatus::Assigned);
+}
+
+#[test]
+#[should_panic(expected = "Task is not in valid state for this operation")]
+fn test_update_task_details_in_progress_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.start_task(&assignee, &task_id);
+
+    client.update_task_details(
+        &creator,
+        &task_id,
+        &SorobanString::from_str(&e, "Bigger scope"),
+        &SorobanString::from_str(&e, "Now also rewrite the backend"),
+        &SorobanString::from_str(&e, ""),
+    );
+}
+
+#[test]
+fn test_cancel_task_with_cancel_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let free_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let charged_id = create_assigned_task(&e, &client, &creator, &assignee, 2_000_000, deadline);
+
+    // Free by default
+    assert_eq!(client.get_cancel_fee_bps(), 0);
+    client.cancel_task(&creator, &free_id);
+    assert_eq!(token_client.balance(&creator), 8_000_000);
+    assert_eq!(client.get_platform_fees(), 0);
+
+    // 1% of the escrow goes to the platform fee pool
+    client.set_cancel_fee_bps(&admin, &100);
+    client.cancel_task(&creator, &charged_id);
+    assert_eq!(token_client.balance(&creator), 8_000_000 + 1_980_000);
+    assert_eq!(client.get_platform_fees(), 20_000);
+    assert_eq!(client.check_solvency(), (20_000, 20_000, true));
+}
+
+#[test]
+#[should_panic(expected = "Fee rate cannot exceed 100%")]
+fn test_set_cancel_fee_above_full_escrow_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, _token_admin_client, admin) = create_taskmaster_client(&e);
+
+    client.set_cancel_fee_bps(&admin, &10_001);
+}
+
+#[test]
+fn test_get_task_ids_in_range() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    for _ in 0..5 {
+        client.create_task(
+            &creator,
+            &SorobanString::from_str(&e, "Test Task"),
+            &SorobanString::from_str(&e, "Test Description"),
+            &SorobanString::from_str(&e, ""),
+            &1_000_000,
+            &deadline,
+        );
+    }
+
+    // Leave a gap where task 3 used to be
+    e.as_contract(&client.address, || {
+        let key = DataKey::Tasks;
+        let mut tasks: Map<u64, Task> = e.storage().instance().get(&key).unwrap();
+        tasks.remove(3);
+        e.storage().instance().set(&key, &tasks);
+    });
+
+    let ids = client.get_task_ids_in_range(&2, &6);
+    assert_eq!(ids.len(), 3);
+    assert_eq!(ids.get(0).unwrap(), 2);
+    assert_eq!(ids.get(1).unwrap(), 4);
+    assert_eq!(ids.get(2).unwrap(), 5);
+
+    // Range past the last task and an empty range
+    assert_eq!(client.get_task_ids_in_range(&5, &1_000).len(), 1);
+    assert_eq!(client.get_task_ids_in_range(&2, &2).len(), 0);
+}
+
+#[test]
+fn test_reclaim_overdue_task_without_mark_expired() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.start_task(&assignee, &task_id);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.reclaim_expired_funds(&task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Cancelled);
+    assert!(task.was_expired);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Task must be expired to reclaim funds")]
+fn test_reclaim_before_deadline_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 100,
+    );
+
+    client.reclaim_expired_funds(&task_id);
+}
+
+#[test]
+fn test_get_settings() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, _token_admin_client, admin) = create_taskmaster_client(&e);
+
+    client.set_release_timeout(&admin, &3600);
+    client.set_max_active_assignments(&admin, &2);
+    client.set_min_task_duration(&admin, &600);
+    client.set_require_github_link(&admin, &true);
+    client.set_cancel_fee_bps(&admin, &50);
+    client.set_priority_fee_bps(&admin, &2, &500);
+
+    assert_eq!(
+        client.get_settings(),
+        Settings {
+            token: token_client.address.clone(),
+            deployer: admin,
+            platform_fee_percentage: 3,
+            cancel_fee_bps: 50,
+            release_timeout: 3600,
+            min_task_duration: 600,
+            max_active_assignments: 2,
+            require_github_link: true,
+            withdrawal_threshold: 0,
+            vault: None,
+            min_fee: 0,
+            block_deployer_assignee: false,
+            restrict_expiry: false,
+            fee_token: None,
+            dispute_fee_policy: DisputeFeePolicy::NoFeeOnRefund,
+            min_work_time: 0,
+            fee_treasury: None,
+            priority_fee_bps: Vec::from_array(&e, [300, 300, 500, 300]),
+        }
+    );
+}
+
+#[test]
+fn test_request_and_finalize_cancel() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 7 * 86400,
+    );
+    client.start_task(&assignee, &task_id);
+    client.request_cancel(&creator, &task_id);
+
+    // Too early: the assignee still has time to deliver
+    e.ledger().with_mut(|li| {
+        li.timestamp += 86400 - 1;
+    });
+    assert!(client.try_finalize_cancel(&creator, &task_id).is_err());
+
+    e.ledger().with_mut(|li| {
+        li.timestamp += 1;
+    });
+    client.finalize_cancel(&creator, &task_id);
+
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Cancelled);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+}
+
+#[test]
+fn test_assignee_salvages_task_after_cancel_request() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 7 * 86400,
+    );
+    client.request_cancel(&creator, &task_id);
+    client.complete_task(&assignee, &task_id);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp += 86400;
+    });
+    assert!(client.try_finalize_cancel(&creator, &task_id).is_err());
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Completed);
+    assert_eq!(task.cancel_requested_at, None);
+    client.release_funds(&creator, &task_id);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+}
+
+#[test]
+fn test_emergency_withdraw_recovers_orphaned_tokens() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    // Tokens sent straight to the contract, outside any task
+    mint_tokens(&token_admin_client, &client.address, 250_000);
+
+    client.emergency_withdraw(&admin, &250_000);
+
+    assert_eq!(token_client.balance(&admin), 250_000);
+    assert_eq!(client.check_solvency(), (1_000_000, 1_000_000, true));
+}
+
+#[test]
+#[should_panic(expected = "Amount exceeds recoverable balance")]
+fn test_emergency_withdraw_from_escrow_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    mint_tokens(&token_admin_client, &client.address, 250_000);
+
+    client.emergency_withdraw(&admin, &250_001);
+}
+
+#[test]
+fn test_set_token_without_outstanding_escrows() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // Settle everything held in the old token
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+    client.withdraw_platform_fees(&admin);
+
+    let (new_token_client, new_token_admin_client) = create_token_contract(&e, &admin);
+    client.set_token(&admin, &new_token_client.address);
+    assert_eq!(client.get_settings().token, new_token_client.address);
+
+    // New tasks are funded in the new token
+    mint_tokens(&new_token_admin_client, &creator, 5_000_000);
+    create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        2_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    assert_eq!(new_token_client.balance(&client.address), 2_000_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+#[should_panic(expected = "Outstanding escrows in current token")]
+fn test_set_token_with_outstanding_escrow_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+
+    client.set_token(&admin, &Address::generate(&e));
+}
+
+#[test]
+fn test_create_task_with_client_ref_is_idempotent() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let create = |client_ref: &str| {
+        let mut options = default_options(&e);
+        options.client_ref = Some(SorobanString::from_str(&e, client_ref));
+        client.create_task_with_options(
+            &creator,
+            &SorobanString::from_str(&e, "Test Task"),
+            &SorobanString::from_str(&e, "Test Description"),
+            &SorobanString::from_str(&e, ""),
+            &1_000_000,
+            &(e.ledger().timestamp() + 86400),
+            &options,
+        )
+    };
+
+    let first_id = create("order-1");
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+
+    // A retry with the same ref returns the same task without re-funding
+    assert_eq!(create("order-1"), first_id);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+    assert_eq!(client.get_task_count(), 1);
+
+    let second_id = create("order-2");
+    assert_ne!(second_id, first_id);
+    assert_eq!(token_client.balance(&creator), 8_000_000);
+    assert_eq!(client.get_user_tasks(&creator).len(), 2);
+}
+
+#[test]
+fn test_rate_creator() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let released_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let cancelled_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    assert_eq!(client.get_creator_rating(&creator), (0, 0));
+
+    client.complete_task(&assignee, &released_id);
+    client.release_funds(&creator, &released_id);
+    client.rate_creator(&assignee, &released_id, &5);
+    client.cancel_task(&creator, &cancelled_id);
+    client.rate_creator(&assignee, &cancelled_id, &2);
+
+    assert_eq!(client.get_creator_rating(&creator), (7, 2));
+    assert!(client.get_task(&released_id).creator_rated);
+}
+
+#[test]
+#[should_panic(expected = "Task already rated")]
+fn test_rate_creator_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+    client.rate_creator(&assignee, &task_id, &4);
+
+    client.rate_creator(&assignee, &task_id, &1);
+}
+
+#[test]
+#[should_panic(expected = "Score must be between 1 and 5")]
+fn test_rate_creator_out_of_range_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    client.rate_creator(&assignee, &task_id, &6);
+}
+
+#[test]
+fn test_get_task_funding_breakdown() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    for funding_amount in [1_000_000i128, 1_234_567] {
+        let assignee = Address::generate(&e);
+        let task_id =
+            create_assigned_task(&e, &client, &creator, &assignee, funding_amount, deadline);
+        client.complete_task(&assignee, &task_id);
+
+        let before = client.get_task_funding_breakdown(&task_id);
+        let fees_before = client.get_platform_fees();
+        client.release_funds(&creator, &task_id);
+
+        assert_eq!(
+            before,
+            FundingBreakdown {
+                gross_funding: funding_amount,
+                platform_fee: client.get_platform_fees() - fees_before,
+                assignee_payout: token_client.balance(&assignee),
+                escrow_held: funding_amount,
+            }
+        );
+        assert_eq!(client.get_task_funding_breakdown(&task_id).escrow_held, 0);
+    }
+}
+
+// Commitment hiding an assignee's address behind a salt
+fn assignee_commitment(e: &Env, assignee: &Address, salt: &BytesN<32>) -> BytesN<32> {
+    let mut preimage = assignee.clone().to_xdr(e);
+    preimage.append(&Bytes::from(salt.clone()));
+    e.crypto().sha256(&preimage).to_bytes()
+}
+
+#[test]
+fn test_reveal_and_accept_committed_task() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let salt = BytesN::from_array(&e, &[7u8; 32]);
+    let task_id = client.create_task_committed(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(e.ledger().timestamp() + 86400),
+        &assignee_commitment(&e, &assignee, &salt),
+    );
+    assert_eq!(client.get_task(&task_id).assignee, None);
+
+    client.reveal_and_accept(&assignee, &task_id, &salt);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Assigned);
+    assert_eq!(task.assignee, Some(assignee.clone()));
+    assert_eq!(client.get_assigned_tasks(&assignee).len(), 1);
+}
+
+#[test]
+#[should_panic(expected = "Commitment does not match")]
+fn test_reveal_with_wrong_assignee_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let front_runner = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let salt = BytesN::from_array(&e, &[7u8; 32]);
+    let task_id = client.create_task_committed(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(e.ledger().timestamp() + 86400),
+        &assignee_commitment(&e, &assignee, &salt),
+    );
+
+    client.reveal_and_accept(&front_runner, &task_id, &salt);
+}
+
+#[test]
+fn test_get_creator_fees_paid() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    assert_eq!(client.get_creator_fees_paid(&creator), 0);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let mut expected_fees = 0i128;
+    for funding_amount in [1_000_000i128, 2_500_000, 333_333] {
+        let task_id =
+            create_assigned_task(&e, &client, &creator, &assignee, funding_amount, deadline);
+        client.complete_task(&assignee, &task_id);
+        expected_fees += client.simulate_release(&task_id).platform_fee;
+        client.release_funds(&creator, &task_id);
+    }
+
+    assert_eq!(client.get_creator_fees_paid(&creator), expected_fees);
+    assert_eq!(client.get_creator_fees_paid(&creator), client.get_platform_fees());
+}
+
+#[test]
+fn test_reassign_prunes_empty_assigned_index_entry() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let old_assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &old_assignee, 1_000_000, deadline);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+    client.reassign_task(&creator, &task_id, &new_assignee);
+
+    // The old assignee's entry is gone, not left as an empty list
+    e.as_contract(&client.address, || {
+        let storage = e.storage().instance();
+        assert!(!storage.has(&DataKey::AssignedTasks(old_assignee.clone())));
+        assert!(storage.has(&DataKey::AssignedTasks(new_assignee.clone())));
+    });
+    assert_eq!(client.get_assigned_tasks(&old_assignee).len(), 0);
+    assert_eq!(client.get_assigned_tasks(&new_assignee).len(), 1);
+}
+
+#[test]
+fn test_can_extend_deadline() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let active_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let completed_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // Assigned and in-progress tasks before their deadline can be extended
+    assert!(client.can_extend_deadline(&active_id));
+    client.start_task(&assignee, &active_id);
+    assert!(client.can_extend_deadline(&active_id));
+
+    // Completed tasks cannot
+    client.complete_task(&assignee, &completed_id);
+    assert!(!client.can_extend_deadline(&completed_id));
+
+    // Nor can tasks whose deadline has passed
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    assert!(!client.can_extend_deadline(&active_id));
+    client.mark_expired(&active_id);
+    assert!(!client.can_extend_deadline(&active_id));
+}
+
+// Mock contract assignee that records release notifications
+#[contract]
+pub struct ReleaseReceiver;
+
+#[contractimpl]
+impl ReleaseReceiver {
+    pub fn on_release(env: Env, task_id: u64, amount: i128) {
+        env.storage().instance().set(&task_id, &amount);
+    }
+
+    pub fn get_notified(env: Env, task_id: u64) -> Option<i128> {
+        env.storage().instance().get(&task_id)
+    }
+}
+
+// Mock contract assignee whose release callback always fails
+#[contract]
+pub struct PanickingReceiver;
+
+#[contractimpl]
+impl PanickingReceiver {
+    pub fn on_release(_env: Env, _task_id: u64, _amount: i128) {
+        panic!("Callback failed");
+    }
+}
+
+#[test]
+fn test_release_notifies_contract_assignee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let receiver_id = e.register(ReleaseReceiver, ());
+    let receiver = ReleaseReceiverClient::new(&e, &receiver_id);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.notify_on_release = true;
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &receiver_id,
+        1_000_000,
+        deadline,
+        &options,
+    );
+
+    client.complete_task(&receiver_id, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    // Payout of 1_000_000 minus the 3% platform fee, reported to the callback
+    assert_eq!(token_client.balance(&receiver_id), 970_000);
+    assert_eq!(receiver.get_notified(&task_id), Some(970_000));
+}
+
+#[test]
+fn test_release_survives_panicking_callback() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let receiver_id = e.register(PanickingReceiver, ());
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.notify_on_release = true;
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &receiver_id,
+        1_000_000,
+        deadline,
+        &options,
+    );
+
+    client.complete_task(&receiver_id, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    // The failed callback does not revert the release
+    assert_eq!(token_client.balance(&receiver_id), 970_000);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::FundsReleased);
+}
+
+#[test]
+fn test_get_creator_tasks_by_status() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let other_creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    mint_tokens(&token_admin_client, &other_creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let first_done = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let in_progress = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let second_done = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let others_done =
+        create_assigned_task(&e, &client, &other_creator, &assignee, 100_000, deadline);
+
+    client.complete_task(&assignee, &first_done);
+    client.start_task(&assignee, &in_progress);
+    client.complete_task(&assignee, &second_done);
+    client.complete_task(&assignee, &others_done);
+
+    // Only the creator's own completed tasks are returned
+    let completed = client.get_creator_tasks_by_status(&creator, &TaskStatus::Completed, &0, &10);
+    assert_eq!(completed.len(), 2);
+    assert_eq!(completed.get(0).unwrap().id, first_done);
+    assert_eq!(completed.get(1).unwrap().id, second_done);
+
+    let active = client.get_creator_tasks_by_status(&creator, &TaskStatus::InProgress, &0, &10);
+    assert_eq!(active.len(), 1);
+    assert_eq!(active.get(0).unwrap().id, in_progress);
+
+    // Pagination applies to the filtered subset
+    let page = client.get_creator_tasks_by_status(&creator, &TaskStatus::Completed, &1, &1);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, second_done);
+    let none = client.get_creator_tasks_by_status(&creator, &TaskStatus::Expired, &0, &10);
+    assert_eq!(none.len(), 0);
+}
+
+// Mock yield vault that holds deposited escrow
+#[contract]
+pub struct MockVault;
+
+#[contractimpl]
+impl MockVault {
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage().instance().set(&symbol_short!("TOKEN"), &token);
+    }
+
+    pub fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let token: Address = env.storage().instance().get(&symbol_short!("TOKEN")).unwrap();
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+    }
+
+    pub fn withdraw(env: Env, to: Address, amount: i128) {
+        to.require_auth();
+        let token: Address = env.storage().instance().get(&symbol_short!("TOKEN")).unwrap();
+        token::Client::new(&env, &token).transfer(&env.current_contract_address(), &to, &amount);
+    }
+}
+
+// Mock yield vault that rejects every deposit and withdrawal
+#[contract]
+pub struct UnavailableVault;
+
+#[contractimpl]
+impl UnavailableVault {
+    pub fn deposit(_env: Env, _from: Address, _amount: i128) {
+        panic!("Vault unavailable");
+    }
+
+    pub fn withdraw(_env: Env, _to: Address, _amount: i128) {
+        panic!("Vault unavailable");
+    }
+}
+
+// Mock yield vault that takes deposits but never pays them back
+#[contract]
+pub struct LeakyVault;
+
+#[contractimpl]
+impl LeakyVault {
+    pub fn __constructor(env: Env, token: Address) {
+        env.storage().instance().set(&symbol_short!("TOKEN"), &token);
+    }
+
+    pub fn deposit(env: Env, from: Address, amount: i128) {
+        from.require_auth();
+        let token: Address = env.storage().instance().get(&symbol_short!("TOKEN")).unwrap();
+        token::Client::new(&env, &token).transfer(&from, &env.current_contract_address(), &amount);
+    }
+
+    pub fn withdraw(_env: Env, _to: Address, _amount: i128) {}
+}
+
+// Mock yield vault that accepts deposits without ever pulling the tokens
+#[contract]
+pub struct NonPullingVault;
+
+#[contractimpl]
+impl NonPullingVault {
+    pub fn deposit(_env: Env, _from: Address, _amount: i128) {}
+
+    pub fn withdraw(_env: Env, _to: Address, _amount: i128) {}
+}
+
+#[test]
+fn test_vault_holds_escrow_until_release() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let vault = e.register(MockVault, (token_client.address.clone(),));
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_vault(&admin, &Some(vault.clone()));
+    assert_eq!(client.get_vault(), Some(vault.clone()));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // The escrow is deposited into the vault on creation
+    assert_eq!(token_client.balance(&vault), 1_000_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_task(&task_id).vault_deposit, 1_000_000);
+    assert_eq!(client.check_solvency(), (1_000_000, 1_000_000, true));
+
+    // The vault cannot be switched while it holds escrow
+    assert!(client.try_set_vault(&admin, &None).is_err());
+
+    // Release withdraws from the vault before paying out
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+    assert_eq!(token_client.balance(&vault), 0);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+    assert_eq!(token_client.balance(&client.address), 30_000);
+    assert_eq!(client.get_task(&task_id).vault_deposit, 0);
+
+    client.set_vault(&admin, &None);
+    assert_eq!(client.get_vault(), None);
+}
+
+#[test]
+fn test_vault_fallback_keeps_escrow_in_contract() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // Without a vault the escrow stays in the contract
+    let deadline = e.ledger().timestamp() + 86400;
+    let plain_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    assert_eq!(client.get_task(&plain_id).vault_deposit, 0);
+    assert_eq!(token_client.balance(&client.address), 1_000_000);
+
+    // A failing vault leaves the escrow in the contract as well
+    let vault = e.register(UnavailableVault, ());
+    client.set_vault(&admin, &Some(vault.clone()));
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    assert_eq!(client.get_task(&task_id).vault_deposit, 0);
+    assert_eq!(token_client.balance(&client.address), 2_000_000);
+
+    // Refunds work without touching the vault
+    client.cancel_task(&creator, &task_id);
+    assert_eq!(token_client.balance(&client.address), 1_000_000);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+}
+
+#[test]
+fn test_vault_withdraw_failure_keeps_bookkeeping() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let vault = e.register(MockVault, (token_client.address.clone(),));
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_vault(&admin, &Some(vault.clone()));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    assert_eq!(token_client.balance(&vault), 1_000_000);
+
+    // The vault goes down while it still holds the escrow
+    let unavailable = e.register(UnavailableVault, ());
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::Vault, &unavailable);
+    });
+    assert!(client.try_cancel_task(&creator, &task_id).is_err());
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Assigned);
+    assert_eq!(client.get_task(&task_id).vault_deposit, 1_000_000);
+    assert_eq!(client.check_solvency(), (1_000_000, 1_000_000, true));
+
+    // Once the vault is back the refund goes through
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::Vault, &vault);
+    });
+    client.cancel_task(&creator, &task_id);
+    assert_eq!(client.get_task(&task_id).vault_deposit, 0);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+}
+
+#[test]
+#[should_panic(expected = "Vault withdrawal failed")]
+fn test_vault_withdraw_without_funds_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let vault = e.register(LeakyVault, (token_client.address.clone(),));
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_vault(&admin, &Some(vault));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+
+    // The vault reports success but returns nothing
+    client.release_funds(&creator, &task_id);
+}
+
+#[test]
+fn test_get_success_rate() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // No finished tasks yet
+    assert_eq!(client.get_success_rate(&assignee), u32::MAX);
+
+    // Two paid-out tasks and one abandoned task
+    let deadline = e.ledger().timestamp() + 86400;
+    for _ in 0..2 {
+        let task_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+        client.complete_task(&assignee, &task_id);
+        client.release_funds(&creator, &task_id);
+    }
+    let abandoned_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    client.assignee_cancel(&assignee, &abandoned_id);
+
+    assert_eq!(client.get_success_rate(&assignee), 66);
+}
+
+#[test]
+fn test_min_fee_applies_to_tiny_tasks() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // Without a floor, 3% of 33 truncates to zero
+    let deadline = e.ledger().timestamp() + 86400;
+    let free_id = create_assigned_task(&e, &client, &creator, &assignee, 33, deadline);
+    assert_eq!(client.get_task_funding_breakdown(&free_id).platform_fee, 0);
+
+    client.set_min_fee(&admin, &5);
+    assert_eq!(client.get_min_fee(), 5);
+
+    // The floor is charged on a tiny payout
+    let tiny_id = create_assigned_task(&e, &client, &creator, &assignee, 33, deadline);
+    client.complete_task(&assignee, &tiny_id);
+    client.release_funds(&creator, &tiny_id);
+    assert_eq!(token_client.balance(&assignee), 28);
+    assert_eq!(client.get_platform_fees(), 5);
+
+    // But never exceeds the funding itself
+    let dust_id = create_assigned_task(&e, &client, &creator, &assignee, 3, deadline);
+    client.complete_task(&assignee, &dust_id);
+    client.release_funds(&creator, &dust_id);
+    assert_eq!(token_client.balance(&assignee), 28);
+    assert_eq!(client.get_platform_fees(), 8);
+
+    // Larger payouts still pay the percentage fee
+    let regular_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000, deadline);
+    assert_eq!(client.get_task_funding_breakdown(&regular_id).platform_fee, 30);
+
+    assert!(client.try_set_min_fee(&admin, &-1).is_err());
+}
+
+// Replacement parameters for relisting a test task
+fn relist_params(e: &Env, funding_amount: i128, assignee: Option<Address>) -> RelistParams {
+    RelistParams {
+        title: SorobanString::from_str(e, "Relisted Task"),
+        description: SorobanString::from_str(e, "Relisted Description"),
+        github_link: SorobanString::from_str(e, ""),
+        funding_amount,
+        deadline: e.ledger().timestamp() + 2 * 86400,
+        assignee,
+    }
+}
+
+#[test]
+fn test_relist_task_with_higher_funding() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    let params = relist_params(&e, 1_500_000, Some(new_assignee.clone()));
+    let new_id = client.relist_task(&creator, &task_id, &params);
+
+    // Only the extra funding is pulled from the creator
+    assert_eq!(token_client.balance(&creator), 8_500_000);
+    assert_eq!(token_client.balance(&client.address), 1_500_000);
+
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Cancelled);
+    let new_task = client.get_task(&new_id);
+    assert_ne!(new_id, task_id);
+    assert_eq!(new_task.relisted_from, Some(task_id));
+    assert_eq!(new_task.funding_amount, 1_500_000);
+    assert_eq!(new_task.status, TaskStatus::Assigned);
+    assert_eq!(new_task.assignee, Some(new_assignee));
+    assert_eq!(client.get_user_financials(&creator), (0, 0, 1_500_000));
+}
+
+#[test]
+fn test_relist_task_with_lower_funding() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    let new_id = client.relist_task(&creator, &task_id, &relist_params(&e, 400_000, None));
+
+    // The difference is refunded to the creator
+    assert_eq!(token_client.balance(&creator), 9_600_000);
+    assert_eq!(token_client.balance(&client.address), 400_000);
+
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Cancelled);
+    let new_task = client.get_task(&new_id);
+    assert_eq!(new_task.status, TaskStatus::Created);
+    assert_eq!(new_task.assignee, None);
+    assert_eq!(new_task.relisted_from, Some(task_id));
+
+    // A cancelled task cannot be relisted again
+    let params = relist_params(&e, 400_000, None);
+    assert!(client.try_relist_task(&creator, &task_id, &params).is_err());
+}
+
+#[test]
+fn test_get_stats_tracks_lifecycles() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    assert_eq!(client.get_stats(), Stats::default());
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let released_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let cancelled_id = create_assigned_task(&e, &client, &creator, &assignee, 500_000, deadline);
+    let _open_id = create_assigned_task(&e, &client, &creator, &assignee, 200_000, deadline);
+
+    // One payout (30_000 fee), one cancellation with a 1% fee (5_000), one still open
+    client.complete_task(&assignee, &released_id);
+    client.release_funds(&creator, &released_id);
+    client.set_cancel_fee_bps(&admin, &100);
+    client.cancel_task(&creator, &cancelled_id);
+    client.withdraw_platform_fees_to(&admin, &admin, &10_000);
+
+    assert_eq!(
+        client.get_stats(),
+        Stats {
+            total_tasks: 3,
+            total_escrowed: 1_700_000,
+            total_fees_earned: 35_000,
+            total_fees_withdrawn: 10_000,
+            active_tasks: 1,
+            completed_tasks: 1,
+            released_volume: 1_000_000,
+            release_fees: 30_000,
+            total_paid: 970_000,
+            total_refunded: 495_000,
+            fee_token_fees: 0,
+        }
+    );
+    assert_eq!(client.get_platform_fees(), 25_000);
+}
+
+#[test]
+fn test_tasks_needing_reminder() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let now = e.ledger().timestamp();
+    let soon_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 3600);
+    let later_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 7200);
+    let _far_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 86400);
+    client.start_task(&assignee, &later_id);
+
+    // Only active tasks due within the window are returned
+    let due = client.get_tasks_needing_reminder(&7200, &1, &10);
+    assert_eq!(due.tasks.len(), 2);
+    assert_eq!(due.tasks.get(0).unwrap().id, soon_id);
+    assert_eq!(due.tasks.get(1).unwrap().id, later_id);
+    assert_eq!(due.next_cursor, None);
+    let first_page = client.get_tasks_needing_reminder(&7200, &1, &1);
+    assert_eq!(first_page.tasks.len(), 1);
+    assert_eq!(first_page.next_cursor, Some(soon_id + 1));
+
+    // Reminded tasks do not reappear
+    client.mark_reminded(&assignee, &soon_id);
+    assert!(client.get_task(&soon_id).reminded);
+    let due = client.get_tasks_needing_reminder(&7200, &1, &10);
+    assert_eq!(due.tasks.len(), 1);
+    assert_eq!(due.tasks.get(0).unwrap().id, later_id);
+
+    client.mark_reminded(&creator, &later_id);
+    assert_eq!(client.get_tasks_needing_reminder(&7200, &1, &10).tasks.len(), 0);
+}
+
+#[test]
+fn test_mark_reminded_requires_party_and_active_task() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 3600;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+
+    // Unrelated callers cannot suppress a reminder
+    assert!(client.try_mark_reminded(&stranger, &task_id).is_err());
+    assert!(!client.get_task(&task_id).reminded);
+
+    // Finished tasks have nothing left to remind about
+    client.complete_task(&assignee, &task_id);
+    assert!(client.try_mark_reminded(&creator, &task_id).is_err());
+    assert!(!client.get_task(&task_id).reminded);
+}
+
+#[test]
+fn test_block_deployer_assignee_policy() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // Disabled by default: the deployer may take tasks
+    let deadline = e.ledger().timestamp() + 100;
+    let allowed_id = create_assigned_task(&e, &client, &creator, &admin, 100_000, deadline);
+    assert_eq!(client.get_task(&allowed_id).assignee, Some(admin.clone()));
+
+    client.set_block_deployer_assignee(&admin, &true);
+    assert!(client.get_block_deployer_assignee());
+
+    // Assigning a new task to the deployer is rejected
+    let task_id = client.create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &100_000,
+        &deadline,
+    );
+    assert!(client.try_assign_task(&creator, &task_id, &admin).is_err());
+
+    // So is reassigning an expired task to the deployer
+    client.assign_task(&creator, &task_id, &assignee);
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+    assert!(client.try_reassign_task(&creator, &task_id, &admin).is_err());
+
+    client.set_block_deployer_assignee(&admin, &false);
+    client.reassign_task(&creator, &task_id, &admin);
+    assert_eq!(client.get_task(&task_id).assignee, Some(admin));
+}
+
+#[test]
+fn test_get_remaining_time() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    assert_eq!(client.get_remaining_time(&task_id), 100);
+
+    // Past the deadline the remaining time bottoms out at zero
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 50;
+    });
+    assert_eq!(client.get_remaining_time(&task_id), 0);
+}
+
+#[test]
+fn test_mark_expired_batch_skips_ineligible_tasks() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let now = e.ledger().timestamp();
+    let overdue_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 100);
+    let done_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 100);
+    let pending_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 1000);
+    let started_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 100);
+    client.start_task(&assignee, &started_id);
+    client.complete_task(&assignee, &done_id);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 101;
+    });
+
+    // Completed, not-yet-due and unknown tasks are skipped without panicking
+    let mut ids = Vec::new(&e);
+    for id in [overdue_id, done_id, pending_id, started_id, 999] {
+        ids.push_back(id);
+    }
+    let expired = client.mark_expired_batch(&ids);
+
+    assert_eq!(expired.len(), 2);
+    assert_eq!(expired.get(0).unwrap(), overdue_id);
+    assert_eq!(expired.get(1).unwrap(), started_id);
+    assert_eq!(client.get_task(&overdue_id).status, TaskStatus::Expired);
+    assert_eq!(client.get_task(&started_id).status, TaskStatus::Expired);
+    assert_eq!(client.get_task(&done_id).status, TaskStatus::Completed);
+    assert_eq!(client.get_task(&pending_id).status, TaskStatus::Assigned);
+}
+
+// Mock oracle answering every release check with a fixed result
+#[contract]
+pub struct MockOracle;
+
+#[contractimpl]
+impl MockOracle {
+    pub fn __constructor(env: Env, result: bool) {
+        env.storage().instance().set(&symbol_short!("RESULT"), &result);
+    }
+
+    pub fn verified(env: Env, _task_id: u64) -> bool {
+        env.storage().instance().get(&symbol_short!("RESULT")).unwrap()
+    }
+}
+
+#[test]
+fn test_release_gated_by_oracle() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let approving_oracle = e.register(MockOracle, (true,));
+    let rejecting_oracle = e.register(MockOracle, (false,));
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let mut options = default_options(&e);
+    options.oracle_fn = Some(symbol_short!("verified"));
+
+    // A satisfied oracle lets the release proceed
+    options.oracle = Some(approving_oracle);
+    let approved_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        deadline,
+        &options,
+    );
+    client.complete_task(&assignee, &approved_id);
+    client.release_funds(&creator, &approved_id);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+
+    // An unsatisfied oracle blocks it
+    options.oracle = Some(rejecting_oracle);
+    let blocked_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        deadline,
+        &options,
+    );
+    client.complete_task(&assignee, &blocked_id);
+    assert!(client.try_release_funds(&creator, &blocked_id).is_err());
+    assert_eq!(client.get_task(&blocked_id).status, TaskStatus::Completed);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+}
+
+#[test]
+fn test_get_take_rate_tracks_releases() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    assert_eq!(client.get_take_rate(), (0, 0));
+
+    // 3% of 1_000_000, 33 (rounds to 0) and 250 (rounds to 7)
+    let deadline = e.ledger().timestamp() + 86400;
+    for amount in [1_000_000, 33, 250] {
+        let task_id = create_assigned_task(&e, &client, &creator, &assignee, amount, deadline);
+        client.complete_task(&assignee, &task_id);
+        client.release_funds(&creator, &task_id);
+    }
+
+    assert_eq!(client.get_take_rate(), (1_000_283, 30_007));
+}
+
+#[test]
+fn test_reopen_to_pool_and_claim() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let worker = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // Only pooled tasks can be claimed, and only expired tasks can be pooled
+    assert!(client.try_claim_task(&worker, &task_id).is_err());
+    assert!(client.try_reopen_to_pool(&creator, &task_id, &(deadline + 1000)).is_err());
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+    client.reopen_to_pool(&creator, &task_id, &(deadline + 1000));
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Created);
+    assert_eq!(task.assignee, None);
+    assert_eq!(task.deadline, deadline + 1000);
+    assert!(task.open_pool);
+    assert_eq!(client.get_assigned_tasks(&assignee).len(), 0);
+
+    // Any worker can now claim it
+    client.claim_task(&worker, &task_id);
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Assigned);
+    assert_eq!(task.assignee, Some(worker.clone()));
+    assert!(!task.open_pool);
+    assert_eq!(client.get_assigned_tasks(&worker).len(), 1);
+    assert!(client.try_claim_task(&assignee, &task_id).is_err());
+}
+
+#[test]
+fn test_mark_expired_open_policy() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let first_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    let second_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    // By default anyone may mark a task expired
+    assert!(!client.get_restrict_expiry());
+    client.mark_expired(&first_id);
+    client.mark_expired_by(&stranger, &second_id);
+    assert_eq!(client.get_task(&first_id).status, TaskStatus::Expired);
+    assert_eq!(client.get_task(&second_id).status, TaskStatus::Expired);
+}
+
+#[test]
+fn test_mark_expired_restricted_policy() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 100_000, deadline);
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    client.set_restrict_expiry(&admin, &true);
+
+    // Unidentified and unrelated callers are rejected
+    assert!(client.try_mark_expired(&task_id).is_err());
+    assert!(client.try_mark_expired_by(&stranger, &task_id).is_err());
+    let mut ids = Vec::new(&e);
+    ids.push_back(task_id);
+    assert!(client.try_mark_expired_batch(&ids).is_err());
+
+    // The creator may still expire the task
+    client.mark_expired_by(&creator, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Expired);
+}
+
+#[test]
+fn test_cancel_with_refund_address() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let default_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let override_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // Only the creator may redirect refunds
+    assert!(client.try_set_refund_address(&assignee, &override_id, &treasury).is_err());
+    client.set_refund_address(&creator, &override_id, &treasury);
+    assert_eq!(client.get_task(&override_id).refund_address, Some(treasury.clone()));
+
+    // Without an override the creator is refunded
+    client.cancel_task(&creator, &default_id);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+
+    // With one, the refund goes to the override address
+    client.cancel_task(&creator, &override_id);
+    assert_eq!(token_client.balance(&treasury), 1_000_000);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+
+    // Terminal tasks can no longer be redirected
+    assert!(client.try_set_refund_address(&creator, &default_id, &treasury).is_err());
+}
+
+#[test]
+fn test_reclaim_ignores_refund_address() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.set_refund_address(&creator, &task_id, &treasury);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+    client.reclaim_expired_funds(&task_id);
+
+    // Anyone can trigger the reclaim, so it only ever pays the creator
+    assert_eq!(token_client.balance(&treasury), 0);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+}
+
+#[test]
+fn test_fee_split_never_strands_funds() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 100_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    for amount in [1, 2, 7, 33, 34, 97, 101, 999, 7_919, 104_729, 1_000_003, 9_999_999] {
+        let fees_before = client.get_platform_fees();
+        let paid_before = token_client.balance(&assignee);
+
+        let task_id = create_assigned_task(&e, &client, &creator, &assignee, amount, deadline);
+        client.complete_task(&assignee, &task_id);
+        client.release_funds(&creator, &task_id);
+
+        // Fee accrued plus payout account for every unit of funding
+        let fee = client.get_platform_fees() - fees_before;
+        let payout = token_client.balance(&assignee) - paid_before;
+        assert_eq!(fee + payout, amount);
+    }
+
+    // Nothing beyond the accrued fees is left behind
+    assert_eq!(token_client.balance(&client.address), client.get_platform_fees());
+}
+
+#[test]
+fn test_candidates_race_to_claim() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let first = Address::generate(&e);
+    let second = Address::generate(&e);
+    let outsider = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut candidates = Vec::new(&e);
+    candidates.push_back(first.clone());
+    candidates.push_back(second.clone());
+    let task_id = client.create_task_with_candidates(
+        &creator,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(e.ledger().timestamp() + 86400),
+        &candidates,
+    );
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Created);
+
+    // Workers outside the list cannot claim
+    assert!(client.try_claim_candidate_task(&outsider, &task_id).is_err());
+
+    // The first candidate to claim is assigned
+    client.claim_candidate_task(&second, &task_id);
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Assigned);
+    assert_eq!(task.assignee, Some(second));
+
+    // Later candidates find the task taken
+    assert!(client.try_claim_candidate_task(&first, &task_id).is_err());
+}
+
+#[test]
+fn test_has_withdrawable_fees() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // Nothing accrued yet
+    assert!(!client.has_withdrawable_fees());
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+    assert!(client.has_withdrawable_fees());
+
+    // Accrued fees the balance cannot cover are not withdrawable
+    e.as_contract(&client.address, || {
+        e.storage().instance().set(&DataKey::PlatformFees, &50_000i128);
+    });
+    assert!(!client.has_withdrawable_fees());
+}
+
+#[test]
+fn test_priority_fee_schedule_snapshot() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // Urgent tasks pay 1%, low-priority tasks keep the default 3%
+    client.set_priority_fee_bps(&admin, &3, &100);
+    assert_eq!(client.get_priority_fee_bps(&3), 100);
+    assert_eq!(client.get_priority_fee_bps(&0), 300);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let mut options = default_options(&e);
+    options.priority = 3;
+    let urgent_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        deadline,
+        &options,
+    );
+    let low_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // The rate is locked in at creation, so later schedule changes do not apply
+    client.set_priority_fee_bps(&admin, &3, &500);
+    assert_eq!(client.get_task(&urgent_id).fee_bps, 100);
+
+    client.complete_task(&assignee, &urgent_id);
+    client.release_funds(&creator, &urgent_id);
+    assert_eq!(token_client.balance(&assignee), 990_000);
+    assert_eq!(client.get_platform_fees(), 10_000);
+
+    client.complete_task(&assignee, &low_id);
+    client.release_funds(&creator, &low_id);
+    assert_eq!(token_client.balance(&assignee), 1_960_000);
+    assert_eq!(client.get_platform_fees(), 40_000);
+
+    assert!(client.try_set_priority_fee_bps(&admin, &4, &100).is_err());
+    assert!(client.try_set_priority_fee_bps(&admin, &1, &10_001).is_err());
+}
+
+#[test]
+fn test_get_github_tasks() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let create = |link: &str| {
+        client.create_task(
+            &creator,
+            &SorobanString::from_str(&e, "Test Task"),
+            &SorobanString::from_str(&e, "Test Description"),
+            &SorobanString::from_str(&e, link),
+            &100_000,
+            &deadline,
+        )
+    };
+    let first_linked = create("https://github.com/test/repo/issues/1");
+    let unlinked = create("");
+    let second_linked = create("https://github.com/test/repo/issues/2");
+
+    // Only tasks with a link are returned, in ID order
+    let linked = client.get_github_tasks(&0, &10);
+    assert_eq!(linked.len(), 2);
+    assert_eq!(linked.get(0).unwrap().id, first_linked);
+    assert_eq!(linked.get(1).unwrap().id, second_linked);
+
+    let page = client.get_github_tasks(&1, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get(0).unwrap().id, second_linked);
+    assert_eq!(client.get_github_tasks(&5, &10).len(), 0);
+
+    // Editing a task's link keeps the index in step
+    client.assign_task(&creator, &unlinked, &assignee);
+    client.update_task_details(
+        &creator,
+        &unlinked,
+        &SorobanString::from_str(&e, "Test Task"),
+        &SorobanString::from_str(&e, "Test Description"),
+        &SorobanString::from_str(&e, "https://github.com/test/repo/issues/3"),
+    );
+    let linked = client.get_github_tasks(&0, &10);
+    assert_eq!(linked.len(), 3);
+    assert_eq!(linked.get(1).unwrap().id, unlinked);
+}
+
+#[test]
+fn test_release_with_separate_fee_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let (fee_token_client, fee_token_admin_client) = create_token_contract(&e, &admin);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    mint_tokens(&fee_token_admin_client, &creator, 100_000);
+    client.set_fee_token(&admin, &Some(fee_token_client.address.clone()));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    // The assignee receives the full funding in the payment token
+    assert_eq!(token_client.balance(&assignee), 1_000_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_platform_fees(), 0);
+
+    // The fee is pulled from the creator in the fee token
+    assert_eq!(fee_token_client.balance(&creator), 70_000);
+    assert_eq!(fee_token_client.balance(&client.address), 30_000);
+    assert_eq!(client.get_fee_token_fees(), 30_000);
+
+    // The fee token cannot change until its fees are withdrawn
+    assert!(client.try_set_fee_token(&admin, &None).is_err());
+    client.withdraw_fee_token_fees(&admin);
+    assert_eq!(fee_token_client.balance(&admin), 30_000);
+    client.set_fee_token(&admin, &None);
+}
+
+#[test]
+fn test_user_and_assigned_tasks_paged() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    for _ in 0..12 {
+        create_assigned_task(&e, &client, &creator, &assignee, 1_000, deadline);
+    }
+
+    // Pages are contiguous slices of the full index
+    let all = client.get_user_tasks(&creator);
+    let first = client.get_user_tasks_paged(&creator, &0, &5);
+    let second = client.get_user_tasks_paged(&creator, &5, &5);
+    let last = client.get_user_tasks_paged(&creator, &10, &5);
+    assert_eq!(first, all.slice(0..5));
+    assert_eq!(second, all.slice(5..10));
+    assert_eq!(last, all.slice(10..12));
+
+    // Out-of-range pages are empty
+    assert_eq!(client.get_user_tasks_paged(&creator, &12, &5).len(), 0);
+
+    let assigned = client.get_assigned_tasks(&assignee);
+    assert_eq!(client.get_assigned_tasks_paged(&assignee, &3, &4), assigned.slice(3..7));
+    assert_eq!(client.get_assigned_tasks_paged(&assignee, &10, &5).len(), 2);
+}
+
+#[test]
+fn test_resolve_dispute_default_policy_takes_no_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // Only disputed tasks can be resolved this way
+    assert!(client.try_resolve_dispute(&admin, &task_id, &true).is_err());
+
+    client.complete_task(&assignee, &task_id);
+    client.raise_dispute(&creator, &task_id);
+    client.resolve_dispute(&admin, &task_id, &true);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::FundsReleased);
+    assert!(!task.disputed);
+    assert_eq!(token_client.balance(&assignee), 1_000_000);
+    assert_eq!(client.get_platform_fees(), 0);
+}
+
+#[test]
+fn test_resolve_dispute_fee_on_disputed_payout() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_dispute_fee_policy(&admin, &DisputeFeePolicy::FeeOnDisputedPayout);
+    assert_eq!(client.get_dispute_fee_policy(), DisputeFeePolicy::FeeOnDisputedPayout);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let paid_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let refunded_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // Resolved for the assignee: the fee is taken
+    client.complete_task(&assignee, &paid_id);
+    client.raise_dispute(&assignee, &paid_id);
+    client.resolve_dispute(&admin, &paid_id, &true);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+    assert_eq!(client.get_platform_fees(), 30_000);
+
+    // Resolved for the creator: the whole escrow comes back
+    client.raise_dispute(&creator, &refunded_id);
+    client.resolve_dispute(&admin, &refunded_id, &false);
+    assert_eq!(client.get_task(&refunded_id).status, TaskStatus::Cancelled);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+    assert_eq!(client.get_platform_fees(), 30_000);
+}
+
+#[test]
+fn test_reclaim_expired_funds_by_third_party() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let keeper = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    // A keeper triggers the reclaim without any authorization of its own
+    e.set_auths(&[]);
+    client.reclaim_expired_funds(&task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Cancelled);
+    assert!(task.was_expired);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+    assert_eq!(token_client.balance(&keeper), 0);
+}
+
+#[test]
+fn test_expiry_action_refund() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        deadline,
+        &default_options(&e),
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Expired);
+    assert_eq!(task.assignee, Some(assignee));
+
+    client.reclaim_expired_funds(&task_id);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+}
+
+#[test]
+fn test_expiry_action_auto_reassign_to_backup() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let backup = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.expiry_action = ExpiryAction::AutoReassignToBackup(backup.clone());
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task_with_options(
+        &e, &client, &creator, &assignee, 1_000_000, deadline, &options,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Assigned);
+    assert_eq!(task.assignee, Some(backup.clone()));
+    assert_eq!(task.previous_assignees, Vec::from_array(&e, [assignee.clone()]));
+    assert_eq!(task.deadline, deadline + 1 + 86400);
+    assert_eq!(client.get_assigned_tasks(&backup), Vec::from_array(&e, [task_id]));
+    assert_eq!(client.get_assigned_tasks(&assignee).len(), 0);
+
+    // The backup can carry the task through to payout
+    client.complete_task(&backup, &task_id);
+    client.release_funds(&creator, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::FundsReleased);
+}
+
+#[test]
+fn test_expiry_action_reopen_to_pool() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let worker = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.expiry_action = ExpiryAction::ReopenToPool;
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task_with_options(
+        &e, &client, &creator, &assignee, 1_000_000, deadline, &options,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Created);
+    assert_eq!(task.assignee, None);
+    assert!(task.open_pool);
+    assert_eq!(task.previous_assignees, Vec::from_array(&e, [assignee.clone()]));
+    assert_eq!(task.deadline, deadline + 1 + 86400);
+    assert_eq!(client.get_assigned_tasks(&assignee).len(), 0);
+
+    client.claim_task(&worker, &task_id);
+    assert_eq!(client.get_task(&task_id).assignee, Some(worker));
+}
+
+#[test]
+fn test_storage_uses_typed_keys() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    assert_eq!(client.get_storage_version(), 1);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    // Each user's task index lives under its own key
+    e.as_contract(&client.address, || {
+        let storage = e.storage().instance();
+        let created: Vec<u64> = storage.get(&DataKey::UserTasks(creator.clone())).unwrap();
+        let assigned: Vec<u64> = storage.get(&DataKey::AssignedTasks(assignee.clone())).unwrap();
+        assert_eq!(created, Vec::from_array(&e, [task_id]));
+        assert_eq!(assigned, Vec::from_array(&e, [task_id]));
+        assert_eq!(storage.get::<_, u64>(&DataKey::TaskCounter), Some(task_id + 1));
+        assert_eq!(storage.get::<_, i128>(&DataKey::PlatformFees), Some(30_000));
+    });
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::FundsReleased);
+}
+
+#[test]
+fn test_complete_task_with_hash() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let hashed_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let plain_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    let deliverable = Bytes::from_slice(&e, b"release-v1.0.tar.gz");
+    let hash: BytesN<32> = e.crypto().sha256(&deliverable).into();
+    client.complete_task_with_hash(&assignee, &hashed_id, &hash);
+
+    let task = client.get_task(&hashed_id);
+    assert_eq!(task.status, TaskStatus::Completed);
+    assert_eq!(task.deliverable_hash, Some(hash));
+
+    // Plain completion records no hash
+    client.complete_task(&assignee, &plain_id);
+    let task = client.get_task(&plain_id);
+    assert_eq!(task.status, TaskStatus::Completed);
+    assert_eq!(task.deliverable_hash, None);
+}
+
+#[test]
+fn test_reassign_after_creatorship_transfer() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let new_creator = Address::generate(&e);
+    let old_assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &old_assignee, 1_000_000, deadline);
+    client.transfer_creatorship(&creator, &task_id, &new_creator);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    // The previous creator no longer controls the task
+    assert!(client.try_reassign_task(&creator, &task_id, &new_assignee).is_err());
+
+    client.reassign_task(&new_creator, &task_id, &new_assignee);
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.assignee, Some(new_assignee.clone()));
+    assert_eq!(client.get_assigned_tasks(&old_assignee).len(), 0);
+    assert_eq!(client.get_assigned_tasks(&new_assignee), Vec::from_array(&e, [task_id]));
+
+    // Creator indexes are untouched by the reassignment
+    assert_eq!(client.get_user_tasks(&creator).len(), 0);
+    assert_eq!(client.get_user_tasks(&new_creator), Vec::from_array(&e, [task_id]));
+}
+
+#[test]
+fn test_get_task_escrow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let released_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let held_id = create_assigned_task(&e, &client, &creator, &assignee, 2_000_000, deadline);
+    assert_eq!(client.get_task(&released_id).status, TaskStatus::Assigned);
+    assert_eq!(client.get_task_escrow(&released_id), 1_000_000);
+    assert_eq!(client.get_task_escrow(&held_id), 2_000_000);
+
+    // Escrow drops to zero once the funds are released
+    client.complete_task(&assignee, &released_id);
+    client.release_funds(&creator, &released_id);
+    assert_eq!(client.get_task_escrow(&released_id), 0);
+
+    // The contract's balance covers the remaining escrow plus accrued fees
+    let held = client.get_task_escrow(&released_id) + client.get_task_escrow(&held_id);
+    assert_eq!(token_client.balance(&client.address), held + client.get_platform_fees());
+}
+
+#[test]
+fn test_reassign_all_expired() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let other_creator = Address::generate(&e);
+    let old_assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    mint_tokens(&token_admin_client, &other_creator, 10_000_000);
+
+    let now = e.ledger().timestamp();
+    let first = create_assigned_task(&e, &client, &creator, &old_assignee, 1_000, now + 100);
+    let active = create_assigned_task(&e, &client, &creator, &old_assignee, 1_000, now + 86400);
+    let second = create_assigned_task(&e, &client, &creator, &old_assignee, 1_000, now + 100);
+    let foreign =
+        create_assigned_task(&e, &client, &other_creator, &old_assignee, 1_000, now + 100);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 101;
+    });
+    client.mark_expired(&first);
+    client.mark_expired(&second);
+    client.mark_expired(&foreign);
+
+    let new_deadline = now + 86400;
+    let reassigned =
+        client.reassign_all_expired(&creator, &old_assignee, &new_assignee, &new_deadline);
+    assert_eq!(reassigned, Vec::from_array(&e, [first, second]));
+
+    assert_eq!(client.get_task(&first).assignee, Some(new_assignee.clone()));
+    assert_eq!(client.get_task(&second).status, TaskStatus::Assigned);
+    assert_eq!(client.get_task(&second).deadline, new_deadline);
+
+    // The new assignee has time to finish the handed-over work
+    client.complete_task(&new_assignee, &first);
+    client.release_funds(&creator, &first);
+    assert_eq!(client.get_task(&first).status, TaskStatus::FundsReleased);
+
+    // Unexpired tasks and other creators' tasks stay with the old assignee
+    assert_eq!(client.get_assigned_tasks(&old_assignee), Vec::from_array(&e, [active, foreign]));
+    assert_eq!(client.get_task(&foreign).status, TaskStatus::Expired);
+}
+
+#[test]
+fn test_min_work_time() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_min_work_time(&admin, &3600);
+    assert_eq!(client.get_min_work_time(), 3600);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.start_task(&assignee, &task_id);
+    let started_at = e.ledger().timestamp();
+
+    // Completing right after starting is rejected
+    e.ledger().with_mut(|li| {
+        li.timestamp = started_at + 10;
+    });
+    assert!(client.try_complete_task(&assignee, &task_id).is_err());
+
+    // Completing once the minimum work time has passed is allowed
+    e.ledger().with_mut(|li| {
+        li.timestamp = started_at + 3600;
+    });
+    client.complete_task(&assignee, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Completed);
+}
+
+#[test]
+fn test_started_at_recorded_on_start() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    assert_eq!(client.get_task(&task_id).started_at, None);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp += 500;
+    });
+    client.start_task(&assignee, &task_id);
+    assert_eq!(client.get_task(&task_id).started_at, Some(e.ledger().timestamp()));
+}
+
+#[test]
+fn test_get_assignee_queue() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let now = e.ledger().timestamp();
+    let later = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 3000);
+    let soonest = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 1000);
+    let done = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 500);
+    let middle = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 2000);
+    client.start_task(&assignee, &middle);
+
+    // Finished work drops out of the queue
+    client.complete_task(&assignee, &done);
+    client.release_funds(&creator, &done);
+
+    let queue = client.get_assignee_queue(&assignee);
+    assert_eq!(queue.len(), 3);
+    assert_eq!(queue.get(0).unwrap().id, soonest);
+    assert_eq!(queue.get(1).unwrap().id, middle);
+    assert_eq!(queue.get(2).unwrap().id, later);
+}
+
+#[test]
+fn test_create_task_detects_id_collision() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // Roll the counter back onto the existing task
+    set_task_counter(&e, &client, task_id);
+    let result = client.try_create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Colliding Task"),
+        &SorobanString::from_str(&e, "Should not overwrite"),
+        &SorobanString::from_str(&e, ""),
+        &2_000_000,
+        &deadline,
+    );
+    assert!(result.is_err());
+
+    // The original task is untouched
+    let task = client.get_task(&task_id);
+    assert_eq!(task.funding_amount, 1_000_000);
+    assert_eq!(task.assignee, Some(assignee));
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+}
+
+#[test]
+fn test_pledge_task_funded_on_claim() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let worker = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    let expiration = e.ledger().sequence() + 1000;
+    token_client.approve(&creator, &client.address, &1_000_000, &expiration);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = client.create_pledge_task(
+        &creator,
+        &SorobanString::from_str(&e, "Pledge Task"),
+        &SorobanString::from_str(&e, "Funded on commitment"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &deadline,
+    );
+
+    // Nothing is escrowed while the task waits for a worker
+    let task = client.get_task(&task_id);
+    assert!(task.pledged);
+    assert!(task.open_pool);
+    assert_eq!(client.get_task_escrow(&task_id), 0);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+
+    // Claiming pulls the pledged funds into escrow
+    client.claim_task(&worker, &task_id);
+    assert!(!client.get_task(&task_id).pledged);
+    assert_eq!(client.get_task_escrow(&task_id), 1_000_000);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+    assert_eq!(token_client.balance(&client.address), 1_000_000);
+
+    client.complete_task(&worker, &task_id);
+    client.release_funds(&creator, &task_id);
+    assert_eq!(token_client.balance(&worker), 970_000);
+}
+
+#[test]
+fn test_pledge_task_expires_unclaimed() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = client.create_pledge_task(
+        &creator,
+        &SorobanString::from_str(&e, "Pledge Task"),
+        &SorobanString::from_str(&e, "Never claimed"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &deadline,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+    client.reclaim_expired_funds(&task_id);
+
+    // No funds ever moved
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Cancelled);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+}
+
+#[test]
+fn test_required_funding() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, _token_admin_client, admin) = create_taskmaster_client(&e);
+
+    // The assignee bears the fee, so only the funding itself is needed
+    assert_eq!(client.required_funding(&1_000_000, &FeePayer::Assignee), 1_000_000);
+
+    // The creator absorbing the fee needs the 3% on top
+    assert_eq!(client.required_funding(&1_000_000, &FeePayer::Creator), 1_030_000);
+
+    // The minimum fee applies to small tasks
+    client.set_min_fee(&admin, &500);
+    assert_eq!(client.required_funding(&1_000, &FeePayer::Creator), 1_500);
+    assert_eq!(client.required_funding(&1_000, &FeePayer::Assignee), 1_000);
+
+    assert!(client.try_required_funding(&0, &FeePayer::Creator).is_err());
+}
+
+#[test]
+fn test_ledger_summary_reconciles() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_cancel_fee_bps(&admin, &100);
+
+    let now = e.ledger().timestamp();
+    let released = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, now + 86400);
+    let cancelled = create_assigned_task(&e, &client, &creator, &assignee, 500_000, now + 86400);
+    let split = create_assigned_task(&e, &client, &creator, &assignee, 400_000, now + 86400);
+    let abandoned = create_assigned_task(&e, &client, &creator, &assignee, 300_000, now + 86400);
+    let expired = create_assigned_task(&e, &client, &creator, &assignee, 200_000, now + 100);
+    let open = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 86400);
+
+    client.complete_task(&assignee, &released);
+    client.release_funds(&creator, &released);
+    client.cancel_task(&creator, &cancelled);
+    client.admin_resolve(&admin, &split, &AdminOutcome::Split(50));
+    client.assignee_cancel(&assignee, &abandoned);
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 101;
+    });
+    client.reclaim_expired_funds(&expired);
+    client.withdraw_platform_fees_to(&admin, &admin, &10_000);
+
+    let summary = client.get_ledger_summary();
+    assert_eq!(
+        summary,
+        LedgerSummary {
+            total_escrowed: 2_500_000,
+            total_paid: 970_000 + 194_000,
+            total_refunded: 495_000 + 200_000 + 300_000 + 200_000,
+            total_fees_earned: 30_000 + 5_000 + 6_000,
+            total_fees_withdrawn: 10_000,
+            fee_token_fees: 0,
+        }
+    );
+
+    // Everything escrowed is paid, refunded, taken as fees, or still locked
+    let locked = client.get_task_escrow(&open);
+    assert_eq!(
+        summary.total_escrowed,
+        summary.total_paid + summary.total_refunded + summary.total_fees_earned + locked
+    );
+    assert_eq!(
+        token_client.balance(&client.address),
+        locked + summary.total_fees_earned - summary.total_fees_withdrawn
+    );
+}
+
+#[test]
+fn test_unaccepted_assignment_lapses() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.accept_window = 3600;
+    let now = e.ledger().timestamp();
+    let deadline = now + 7 * 86400;
+    let task_id = create_assigned_task_with_options(
+        &e, &client, &creator, &assignee, 1_000_000, deadline, &options,
+    );
+    assert_eq!(client.get_task(&task_id).accept_by, Some(now + 3600));
+
+    // The window is still open
+    assert!(client.try_mark_unaccepted(&task_id).is_err());
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 3601;
+    });
+    client.mark_unaccepted(&task_id);
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Expired);
+    assert_eq!(task.accept_by, None);
+
+    // The creator gets the funds back long before the task deadline
+    client.reclaim_expired_funds(&task_id);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+}
+
+#[test]
+fn test_accepted_assignment_proceeds() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.accept_window = 3600;
+    let now = e.ledger().timestamp();
+    let task_id = create_assigned_task_with_options(
+        &e, &client, &creator, &assignee, 1_000_000, now + 86400, &options,
+    );
+
+    // Starting within the window accepts the task
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 1800;
+    });
+    client.start_task(&assignee, &task_id);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 7200;
+    });
+    assert!(client.try_mark_unaccepted(&task_id).is_err());
+
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::FundsReleased);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+}
+
+#[test]
+fn test_funding_amount_fee_overflow_bound() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    // Largest amount whose fee fits in an i128 at a 100% rate
+    let max_funding = i128::MAX / 10_000;
+    mint_tokens(&token_admin_client, &creator, max_funding + 1);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let result = client.try_create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Too Large"),
+        &SorobanString::from_str(&e, "Fee would overflow"),
+        &SorobanString::from_str(&e, ""),
+        &(max_funding + 1),
+        &deadline,
+    );
+    assert!(result.is_err());
+
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, max_funding, deadline);
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    let fee = max_funding * 300 / 10_000;
+    assert_eq!(client.get_platform_fees(), fee);
+    assert_eq!(token_client.balance(&assignee), max_funding - fee);
+}
+
+#[test]
+#[should_panic(expected = "Funding amount too large for fee calculation")]
+fn test_release_rejects_fee_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+
+    // Simulate a stored amount past the creation cap, e.g. from an older contract version
+    update_stored_task(&e, &client, task_id, |task| {
+        task.funding_amount = i128::MAX / 300 + 1;
+    });
+    client.release_funds(&creator, &task_id);
+}
+
+#[test]
+fn test_get_assignee_actionable() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let now = e.ledger().timestamp();
+    let assigned = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 86400);
+    let started = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 86400);
+    let completed = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 86400);
+    let released = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 86400);
+    let cancelled = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 86400);
+    let expired = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 100);
+
+    client.start_task(&assignee, &started);
+    client.complete_task(&assignee, &completed);
+    client.complete_task(&assignee, &released);
+    client.release_funds(&creator, &released);
+    client.cancel_task(&creator, &cancelled);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 101;
+    });
+    client.mark_expired(&expired);
+
+    assert_eq!(
+        client.get_assignee_actionable(&assignee),
+        Vec::from_array(&e, [assigned, started])
+    );
+}
+
+#[test]
+fn test_auto_sweep_fees_to_treasury() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_auto_sweep_fees(&admin, &Some(treasury.clone()));
+    assert_eq!(client.get_auto_sweep_fees(), Some(treasury.clone()));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+
+    // The simulation reports the fee without accruing it
+    let outcome = client.simulate_release(&task_id);
+    assert_eq!(outcome.platform_fee, 30_000);
+    assert_eq!(outcome.accumulated_fees, 0);
+
+    client.release_funds(&creator, &task_id);
+
+    // The fee lands in the treasury and never accrues in the contract
+    assert_eq!(token_client.balance(&treasury), 30_000);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+    assert_eq!(token_client.balance(&client.address), 0);
+    assert_eq!(client.get_platform_fees(), 0);
+    assert_eq!(client.get_ledger_summary().total_fees_withdrawn, 30_000);
+}
+
+#[test]
+fn test_manual_fee_mode_accumulates() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    // Turning the sweep off again returns to manual withdrawal
+    client.set_auto_sweep_fees(&admin, &Some(treasury.clone()));
+    client.set_auto_sweep_fees(&admin, &None);
+    assert_eq!(client.get_auto_sweep_fees(), None);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    assert_eq!(client.get_platform_fees(), 30_000);
+    assert_eq!(token_client.balance(&client.address), 30_000);
+    assert_eq!(token_client.balance(&treasury), 0);
+}
+
+#[test]
+fn test_get_task_sla_status() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_release_timeout(&admin, &1000);
+
+    let mut options = default_options(&e);
+    options.accept_window = 500;
+    let now = e.ledger().timestamp();
+    let unaccepted = create_assigned_task_with_options(
+        &e, &client, &creator, &assignee, 1_000, now + 86400, &options,
+    );
+    let overdue = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 2000);
+    let awaiting = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 86400);
+    let healthy = create_assigned_task(&e, &client, &creator, &assignee, 1_000, now + 86400);
+    client.start_task(&assignee, &overdue);
+    client.start_task(&assignee, &healthy);
+    client.complete_task(&assignee, &awaiting);
+
+    // Nothing has been breached yet
+    assert_eq!(client.get_task_sla_status(&unaccepted), SlaStatus::default());
+    assert_eq!(client.get_task_sla_status(&awaiting), SlaStatus::default());
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 2001;
+    });
+
+    let status = client.get_task_sla_status(&unaccepted);
+    assert!(status.unaccepted && !status.overdue && !status.release_overdue);
+    let status = client.get_task_sla_status(&overdue);
+    assert!(status.overdue && !status.unaccepted && !status.release_overdue);
+    let status = client.get_task_sla_status(&awaiting);
+    assert!(status.release_overdue && !status.overdue && !status.unaccepted);
+    assert_eq!(client.get_task_sla_status(&healthy), SlaStatus::default());
+}
+
+#[test]
+fn test_cancel_all() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let assigned = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let started = create_assigned_task(&e, &client, &creator, &assignee, 2_000_000, deadline);
+    let released = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let cancelled = create_assigned_task(&e, &client, &creator, &assignee, 500_000, deadline);
+    client.start_task(&assignee, &started);
+    client.complete_task(&assignee, &released);
+    client.release_funds(&creator, &released);
+    client.cancel_task(&creator, &cancelled);
+    assert_eq!(token_client.balance(&creator), 6_000_000);
+
+    assert_eq!(client.cancel_all(&creator), 2);
+    assert_eq!(client.get_task(&assigned).status, TaskStatus::Cancelled);
+    assert_eq!(client.get_task(&started).status, TaskStatus::Cancelled);
+    assert_eq!(client.get_task(&released).status, TaskStatus::FundsReleased);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+
+    // Nothing is left to cancel
+    assert_eq!(client.cancel_all(&creator), 0);
+}
+
+#[test]
+fn test_validate_task() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, _token_admin_client, admin) = create_taskmaster_client(&e);
+    let title = SorobanString::from_str(&e, "Build feature");
+    let description = SorobanString::from_str(&e, "Implement the new feature");
+    let empty = SorobanString::from_str(&e, "");
+    let now = e.ledger().timestamp();
+    let deadline = now + 86400;
+
+    client.validate_task(&title, &description, &1_000_000, &deadline);
+    assert_eq!(
+        client.try_validate_task(&empty, &description, &1_000_000, &deadline),
+        Err(Ok(TaskError::EmptyTitle))
+    );
+    assert_eq!(
+        client.try_validate_task(&title, &empty, &1_000_000, &deadline),
+        Err(Ok(TaskError::EmptyDescription))
+    );
+    assert_eq!(
+        client.try_validate_task(&title, &description, &0, &deadline),
+        Err(Ok(TaskError::NonPositiveFunding))
+    );
+    assert_eq!(
+        client.try_validate_task(&title, &description, &i128::MAX, &deadline),
+        Err(Ok(TaskError::FundingTooLarge))
+    );
+    assert_eq!(
+        client.try_validate_task(&title, &description, &1_000_000, &now),
+        Err(Ok(TaskError::DeadlineInPast))
+    );
+
+    client.set_min_task_duration(&admin, &172800);
+    assert_eq!(
+        client.try_validate_task(&title, &description, &1_000_000, &deadline),
+        Err(Ok(TaskError::DeadlineTooSoon))
+    );
+
+    // Validation is a dry run and creates nothing
+    assert_eq!(client.get_task_count(), 0);
+}
+
+#[test]
+fn test_new_deadline_respects_min_task_duration() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_min_task_duration(&admin, &3600);
+
+    let deadline = e.ledger().timestamp() + 7200;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    // A new deadline inside the minimum window is rejected on every path that sets one
+    let now = e.ledger().timestamp();
+    assert!(client
+        .try_reassign_and_extend(&creator, &task_id, &new_assignee, &(now + 60))
+        .is_err());
+    assert!(client.try_reopen_to_pool(&creator, &task_id, &(now + 60)).is_err());
+
+    client.reassign_and_extend(&creator, &task_id, &new_assignee, &(now + 3600));
+    assert_eq!(client.get_task(&task_id).deadline, now + 3600);
+}
+
+#[test]
+fn test_dispute_freezes_task() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let now = e.ledger().timestamp();
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, now + 86400);
+    client.request_cancel(&creator, &task_id);
+    client.raise_dispute(&assignee, &task_id);
+
+    // Neither party can move the escrow while the dispute is open
+    assert!(client.try_cancel_task(&creator, &task_id).is_err());
+    assert_eq!(client.cancel_all(&creator), 0);
+    assert!(client.try_assignee_cancel(&assignee, &task_id).is_err());
+    let params = relist_params(&e, 1_000_000, None);
+    assert!(client.try_relist_task(&creator, &task_id, &params).is_err());
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 86401;
+    });
+    assert!(client.try_finalize_cancel(&creator, &task_id).is_err());
+    assert!(client.try_mark_expired(&task_id).is_err());
+    assert_eq!(client.mark_expired_batch(&Vec::from_array(&e, [task_id])).len(), 0);
+    assert!(client.try_reclaim_expired_funds(&task_id).is_err());
+
+    // A task that expired before the dispute cannot be handed on either
+    update_stored_task(&e, &client, task_id, |task| task.status = TaskStatus::Expired);
+    assert!(client.try_reassign_task(&creator, &task_id, &new_assignee).is_err());
+    assert!(client.try_reopen_to_pool(&creator, &task_id, &(now + 2 * 86400)).is_err());
+    assert!(client.get_task(&task_id).disputed);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Expired);
+}
+
+#[test]
+fn test_refund_address_follows_creatorship_and_relist() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let new_creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let treasury = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+
+    // Transferring creatorship drops the previous creator's refund override
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.set_refund_address(&creator, &task_id, &treasury);
+    client.transfer_creatorship(&creator, &task_id, &new_creator);
+    assert_eq!(client.get_task(&task_id).refund_address, None);
+    client.cancel_task(&new_creator, &task_id);
+    assert_eq!(token_client.balance(&new_creator), 1_000_000);
+    assert_eq!(token_client.balance(&treasury), 0);
+
+    // A relist surplus goes to the refund address like any other refund
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.set_refund_address(&creator, &task_id, &treasury);
+    client.relist_task(&creator, &task_id, &relist_params(&e, 400_000, None));
+    assert_eq!(token_client.balance(&treasury), 600_000);
+    assert_eq!(token_client.balance(&creator), 8_000_000);
+}
+
+#[test]
+fn test_fee_token_release_without_creator_signature() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let (fee_token_client, fee_token_admin_client) = create_token_contract(&e, &admin);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    mint_tokens(&fee_token_admin_client, &creator, 100_000);
+    client.set_fee_token(&admin, &Some(fee_token_client.address.clone()));
+    client.set_release_timeout(&admin, &3600);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let mut options = default_options(&e);
+    options.auto_release = true;
+    let auto_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        deadline,
+        &options,
+    );
+    let claim_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // Auto-release pays the assignee without the creator's signature
+    client.complete_task(&assignee, &auto_id);
+    assert!(e.auths().iter().all(|(address, _)| *address != creator));
+    assert_eq!(client.get_task(&auto_id).status, TaskStatus::FundsReleased);
+
+    // So does a timeout claim
+    client.complete_task(&assignee, &claim_id);
+    e.ledger().with_mut(|li| {
+        li.timestamp += 3600;
+    });
+    client.claim_after_timeout(&assignee, &claim_id);
+    assert!(e.auths().iter().all(|(address, _)| *address != creator));
+
+    // The assignee receives the full escrow and the fees become the creator's debt
+    assert_eq!(token_client.balance(&assignee), 2_000_000);
+    assert_eq!(client.get_fee_token_debt(&creator), 60_000);
+    assert_eq!(fee_token_client.balance(&creator), 100_000);
+    assert_eq!(client.get_fee_token_fees(), 0);
+
+    // Outstanding debt blocks new tasks and changing the fee token
+    let result = client.try_create_task(
+        &creator,
+        &SorobanString::from_str(&e, "Next Task"),
+        &SorobanString::from_str(&e, "Next Description"),
+        &SorobanString::from_str(&e, ""),
+        &1_000_000,
+        &(e.ledger().timestamp() + 86400),
+    );
+    assert!(result.is_err());
+    assert!(client.try_set_fee_token(&admin, &None).is_err());
+
+    // Settling pays the fee token balance and clears the debt
+    client.settle_fee_token_debt(&creator);
+    assert_eq!(client.get_fee_token_debt(&creator), 0);
+    assert_eq!(fee_token_client.balance(&creator), 40_000);
+    assert_eq!(client.get_fee_token_fees(), 60_000);
+    assert!(client.try_settle_fee_token_debt(&creator).is_err());
+    create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+}
+
+#[test]
+fn test_simulate_release_matches_release_with_fee_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let (fee_token_client, fee_token_admin_client) = create_token_contract(&e, &admin);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 20_000_000_000);
+    mint_tokens(&fee_token_admin_client, &creator, 1_000_000_000);
+    client.set_fee_token(&admin, &Some(fee_token_client.address.clone()));
+
+    for funding_amount in [100i128, 333i128, 1_000_000i128, 10_000_000_000i128] {
+        let task_id = create_assigned_task(
+            &e,
+            &client,
+            &creator,
+            &assignee,
+            funding_amount,
+            e.ledger().timestamp() + 86400,
+        );
+        client.complete_task(&assignee, &task_id);
+
+        let balance_before = token_client.balance(&assignee);
+        let fees_before = client.get_fee_token_fees();
+        let outcome = client.simulate_release(&task_id);
+        let breakdown = client.get_task_funding_breakdown(&task_id);
+
+        client.release_funds(&creator, &task_id);
+
+        // The whole escrow goes to the assignee and the fee is paid in the fee token
+        assert_eq!(token_client.balance(&assignee) - balance_before, outcome.assignee_amount);
+        assert_eq!(outcome.assignee_amount, funding_amount);
+        assert_eq!(breakdown.assignee_payout, outcome.assignee_amount);
+        assert_eq!(breakdown.platform_fee, outcome.platform_fee);
+        assert_eq!(client.get_fee_token_fees() - fees_before, outcome.platform_fee);
+        assert_eq!(client.get_platform_fees(), outcome.accumulated_fees);
+        assert_eq!(outcome.accumulated_fees, 0);
+    }
+}
+
+#[test]
+fn test_multisig_fee_token_withdrawal_requires_approvals() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_admin_client, admin, signers) = create_multisig_taskmaster_client(&e);
+    let (fee_token_client, fee_token_admin_client) = create_token_contract(&e, &admin);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    mint_tokens(&fee_token_admin_client, &creator, 100_000);
+    client.set_fee_token(&admin, &Some(fee_token_client.address.clone()));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+
+    // A single approval is not enough
+    let action = WithdrawalAction::FeeTokenFees(admin.clone(), 30_000);
+    let request_id = client.propose_withdrawal(&admin, &action);
+    client.approve_withdrawal(&signers.get(0).unwrap(), &request_id);
+    assert!(client.try_withdraw_fee_token_fees(&admin).is_err());
+
+    client.approve_withdrawal(&signers.get(1).unwrap(), &request_id);
+    client.withdraw_fee_token_fees(&admin);
+    assert_eq!(fee_token_client.balance(&admin), 30_000);
+    assert_eq!(client.get_withdrawal_request(&request_id), None);
+}
+
+#[test]
+fn test_multisig_auto_sweep_requires_approvals() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_admin_client, admin, signers) = create_multisig_taskmaster_client(&e);
+    let treasury = Address::generate(&e);
+
+    // Redirecting fees needs the same approvals as withdrawing them
+    assert!(client.try_set_auto_sweep_fees(&admin, &Some(treasury.clone())).is_err());
+
+    let action = WithdrawalAction::AutoSweep(Some(treasury.clone()));
+    let request_id = client.propose_withdrawal(&admin, &action);
+    client.approve_withdrawal(&signers.get(0).unwrap(), &request_id);
+    client.approve_withdrawal(&signers.get(1).unwrap(), &request_id);
+
+    // The approvals only cover the treasury they name
+    assert!(client.try_set_auto_sweep_fees(&admin, &Some(Address::generate(&e))).is_err());
+    client.set_auto_sweep_fees(&admin, &Some(treasury.clone()));
+    assert_eq!(client.get_auto_sweep_fees(), Some(treasury));
+    assert_eq!(client.get_withdrawal_request(&request_id), None);
+}
+
+#[test]
+fn test_reclaim_overdue_task_needs_creator_when_started_or_restricted() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let started_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    let assigned_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.start_task(&assignee, &started_id);
+    client.set_restrict_expiry(&admin, &true);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    // Without the creator's authorization neither overdue task can be reclaimed
+    e.set_auths(&[]);
+    assert!(client.try_reclaim_expired_funds(&started_id).is_err());
+    assert!(client.try_reclaim_expired_funds(&assigned_id).is_err());
+    assert_eq!(client.get_task(&started_id).status, TaskStatus::InProgress);
+
+    // The creator may reclaim both
+    e.mock_all_auths();
+    client.reclaim_expired_funds(&started_id);
+    client.reclaim_expired_funds(&assigned_id);
+    assert_eq!(token_client.balance(&creator), 10_000_000);
+}
+
+#[test]
+fn test_reclaim_overdue_task_applies_expiry_action() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let backup = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.expiry_action = ExpiryAction::AutoReassignToBackup(backup.clone());
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        deadline,
+        &options,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    // The backup hand-over cannot be skipped by reclaiming straight away
+    assert!(client.try_reclaim_expired_funds(&task_id).is_err());
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+
+    client.mark_expired(&task_id);
+    assert_eq!(client.get_task(&task_id).assignee, Some(backup));
+}
+
+#[test]
+fn test_expiry_reassign_respects_backup_capacity() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let backup = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_max_active_assignments(&admin, &1);
+
+    let deadline = e.ledger().timestamp() + 86400;
+    create_assigned_task(&e, &client, &creator, &backup, 1_000_000, deadline + 86400);
+
+    let mut options = default_options(&e);
+    options.expiry_action = ExpiryAction::AutoReassignToBackup(backup.clone());
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        deadline,
+        &options,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+
+    // The busy backup does not take the task; it stays expired for the creator
+    client.mark_expired(&task_id);
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Expired);
+    assert_eq!(task.assignee, Some(assignee));
+    assert_eq!(client.get_assigned_tasks(&backup).len(), 1);
+
+    client.reclaim_expired_funds(&task_id);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+}
+
+#[test]
+fn test_register_user_stores_username_under_typed_key() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, _token_admin_client, _admin) = create_taskmaster_client(&e);
+    let user = Address::generate(&e);
+    let username = SorobanString::from_str(&e, "alice");
+
+    client.register_user(&user, &username);
+
+    let stored: Option<SorobanString> = e.as_contract(&client.address, || {
+        e.storage().instance().get(&DataKey::Username(user.clone()))
+    });
+    assert_eq!(stored, Some(username));
+    assert!(client.try_register_user(&user, &SorobanString::from_str(&e, "bob")).is_err());
+}
+
+#[test]
+fn test_ledger_summary_reconciles_with_fee_token() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let (fee_token_client, fee_token_admin_client) = create_token_contract(&e, &admin);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    mint_tokens(&fee_token_admin_client, &creator, 100_000);
+    client.set_fee_token(&admin, &Some(fee_token_client.address.clone()));
+    client.set_cancel_fee_bps(&admin, &100);
+
+    let now = e.ledger().timestamp();
+    let released = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, now + 86400);
+    let cancelled = create_assigned_task(&e, &client, &creator, &assignee, 500_000, now + 86400);
+    let open = create_assigned_task(&e, &client, &creator, &assignee, 100_000, now + 86400);
+
+    client.complete_task(&assignee, &released);
+    client.release_funds(&creator, &released);
+    client.cancel_task(&creator, &cancelled);
+
+    // The release fee is paid in the fee token; only the cancellation fee stays in escrow
+    let summary = client.get_ledger_summary();
+    assert_eq!(summary.total_paid, 1_000_000);
+    assert_eq!(summary.total_fees_earned, 5_000);
+    assert_eq!(summary.fee_token_fees, 30_000);
+    assert_eq!(fee_token_client.balance(&client.address), summary.fee_token_fees);
+
+    let locked = client.get_task_escrow(&open);
+    assert_eq!(
+        summary.total_escrowed,
+        summary.total_paid + summary.total_refunded + summary.total_fees_earned + locked
+    );
+    assert_eq!(
+        token_client.balance(&client.address),
+        locked + summary.total_fees_earned - summary.total_fees_withdrawn
+    );
+}
+
+// Mock oracle approving the first release check and rejecting every later one
+#[contract]
+pub struct OneShotOracle;
+
+#[contractimpl]
+impl OneShotOracle {
+    pub fn verified(env: Env, _task_id: u64) -> bool {
+        let asked: bool = env.storage().instance().get(&symbol_short!("ASKED")).unwrap_or(false);
+        env.storage().instance().set(&symbol_short!("ASKED"), &true);
+        !asked
+    }
+}
+
+#[test]
+fn test_auto_release_consults_oracle_once() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let oracle = e.register(OneShotOracle, ());
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.auto_release = true;
+    options.oracle = Some(oracle);
+    options.oracle_fn = Some(symbol_short!("verified"));
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+        &options,
+    );
+
+    // The oracle's single approval is enough to pay out on completion
+    client.complete_task(&assignee, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::FundsReleased);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+}
+
+#[test]
+fn test_cancel_request_does_not_survive_reassignment() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let new_assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let task_id = create_assigned_task(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        e.ledger().timestamp() + 86400,
+    );
+    client.request_cancel(&creator, &task_id);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp += 2 * 86400;
+    });
+    client.mark_expired(&task_id);
+    client.reassign_task(&creator, &task_id, &new_assignee);
+
+    // The notice was given to the old assignee, so the new one cannot be cancelled on it
+    let task = client.get_task(&task_id);
+    assert_eq!(task.cancel_requested_at, None);
+    assert!(client.try_finalize_cancel(&creator, &task_id).is_err());
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Assigned);
+    assert_eq!(token_client.balance(&creator), 9_000_000);
+}
+
+#[test]
+fn test_reopen_to_pool_clears_assignment_state() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let deadline = e.ledger().timestamp() + 100;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    e.ledger().with_mut(|li| {
+        li.timestamp = deadline + 1;
+    });
+    client.mark_expired(&task_id);
+
+    // Leave behind everything the old assignment could have set
+    update_stored_task(&e, &client, task_id, |task| {
+        task.accept_by = Some(deadline);
+        task.cancel_requested_at = Some(deadline);
+        task.deliverable_hash = Some(BytesN::from_array(&e, &[7; 32]));
+        task.assignee_approved = true;
+        task.completed_at = Some(deadline);
+        task.started_at = Some(deadline);
+        task.reminded = true;
+    });
+    client.reopen_to_pool(&creator, &task_id, &(deadline + 1000));
+
+    let task = client.get_task(&task_id);
+    assert_eq!(task.accept_by, None);
+    assert_eq!(task.cancel_requested_at, None);
+    assert_eq!(task.deliverable_hash, None);
+    assert!(!task.assignee_approved);
+    assert_eq!(task.completed_at, None);
+    assert_eq!(task.started_at, None);
+    assert!(!task.reminded);
+}
+
+#[test]
+fn test_unaccepted_assignment_follows_expiry_action() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, _admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let backup = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.accept_window = 3600;
+    options.expiry_action = ExpiryAction::AutoReassignToBackup(backup.clone());
+    let now = e.ledger().timestamp();
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        now + 86400,
+        &options,
+    );
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 3601;
+    });
+    client.mark_unaccepted(&task_id);
+
+    // The backup takes over with a fresh acceptance window
+    let task = client.get_task(&task_id);
+    assert_eq!(task.status, TaskStatus::Assigned);
+    assert_eq!(task.assignee, Some(backup.clone()));
+    assert_eq!(task.accept_by, Some(now + 3601 + 3600));
+    assert_eq!(client.get_assigned_tasks(&assignee).len(), 0);
+    assert_eq!(client.get_assigned_tasks(&backup).len(), 1);
+}
+
+#[test]
+fn test_mark_unaccepted_by_restricted_policy() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, _token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let stranger = Address::generate(&e);
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+
+    let mut options = default_options(&e);
+    options.accept_window = 3600;
+    let now = e.ledger().timestamp();
+    let task_id = create_assigned_task_with_options(
+        &e,
+        &client,
+        &creator,
+        &assignee,
+        1_000_000,
+        now + 86400,
+        &options,
+    );
+    client.set_restrict_expiry(&admin, &true);
+
+    e.ledger().with_mut(|li| {
+        li.timestamp = now + 3601;
+    });
+
+    // Anonymous and outside callers are turned away, but the creator can still lapse it
+    assert!(client.try_mark_unaccepted(&task_id).is_err());
+    assert!(client.try_mark_unaccepted_by(&stranger, &task_id).is_err());
+    client.mark_unaccepted_by(&creator, &task_id);
+    assert_eq!(client.get_task(&task_id).status, TaskStatus::Expired);
+}
+
+#[test]
+fn test_vault_deposit_booked_only_when_pulled() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let vault = e.register(NonPullingVault, ());
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_vault(&admin, &Some(vault.clone()));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+
+    // The vault reported success but took nothing, so the escrow is still held here
+    assert_eq!(client.get_task(&task_id).vault_deposit, 0);
+    assert_eq!(token_client.balance(&client.address), 1_000_000);
+    assert_eq!(client.check_solvency(), (1_000_000, 1_000_000, true));
+
+    // Nothing is outstanding, so the vault can be dropped and the task paid out
+    client.set_vault(&admin, &None);
+    client.complete_task(&assignee, &task_id);
+    client.release_funds(&creator, &task_id);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+}
+
+#[test]
+fn test_write_off_dead_vault() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_client, token_admin_client, admin) = create_taskmaster_client(&e);
+    let creator = Address::generate(&e);
+    let assignee = Address::generate(&e);
+    let vault = e.register(LeakyVault, (token_client.address.clone(),));
+
+    mint_tokens(&token_admin_client, &creator, 10_000_000);
+    client.set_vault(&admin, &Some(vault.clone()));
+
+    let deadline = e.ledger().timestamp() + 86400;
+    let task_id = create_assigned_task(&e, &client, &creator, &assignee, 1_000_000, deadline);
+    client.complete_task(&assignee, &task_id);
+
+    // The vault keeps the escrow, so the release cannot go through
+    assert!(client.try_release_funds(&creator, &task_id).is_err());
+    assert!(client.try_set_vault(&admin, &None).is_err());
+
+    // The vault can only be written off once its deposits have been made good
+    assert!(client.try_write_off_vault(&creator).is_err());
+    assert!(client.try_write_off_vault(&admin).is_err());
+    mint_tokens(&token_admin_client, &client.address, 1_000_000);
+    client.write_off_vault(&admin);
+
+    assert_eq!(client.get_vault(), None);
+    assert_eq!(client.get_task(&task_id).vault_deposit, 0);
+    assert_eq!(client.check_solvency(), (1_000_000, 1_000_000, true));
+
+    client.release_funds(&creator, &task_id);
+    assert_eq!(token_client.balance(&assignee), 970_000);
+}
+
+#[test]
+fn test_multisig_approvals_bound_to_request() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_admin_client, admin, signers) = create_multisig_taskmaster_client(&e);
+    let token_client = token::Client::new(&e, &token_admin_client.address);
+
+    // Approvals on separate requests do not pool together
+    let action = WithdrawalAction::PlatformFees(admin.clone(), 30_000);
+    let first = client.propose_withdrawal(&admin, &action);
+    let second = client.propose_withdrawal(&admin, &action);
+    assert_ne!(first, second);
+    client.approve_withdrawal(&signers.get(0).unwrap(), &first);
+    client.approve_withdrawal(&signers.get(1).unwrap(), &second);
+    assert!(client.try_withdraw_platform_fees(&admin).is_err());
+
+    // A signer cannot approve the same request twice, or a request that does not exist
+    assert!(client.try_approve_withdrawal(&signers.get(0).unwrap(), &first).is_err());
+    assert!(client.try_approve_withdrawal(&signers.get(0).unwrap(), &99).is_err());
+
+    // A cancelled request can no longer be approved
+    client.cancel_withdrawal(&admin, &second);
+    assert_eq!(client.get_withdrawal_request(&second), None);
+    assert!(client.try_approve_withdrawal(&signers.get(2).unwrap(), &second).is_err());
+
+    client.approve_withdrawal(&signers.get(2).unwrap(), &first);
+    client.withdraw_platform_fees(&admin);
+    assert_eq!(token_client.balance(&admin), 30_000);
+}
+
+#[test]
+fn test_multisig_withdrawal_cannot_be_redirected() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let (client, token_admin_client, admin, signers) = create_multisig_taskmaster_client(&e);
+    let token_client = token::Client::new(&e, &token_admin_client.address);
+    let attacker = Address::generate(&e);
+
+    // Signers approve a withdrawal of the whole balance to the deployer
+    let action = WithdrawalAction::PlatformFees(admin.clone(), 30_000);
+    let request_id = client.propose_withdrawal(&admin, &action);
+    client.approve_withdrawal(&signers.get(0).unwrap(), &request_id);
+    client.approve_withdrawal(&signers.get(1).unwrap(), &request_id);
+
+    // Those approvals cannot send the fees elsewhere or change the amount
+    assert!(client.try_withdraw_platform_fees_to(&admin, &attacker, &30_000).is_err());
+    assert!(client.try_withdraw_platform_fees_to(&admin, &admin, &10_000).is_err());
+    assert_eq!(token_client.balance(&attacker), 0);
+    assert!(client.get_withdrawal_request(&request_id).is_some());
+
+    // The approved withdrawal still goes through as proposed
+    client.withdraw_platform_fees_to(&admin, &admin, &30_000);
+    assert_eq!(token_client.balance(&admin), 30_000);
+    assert_eq!(client.get_platform_fees(), 0);
+}
@@ -111,7 +111,7 @@ const TaskList: React.FC<TaskListProps> = ({ filter = "all", onApplyForTask }) =
           await taskMasterService.cancelTask(taskId, address);
           break;
         case "reclaim":
-          await taskMasterService.reclaimExpiredFunds(taskId, address);
+          await taskMasterService.reclaimExpiredFunds(taskId);
           break;
         case "reassign": {
           // For reassignment, we'd need a dialog to get new assignee
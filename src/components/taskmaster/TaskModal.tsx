@@ -67,7 +67,7 @@ const TaskModal: React.FC<TaskModalProps> = ({ taskId, isOpen, onClose, onTaskUp
           await taskMasterService.cancelTask(task.id, address);
           break;
         case "reclaim":
-          await taskMasterService.reclaimExpiredFunds(task.id, address);
+          await taskMasterService.reclaimExpiredFunds(task.id);
           break;
         case "reassign": {
           const newAssignee = prompt("Enter new assignee address:");
@@ -4,9 +4,11 @@ import {
   Client as ContractClient,
   ClientOptions as ContractClientOptions,
   MethodOptions,
+  Result,
   Spec as ContractSpec,
 } from '@stellar/stellar-sdk/contract';
 import type {
+  u32,
   u64,
   i128,
   Option,
@@ -46,33 +48,212 @@ export interface TaskApplication {
 }
 
 
+export interface TaskComment {
+  author: string;
+  posted_at: u64;
+  text: string;
+}
+
+
 export interface Task {
+  abandoned: boolean;
+  accept_by: Option<u64>;
+  accept_window: u64;
   applications: Array<TaskApplication>;
   assignee: Option<string>;
   assignee_approved: boolean;
+  auto_release: boolean;
+  cancel_requested_at: Option<u64>;
+  comments: Array<TaskComment>;
   completed_at: Option<u64>;
+  completion_proof: Option<string>;
   created_at: u64;
   creator: string;
   creator_approved: boolean;
+  creator_rated: boolean;
   deadline: u64;
+  deliverable_hash: Option<Buffer>;
+  depends_on: Array<u64>;
   description: string;
+  disputed: boolean;
+  expiry_action: ExpiryAction;
+  fee_bps: u32;
   funding_amount: i128;
-  github_link: string;
+  github_link: Option<string>;
   id: u64;
+  notify_on_release: boolean;
+  open_pool: boolean;
+  oracle: Option<string>;
+  oracle_fn: Option<string>;
+  payout_address: Option<string>;
+  pledged: boolean;
+  previous_assignees: Array<string>;
+  priority: u32;
+  processing: boolean;
+  refund_address: Option<string>;
+  released_so_far: i128;
+  relisted_from: Option<u64>;
+  reminded: boolean;
+  started_at: Option<u64>;
   status: TaskStatus;
   title: string;
+  token_decimals: u32;
+  vault_deposit: i128;
+  was_expired: boolean;
+}
+
+export type ExpiryAction = {tag: "Refund", values: void} | {tag: "AutoReassignToBackup", values: readonly [string]} | {tag: "ReopenToPool", values: void};
+
+export type AdminOutcome = {tag: "PayAssignee", values: void} | {tag: "RefundCreator", values: void} | {tag: "Split", values: readonly [u32]};
+
+export type DisputeFeePolicy = {tag: "NoFeeOnRefund", values: void} | {tag: "FeeOnDisputedPayout", values: void};
+
+export type FeePayer = {tag: "Assignee", values: void} | {tag: "Creator", values: void};
+
+
+export interface TaskPage {
+  next_cursor: Option<u64>;
+  tasks: Array<Task>;
+}
+
+
+export interface TaskOptions {
+  accept_window: u64;
+  auto_release: boolean;
+  client_ref: Option<string>;
+  depends_on: Array<u64>;
+  expiry_action: ExpiryAction;
+  notify_on_release: boolean;
+  oracle: Option<string>;
+  oracle_fn: Option<string>;
+  priority: u32;
+}
+
+
+export interface ReleaseOutcome {
+  accumulated_fees: i128;
+  assignee: string;
+  assignee_amount: i128;
+  platform_fee: i128;
+}
+
+
+export interface UserFinancials {
+  earned: i128;
+  locked: i128;
+  spent: i128;
+}
+
+
+export interface AssigneeReputation {
+  completed: u32;
+  failed: u32;
+}
+
+
+export interface FundingBreakdown {
+  assignee_payout: i128;
+  escrow_held: i128;
+  gross_funding: i128;
+  platform_fee: i128;
+}
+
+
+export interface SlaStatus {
+  overdue: boolean;
+  release_overdue: boolean;
+  unaccepted: boolean;
+}
+
+
+export interface RelistParams {
+  assignee: Option<string>;
+  deadline: u64;
+  description: string;
+  funding_amount: i128;
+  github_link: string;
+  title: string;
+}
+
+export type WithdrawalAction = {tag: "PlatformFees", values: readonly [string, i128]} | {tag: "FeeTokenFees", values: readonly [string, i128]} | {tag: "AutoSweep", values: readonly [Option<string>]};
+
+
+export interface WithdrawalRequest {
+  action: WithdrawalAction;
+  approvals: Array<string>;
+  id: u64;
+}
+
+
+export interface Settings {
+  block_deployer_assignee: boolean;
+  cancel_fee_bps: u32;
+  deployer: string;
+  dispute_fee_policy: DisputeFeePolicy;
+  fee_token: Option<string>;
+  fee_treasury: Option<string>;
+  max_active_assignments: u32;
+  min_fee: i128;
+  min_task_duration: u64;
+  min_work_time: u64;
+  platform_fee_percentage: u32;
+  priority_fee_bps: Array<u32>;
+  release_timeout: u64;
+  require_github_link: boolean;
+  restrict_expiry: boolean;
+  token: string;
+  vault: Option<string>;
+  withdrawal_threshold: u32;
+}
+
+
+export interface Stats {
+  active_tasks: u64;
+  completed_tasks: u64;
+  fee_token_fees: i128;
+  release_fees: i128;
+  released_volume: i128;
+  total_escrowed: i128;
+  total_fees_earned: i128;
+  total_fees_withdrawn: i128;
+  total_paid: i128;
+  total_refunded: i128;
+  total_tasks: u64;
+}
+
+
+export interface LedgerSummary {
+  fee_token_fees: i128;
+  total_escrowed: i128;
+  total_fees_earned: i128;
+  total_fees_withdrawn: i128;
+  total_paid: i128;
+  total_refunded: i128;
 }
 
+export const Errors = {
+  1: {message:""},
+  2: {message:""},
+  3: {message:""},
+  4: {message:""},
+  5: {message:""},
+  6: {message:""}
+}
+export type DataKey = {tag: "Tasks", values: void} | {tag: "UserTasks", values: readonly [string]} | {tag: "AssignedTasks", values: readonly [string]} | {tag: "TaskCounter", values: void} | {tag: "Token", values: void} | {tag: "Deployer", values: void} | {tag: "PlatformFees", values: void} | {tag: "UserProfiles", values: void} | {tag: "Username", values: readonly [string]} | {tag: "ReleaseTimeout", values: void} | {tag: "FeeSigners", values: void} | {tag: "SignThreshold", values: void} | {tag: "WithdrawRequests", values: void} | {tag: "WithdrawCounter", values: void} | {tag: "MaxActive", values: void} | {tag: "UserFinancials", values: void} | {tag: "Reputation", values: void} | {tag: "MinDuration", values: void} | {tag: "RequireLink", values: void} | {tag: "CancelFeeBps", values: void} | {tag: "ClientRefs", values: void} | {tag: "CreatorRatings", values: void} | {tag: "Commitments", values: void} | {tag: "CreatorFees", values: void} | {tag: "Vault", values: void} | {tag: "VaultBalance", values: void} | {tag: "MinFee", values: void} | {tag: "Stats", values: void} | {tag: "BlockDeployer", values: void} | {tag: "RestrictExpiry", values: void} | {tag: "Candidates", values: void} | {tag: "PriorityFees", values: void} | {tag: "GithubTasks", values: void} | {tag: "FeeToken", values: void} | {tag: "FeeTokenFees", values: void} | {tag: "FeeTokenDebts", values: void} | {tag: "DisputeFee", values: void} | {tag: "MinWorkTime", values: void} | {tag: "FeeTreasury", values: void} | {tag: "Version", values: void};
+
 export interface Client {
   /**
    * Construct and simulate a initialize transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Initialize the contract with token address
    * 
    * # Arguments
-   * * `token` - Address of the token contract for payments
+   * * `token` - Address of the token contract for payments (the native XLM
+   * Stellar Asset Contract or any SEP-41 token)
    * * `deployer` - Address of the contract deployer who will receive platform fees
+   * * `signers` - Addresses allowed to approve fee withdrawals (empty for none)
+   * * `threshold` - Number of signer approvals required per withdrawal (0 if no signers)
    */
-  initialize: ({token, deployer}: {token: string, deployer: string}, options?: {
+  initialize: ({token, deployer, signers, threshold}: {token: string, deployer: string, signers: Array<string>, threshold: u32}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -89,6 +270,54 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<null>>
 
+  /**
+   * Construct and simulate a get_storage_version transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the storage layout version the contract was initialized with
+   * 
+   * # Returns
+   * The layout version, or 0 if the contract is not initialized
+   */
+  get_storage_version: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a is_initialized transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether the contract has been initialized
+   * 
+   * # Returns
+   * `true` once `initialize` has been called
+   */
+  is_initialized: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
   /**
    * Construct and simulate a register_user transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Register a user profile with a permanent username
@@ -195,15 +424,15 @@ export interface Client {
   }) => Promise<AssembledTransaction<Array<TaskApplication>>>
 
   /**
-   * Construct and simulate a assign_to_applicant transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Assign a task to an applicant
+   * Construct and simulate a add_comment transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Post a comment on a task
    * 
    * # Arguments
-   * * `creator` - Address of the task creator
-   * * `task_id` - ID of the task
-   * * `applicant` - Address of the applicant to assign
+   * * `author` - Address of the task creator or assignee
+   * * `task_id` - ID of the task to comment on
+   * * `text` - Comment body
    */
-  assign_to_applicant: ({creator, task_id, applicant}: {creator: string, task_id: u64, applicant: string}, options?: {
+  add_comment: ({author, task_id, text}: {author: string, task_id: u64, text: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -221,21 +450,16 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a create_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Create a new task with funding (without assigning)
+   * Construct and simulate a get_comments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all comments on a task
    * 
    * # Arguments
-   * * `creator` - Address of the task creator
-   * * `title` - Task title
-   * * `description` - Detailed description of the task
-   * * `github_link` - GitHub repository link (can be empty string)
-   * * `funding_amount` - Amount to fund the task (in stroops)
-   * * `deadline` - Unix timestamp for the task deadline
+   * * `task_id` - ID of the task
    * 
    * # Returns
-   * The ID of the newly created task
+   * Vector of the task's comments, oldest first
    */
-  create_task: ({creator, title, description, github_link, funding_amount, deadline}: {creator: string, title: string, description: string, github_link: string, funding_amount: i128, deadline: u64}, options?: {
+  get_comments: ({task_id}: {task_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -250,18 +474,18 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<u64>>
+  }) => Promise<AssembledTransaction<Array<TaskComment>>>
 
   /**
-   * Construct and simulate a assign_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Assign a task to a user (only if not already assigned)
+   * Construct and simulate a assign_to_applicant transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Assign a task to an applicant
    * 
    * # Arguments
    * * `creator` - Address of the task creator
-   * * `task_id` - ID of the task to assign
-   * * `assignee` - Address of the user to assign the task to
+   * * `task_id` - ID of the task
+   * * `applicant` - Address of the applicant to assign
    */
-  assign_task: ({creator, task_id, assignee}: {creator: string, task_id: u64, assignee: string}, options?: {
+  assign_to_applicant: ({creator, task_id, applicant}: {creator: string, task_id: u64, applicant: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -279,14 +503,21 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a complete_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Mark a task as complete by the assignee
+   * Construct and simulate a create_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a new task with funding (without assigning)
    * 
    * # Arguments
-   * * `assignee` - Address of the assignee
-   * * `task_id` - ID of the task to complete
+   * * `creator` - Address of the task creator
+   * * `title` - Task title
+   * * `description` - Detailed description of the task
+   * * `github_link` - GitHub repository link (empty string for none)
+   * * `funding_amount` - Amount to fund the task (in stroops)
+   * * `deadline` - Unix timestamp for the task deadline
+   * 
+   * # Returns
+   * The ID of the newly created task
    */
-  complete_task: ({assignee, task_id}: {assignee: string, task_id: u64}, options?: {
+  create_task: ({creator, title, description, github_link, funding_amount, deadline}: {creator: string, title: string, description: string, github_link: string, funding_amount: i128, deadline: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -301,17 +532,25 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a start_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update task status to InProgress
+   * Construct and simulate a create_task_with_options transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a new task with funding and additional options
    * 
    * # Arguments
-   * * `assignee` - Address of the assignee
-   * * `task_id` - ID of the task to start
+   * * `creator` - Address of the task creator
+   * * `title` - Task title
+   * * `description` - Detailed description of the task
+   * * `github_link` - GitHub repository link (empty string for none)
+   * * `funding_amount` - Amount to fund the task (in stroops)
+   * * `deadline` - Unix timestamp for the task deadline
+   * * `options` - Optional task settings such as priority
+   * 
+   * # Returns
+   * The ID of the newly created task
    */
-  start_task: ({assignee, task_id}: {assignee: string, task_id: u64}, options?: {
+  create_task_with_options: ({creator, title, description, github_link, funding_amount, deadline, options}: {creator: string, title: string, description: string, github_link: string, funding_amount: i128, deadline: u64, options: TaskOptions}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -326,17 +565,27 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a release_funds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Release funds to the assignee after creator approval
+   * Construct and simulate a create_pledge_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Post a task whose funding is only escrowed once a worker takes it on
+   * 
+   * The task is open for any worker to claim. The creator must approve this contract
+   * to spend `funding_amount`, which is pulled when the task is assigned or claimed.
    * 
    * # Arguments
    * * `creator` - Address of the task creator
-   * * `task_id` - ID of the task to release funds for
+   * * `title` - Task title
+   * * `description` - Detailed description of the task
+   * * `github_link` - GitHub repository link (empty string for none)
+   * * `funding_amount` - Amount pledged to the task (in stroops)
+   * * `deadline` - Unix timestamp for the task deadline
+   * 
+   * # Returns
+   * The ID of the newly created task
    */
-  release_funds: ({creator, task_id}: {creator: string, task_id: u64}, options?: {
+  create_pledge_task: ({creator, title, description, github_link, funding_amount, deadline}: {creator: string, title: string, description: string, github_link: string, funding_amount: i128, deadline: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -351,17 +600,25 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a cancel_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Cancel a task and refund the creator
+   * Construct and simulate a create_task_for transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a task operated by a manager but funded by another account
    * 
    * # Arguments
-   * * `creator` - Address of the task creator
-   * * `task_id` - ID of the task to cancel
+   * * `manager` - Address authorizing the creation
+   * * `funder` - Address whose tokens fund the task; recorded as the creator
+   * * `title` - Task title
+   * * `description` - Detailed description of the task
+   * * `github_link` - GitHub repository link (empty string for none)
+   * * `funding_amount` - Amount to fund the task (in stroops)
+   * * `deadline` - Unix timestamp for the task deadline
+   * 
+   * # Returns
+   * The ID of the newly created task
    */
-  cancel_task: ({creator, task_id}: {creator: string, task_id: u64}, options?: {
+  create_task_for: ({manager, funder, title, description, github_link, funding_amount, deadline}: {manager: string, funder: string, title: string, description: string, github_link: string, funding_amount: i128, deadline: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -376,16 +633,25 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a mark_expired transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Handle expired tasks - mark as expired
+   * Construct and simulate a create_task_in transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a new task whose deadline is a duration from the current ledger time
    * 
    * # Arguments
-   * * `task_id` - ID of the expired task
+   * * `creator` - Address of the task creator
+   * * `title` - Task title
+   * * `description` - Detailed description of the task
+   * * `github_link` - GitHub repository link (empty string for none)
+   * * `funding_amount` - Amount to fund the task (in stroops)
+   * * `duration_secs` - Seconds from now until the task deadline
+   * * `assignee` - Optional address to assign the task to straight away
+   * 
+   * # Returns
+   * The ID of the newly created task
    */
-  mark_expired: ({task_id}: {task_id: u64}, options?: {
+  create_task_in: ({creator, title, description, github_link, funding_amount, duration_secs, assignee}: {creator: string, title: string, description: string, github_link: string, funding_amount: i128, duration_secs: u64, assignee: Option<string>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -400,17 +666,25 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a reclaim_expired_funds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reclaim funds from expired task
+   * Construct and simulate a create_task_committed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a new task reserved for an assignee whose identity stays hidden until they reveal
    * 
    * # Arguments
    * * `creator` - Address of the task creator
-   * * `task_id` - ID of the expired task
+   * * `title` - Task title
+   * * `description` - Detailed description of the task
+   * * `github_link` - GitHub repository link (empty string for none)
+   * * `funding_amount` - Amount to fund the task (in stroops)
+   * * `deadline` - Unix timestamp for the task deadline
+   * * `assignee_commitment` - SHA-256 of the assignee's XDR-encoded address followed by a salt
+   * 
+   * # Returns
+   * The ID of the newly created task
    */
-  reclaim_expired_funds: ({creator, task_id}: {creator: string, task_id: u64}, options?: {
+  create_task_committed: ({creator, title, description, github_link, funding_amount, deadline, assignee_commitment}: {creator: string, title: string, description: string, github_link: string, funding_amount: i128, deadline: u64, assignee_commitment: Buffer}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -425,16 +699,18 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<null>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a withdraw_platform_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Withdraw accumulated platform fees (only deployer can call)
+   * Construct and simulate a reveal_and_accept transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal as the committed assignee of a task and accept it
    * 
    * # Arguments
-   * * `deployer` - Address of the contract deployer
+   * * `assignee` - Address of the committed assignee
+   * * `task_id` - ID of the committed task
+   * * `salt` - Salt used when computing the commitment
    */
-  withdraw_platform_fees: ({deployer}: {deployer: string}, options?: {
+  reveal_and_accept: ({assignee, task_id, salt}: {assignee: string, task_id: u64, salt: Buffer}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -452,13 +728,22 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_platform_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get current accumulated platform fees
+   * Construct and simulate a create_task_with_candidates transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Create a new task offered to several candidates, the first of whom to claim it is assigned
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `title` - Task title
+   * * `description` - Detailed description of the task
+   * * `github_link` - GitHub repository link (empty string for none)
+   * * `funding_amount` - Amount to fund the task (in stroops)
+   * * `deadline` - Unix timestamp for the task deadline
+   * * `candidates` - Addresses allowed to claim the task
    * 
    * # Returns
-   * The total amount of accumulated platform fees
+   * The ID of the newly created task
    */
-  get_platform_fees: (options?: {
+  create_task_with_candidates: ({creator, title, description, github_link, funding_amount, deadline, candidates}: {creator: string, title: string, description: string, github_link: string, funding_amount: i128, deadline: u64, candidates: Array<string>}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -473,18 +758,17 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<i128>>
+  }) => Promise<AssembledTransaction<u64>>
 
   /**
-   * Construct and simulate a reassign_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reassign an expired task to a new assignee
+   * Construct and simulate a claim_candidate_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim a task as one of its candidates
    * 
    * # Arguments
-   * * `creator` - Address of the task creator
-   * * `task_id` - ID of the task to reassign
-   * * `new_assignee` - Address of the new assignee
+   * * `assignee` - Address of the claiming candidate
+   * * `task_id` - ID of the task offered to candidates
    */
-  reassign_task: ({creator, task_id, new_assignee}: {creator: string, task_id: u64, new_assignee: string}, options?: {
+  claim_candidate_task: ({assignee, task_id}: {assignee: string, task_id: u64}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -502,16 +786,15 @@ export interface Client {
   }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get task details by ID
+   * Construct and simulate a assign_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Assign a task to a user (only if not already assigned)
    * 
    * # Arguments
-   * * `task_id` - ID of the task to retrieve
-   * 
-   * # Returns
-   * The task details
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to assign
+   * * `assignee` - Address of the user to assign the task to
    */
-  get_task: ({task_id}: {task_id: u64}, options?: {
+  assign_task: ({creator, task_id, assignee}: {creator: string, task_id: u64, assignee: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -526,19 +809,20 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Task>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_user_tasks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all tasks created by a user
+   * Construct and simulate a update_task_details transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Edit a task's details before the assignee starts work
    * 
    * # Arguments
-   * * `user` - Address of the user
-   * 
-   * # Returns
-   * Vector of task IDs created by the user
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to edit
+   * * `title` - New task title
+   * * `description` - New task description
+   * * `github_link` - New GitHub repository link (empty string for none)
    */
-  get_user_tasks: ({user}: {user: string}, options?: {
+  update_task_details: ({creator, task_id, title, description, github_link}: {creator: string, task_id: u64, title: string, description: string, github_link: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -553,19 +837,2798 @@ export interface Client {
      * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
      */
     simulate?: boolean;
-  }) => Promise<AssembledTransaction<Array<u64>>>
+  }) => Promise<AssembledTransaction<null>>
 
   /**
-   * Construct and simulate a get_assigned_tasks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get all tasks assigned to a user
-   * 
+   * Construct and simulate a complete_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mark a task as complete by the assignee
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * * `task_id` - ID of the task to complete
+   */
+  complete_task: ({assignee, task_id}: {assignee: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a complete_task_with_hash transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mark a task as complete, recording a hash of the delivered artifact
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * * `task_id` - ID of the task to complete
+   * * `deliverable_hash` - SHA-256 hash of the deliverable, for the creator to verify against
+   */
+  complete_task_with_hash: ({assignee, task_id, deliverable_hash}: {assignee: string, task_id: u64, deliverable_hash: Buffer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a update_completion_proof transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Replace the completion proof of a task awaiting fund release
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * * `task_id` - ID of the completed task
+   * * `proof` - New deliverable reference (e.g. a pull request link)
+   */
+  update_completion_proof: ({assignee, task_id, proof}: {assignee: string, task_id: u64, proof: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_payout_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send this task's payout to a wallet other than the assignee's
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * * `task_id` - ID of the task
+   * * `payout_addr` - Address that should receive the payout
+   */
+  set_payout_address: ({assignee, task_id, payout_addr}: {assignee: string, task_id: u64, payout_addr: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_refund_address transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Send this task's refunds to a wallet other than the creator's
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task
+   * * `refund_addr` - Address that should receive refunds
+   */
+  set_refund_address: ({creator, task_id, refund_addr}: {creator: string, task_id: u64, refund_addr: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a start_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update task status to InProgress
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * * `task_id` - ID of the task to start
+   */
+  start_task: ({assignee, task_id}: {assignee: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a release_funds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Release funds to the assignee after creator approval
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to release funds for
+   */
+  release_funds: ({creator, task_id}: {creator: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a release_funds_with_tip transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Release funds to the assignee and add a bonus paid by the creator
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to release funds for
+   * * `tip` - Extra amount sent from the creator to the assignee (no platform fee)
+   */
+  release_funds_with_tip: ({creator, task_id, tip}: {creator: string, task_id: u64, tip: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a claim_after_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Let the assignee claim payment when the creator has not released it in time
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * * `task_id` - ID of the completed task
+   */
+  claim_after_timeout: ({assignee, task_id}: {assignee: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_release_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set how long the creator has to release funds before the assignee may claim them
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `timeout` - Window in seconds after completion
+   */
+  set_release_timeout: ({deployer, timeout}: {deployer: string, timeout: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_max_active_assignments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the maximum number of active tasks a single assignee may hold
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `cap` - Maximum `Assigned`/`InProgress` tasks per assignee (0 for unlimited)
+   */
+  set_max_active_assignments: ({deployer, cap}: {deployer: string, cap: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_min_task_duration transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the minimum time between creation and a new task's deadline (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `duration` - Minimum task duration in seconds (0 to disable)
+   */
+  set_min_task_duration: ({deployer, duration}: {deployer: string, duration: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_min_task_duration transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the minimum time between creation and a new task's deadline
+   * 
+   * # Returns
+   * The minimum task duration in seconds, or 0 if disabled
+   */
+  get_min_task_duration: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a set_min_work_time transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the minimum time between starting and completing a task (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `min_work_time` - Minimum work time in seconds (0 to disable)
+   */
+  set_min_work_time: ({deployer, min_work_time}: {deployer: string, min_work_time: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_min_work_time transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the minimum time between starting and completing a task
+   * 
+   * # Returns
+   * The minimum work time in seconds, or 0 if disabled
+   */
+  get_min_work_time: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a set_priority_fee_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the platform fee rate for new tasks of a priority level (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `priority` - Priority level the rate applies to
+   * * `bps` - Fee rate in basis points (300 = 3%)
+   */
+  set_priority_fee_bps: ({deployer, priority, bps}: {deployer: string, priority: u32, bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_priority_fee_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the platform fee rate applied to new tasks of a priority level
+   * 
+   * # Arguments
+   * * `priority` - Priority level
+   * 
+   * # Returns
+   * The configured fee rate in basis points, or the default 3% rate
+   */
+  get_priority_fee_bps: ({priority}: {priority: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_min_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the smallest platform fee taken on a payout (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `min_fee` - Fee floor in token units, capped at the payout itself (0 to disable)
+   */
+  set_min_fee: ({deployer, min_fee}: {deployer: string, min_fee: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_min_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the smallest platform fee taken on a payout
+   * 
+   * # Returns
+   * The fee floor in token units, or 0 if disabled
+   */
+  get_min_fee: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a set_require_github_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set whether new tasks must reference a GitHub link (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `required` - Whether creating a task without a link is rejected
+   */
+  set_require_github_link: ({deployer, required}: {deployer: string, required: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_require_github_link transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get whether new tasks must reference a GitHub link
+   * 
+   * # Returns
+   * True if task creation requires a GitHub link
+   */
+  get_require_github_link: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_block_deployer_assignee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set whether the deployer is barred from being assigned tasks (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `blocked` - Whether assigning or reassigning a task to the deployer is rejected
+   */
+  set_block_deployer_assignee: ({deployer, blocked}: {deployer: string, blocked: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_block_deployer_assignee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get whether the deployer is barred from being assigned tasks
+   * 
+   * # Returns
+   * True if tasks cannot be assigned to the deployer
+   */
+  get_block_deployer_assignee: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_restrict_expiry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set whether marking tasks expired is limited to participants (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `restricted` - Whether only the creator, assignee or deployer may expire a task
+   */
+  set_restrict_expiry: ({deployer, restricted}: {deployer: string, restricted: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_restrict_expiry transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get whether marking tasks expired is restricted to participants
+   * 
+   * # Returns
+   * True if anonymous expiry is disabled
+   */
+  get_restrict_expiry: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Switch the payment token used by new tasks (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `new_token` - Address of the new token contract
+   */
+  set_token: ({deployer, new_token}: {deployer: string, new_token: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_vault transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the yield vault that holds idle escrow (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `vault` - Vault contract for new task escrows, or None to keep escrow in the contract
+   */
+  set_vault: ({deployer, vault}: {deployer: string, vault: Option<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a write_off_vault transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Give up on a vault that cannot return its deposits (only deployer can call)
+   * 
+   * The deployer must first make good the lost deposits by transferring tokens to this
+   * contract; the vault is then forgotten and every task's escrow is held here again.
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   */
+  write_off_vault: ({deployer}: {deployer: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_vault transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the yield vault that holds idle escrow
+   * 
+   * # Returns
+   * The vault address, or None if escrow stays in the contract
+   */
+  get_vault: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_auto_sweep_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Sweep each release's platform fee straight to a treasury (only deployer can call)
+   * 
+   * While set, release fees bypass the accumulator; cancellation and dispute fees still
+   * accrue for manual withdrawal. Since the treasury receives fees without a withdrawal,
+   * changing it needs signers to have approved a request naming the new treasury.
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `treasury` - Address receiving release fees, or None to accumulate them
+   */
+  set_auto_sweep_fees: ({deployer, treasury}: {deployer: string, treasury: Option<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_auto_sweep_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the treasury release fees are swept to
+   * 
+   * # Returns
+   * The treasury address, or None if fees accumulate for manual withdrawal
+   */
+  get_auto_sweep_fees: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_fee_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Charge release fees in a separate token pulled from the creator (only deployer can call)
+   * 
+   * While set, releases pay the assignee the whole escrow and charge the fee to the creator in
+   * the fee token. A release the creator signs collects the fee at once; timeout claims and
+   * auto-releases record it as a debt the creator must settle before creating more tasks.
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `fee_token` - Token contract fees are charged in, or None to deduct fees from payouts
+   */
+  set_fee_token: ({deployer, fee_token}: {deployer: string, fee_token: Option<string>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_fee_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the token release fees are charged in
+   * 
+   * # Returns
+   * The fee token, or None if fees are deducted from payouts
+   */
+  get_fee_token: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_fee_token_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the platform fees accrued in the fee token
+   * 
+   * # Returns
+   * The unwithdrawn fee-token balance owed to the platform
+   */
+  get_fee_token_fees: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_fee_token_debt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the fee-token fees a creator owes for releases they did not sign
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * 
+   * # Returns
+   * The unsettled fee-token amount, or 0 if nothing is owed
+   */
+  get_fee_token_debt: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a settle_fee_token_debt transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay the fee-token fees owed for timeout claims and auto-releases
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator settling their debt
+   */
+  settle_fee_token_debt: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a withdraw_fee_token_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw the platform fees accrued in the fee token (only deployer can call)
+   * 
+   * With multi-sig configured, signers must have approved a request to pay the whole
+   * fee-token balance to the deployer.
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   */
+  withdraw_fee_token_fees: ({deployer}: {deployer: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_cancel_fee_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the fee charged on creator cancellations (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `bps` - Cancellation fee in basis points of the escrow (0 for free cancellation)
+   */
+  set_cancel_fee_bps: ({deployer, bps}: {deployer: string, bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_cancel_fee_bps transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the fee charged on creator cancellations
+   * 
+   * # Returns
+   * The cancellation fee in basis points, or 0 if cancellation is free
+   */
+  get_cancel_fee_bps: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_max_active_assignments transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the maximum number of active tasks a single assignee may hold
+   * 
+   * # Returns
+   * The per-assignee cap, or 0 if unlimited
+   */
+  get_max_active_assignments: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_release_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the window after completion before the assignee may claim payment
+   * 
+   * # Returns
+   * The release timeout in seconds
+   */
+  get_release_timeout: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_settings transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get every configurable contract parameter in one call
+   * 
+   * # Returns
+   * The current contract settings
+   */
+  get_settings: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Settings>>
+
+  /**
+   * Construct and simulate a simulate_release transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Preview the outcome of releasing funds for a task without mutating state
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task to simulate the release for
+   * 
+   * # Returns
+   * The assignee, their payout, the platform fee and the resulting fee accumulator
+   */
+  simulate_release: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<ReleaseOutcome>>
+
+  /**
+   * Construct and simulate a get_task_funding_breakdown transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Break a task's funding down into fee, payout, and escrow components
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * The task's gross funding, platform fee, net payout, and escrow still held
+   */
+  get_task_funding_breakdown: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<FundingBreakdown>>
+
+  /**
+   * Construct and simulate a get_task_escrow transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the amount held in escrow for a task
+   * 
+   * Summed over all tasks this matches the contract's token balance less accrued platform
+   * fees, with any escrow deposited in the yield vault held there instead.
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * The task's funding minus everything already paid out or refunded
+   */
+  get_task_escrow: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a validate_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a task with the given inputs could be created, without creating it
+   * 
+   * # Arguments
+   * * `title` - Proposed task title
+   * * `description` - Proposed task description
+   * * `funding_amount` - Proposed funding amount
+   * * `deadline` - Proposed deadline timestamp
+   * 
+   * # Returns
+   * Ok if creation would pass validation, otherwise the reason it would be rejected
+   */
+  validate_task: ({title, description, funding_amount, deadline}: {title: string, description: string, funding_amount: i128, deadline: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a required_funding transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the token amount a creator must have available to fund a new task
+   * 
+   * Fees are quoted at the default platform rate, raised to the minimum fee.
+   * 
+   * # Arguments
+   * * `funding_amount` - Amount the task is to be funded with
+   * * `fee_payer` - Whether the assignee or the creator bears the platform fee
+   * 
+   * # Returns
+   * The funding amount, plus the platform fee if the creator absorbs it
+   */
+  required_funding: ({funding_amount, fee_payer}: {funding_amount: i128, fee_payer: FeePayer}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a preview_release_with_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Preview a task's payout split under a hypothetical platform fee rate
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task to preview
+   * * `hypothetical_fee_bps` - Fee rate in basis points (300 = 3%)
+   * 
+   * # Returns
+   * Tuple of (assignee payout, platform fee) for the funds still in escrow
+   */
+  preview_release_with_fee: ({task_id, hypothetical_fee_bps}: {task_id: u64, hypothetical_fee_bps: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
+
+  /**
+   * Construct and simulate a cancel_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a task and refund the creator
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to cancel
+   */
+  cancel_task: ({creator, task_id}: {creator: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a cancel_all transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a creator's assigned and in-progress tasks, refunding each
+   * 
+   * Cancels at most 50 tasks per call; call again to continue with the rest.
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * 
+   * # Returns
+   * Number of tasks cancelled
+   */
+  cancel_all: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a relist_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a task and re-post it with new parameters, netting the refund against the new funding
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to cancel
+   * * `params` - Title, description, link, funding, deadline and assignee of the new task
+   * 
+   * # Returns
+   * The ID of the new task
+   */
+  relist_task: ({creator, task_id, params}: {creator: string, task_id: u64, params: RelistParams}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a request_cancel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Give the assignee notice that the creator intends to cancel a task
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to cancel
+   */
+  request_cancel: ({creator, task_id}: {creator: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a finalize_cancel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a task and refund the creator once the notice period has passed
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to cancel
+   */
+  finalize_cancel: ({creator, task_id}: {creator: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a assignee_cancel transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Give up an assigned task and refund the creator in full
+   * 
+   * # Arguments
+   * * `assignee` - Address of the task assignee
+   * * `task_id` - ID of the task to abandon
+   */
+  assignee_cancel: ({assignee, task_id}: {assignee: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a mark_expired transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Handle expired tasks - mark as expired
+   * 
+   * # Arguments
+   * * `task_id` - ID of the expired task
+   */
+  mark_expired: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a mark_expired_by transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mark a task as expired on behalf of an identified caller
+   * 
+   * # Arguments
+   * * `caller` - Address marking the task; must be a participant when expiry is restricted
+   * * `task_id` - ID of the expired task
+   */
+  mark_expired_by: ({caller, task_id}: {caller: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a mark_unaccepted transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lapse an assignment the assignee did not start within the acceptance window
+   * 
+   * The task is expired and its expiry action carried out, so a refunding task can be
+   * reclaimed or reassigned by the creator before the task deadline.
+   * 
+   * # Arguments
+   * * `task_id` - ID of the unaccepted task
+   */
+  mark_unaccepted: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a mark_unaccepted_by transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lapse an unaccepted assignment on behalf of an identified caller
+   * 
+   * # Arguments
+   * * `caller` - Address lapsing the task; must be a participant when expiry is restricted
+   * * `task_id` - ID of the unaccepted task
+   */
+  mark_unaccepted_by: ({caller, task_id}: {caller: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a mark_expired_batch transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Mark several overdue tasks as expired in one call, skipping any that are not eligible
+   * 
+   * # Arguments
+   * * `task_ids` - IDs of the tasks to expire (at most `MAX_KEEPER_BATCH`)
+   * 
+   * # Returns
+   * IDs of the tasks that were actually marked expired
+   */
+  mark_expired_batch: ({task_ids}: {task_ids: Array<u64>}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a reclaim_expired_funds transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reclaim funds from expired task
+   * 
+   * Anyone may reclaim a task once it is marked expired, or an overdue task that was never
+   * started while expiry is unrestricted; otherwise the creator must authorize the reclaim.
+   * Since the caller may be anyone, the refund always goes to the creator.
+   * 
+   * # Arguments
+   * * `task_id` - ID of the expired task
+   */
+  reclaim_expired_funds: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a withdraw_platform_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw accumulated platform fees (only deployer can call)
+   * 
+   * With multi-sig configured, signers must have approved a request to pay the whole
+   * accumulated balance to the deployer.
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   */
+  withdraw_platform_fees: ({deployer}: {deployer: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a withdraw_platform_fees_to transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Withdraw part of the accumulated platform fees to a chosen recipient
+   * 
+   * With multi-sig configured, signers must have approved a request for this recipient
+   * and amount, so approvals given for one withdrawal cannot be spent on another.
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `recipient` - Address receiving the withdrawn fees
+   * * `amount` - Amount of fees to withdraw
+   */
+  withdraw_platform_fees_to: ({deployer, recipient, amount}: {deployer: string, recipient: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a emergency_withdraw transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Recover tokens held outside of escrow and fee accounting (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `amount` - Amount to recover, at most the balance above all obligations
+   */
+  emergency_withdraw: ({deployer, amount}: {deployer: string, amount: i128}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a propose_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a fee outflow for the signers to approve (only deployer can call)
+   * 
+   * The outflow can only be carried out once enough signers approve this exact request.
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `action` - Withdrawal or treasury change to approve
+   * 
+   * # Returns
+   * ID of the new withdrawal request
+   */
+  propose_withdrawal: ({deployer, action}: {deployer: string, action: WithdrawalAction}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a approve_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Approve a pending fee withdrawal request as one of the configured signers
+   * 
+   * # Arguments
+   * * `signer` - Address of the approving signer
+   * * `request_id` - ID of the withdrawal request
+   */
+  approve_withdrawal: ({signer, request_id}: {signer: string, request_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a cancel_withdrawal transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Drop a pending fee withdrawal request (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `request_id` - ID of the withdrawal request
+   */
+  cancel_withdrawal: ({deployer, request_id}: {deployer: string, request_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_withdrawal_request transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a pending fee withdrawal request
+   * 
+   * # Arguments
+   * * `request_id` - ID of the withdrawal request
+   * 
+   * # Returns
+   * The request and its approvals, or None if it was carried out, cancelled or never made
+   */
+  get_withdrawal_request: ({request_id}: {request_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<WithdrawalRequest>>>
+
+  /**
+   * Construct and simulate a raise_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Flag a task as disputed, freezing its funds until an arbiter resolves it
+   * 
+   * # Arguments
+   * * `caller` - Address of the task creator or assignee
+   * * `task_id` - ID of the task to dispute
+   */
+  raise_dispute: ({caller, task_id}: {caller: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a admin_resolve transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Force-resolve a stuck task (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `task_id` - ID of the task to resolve
+   * * `outcome` - Pay the assignee, refund the creator, or split between them
+   */
+  admin_resolve: ({deployer, task_id, outcome}: {deployer: string, task_id: u64, outcome: AdminOutcome}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a resolve_dispute transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a disputed task in favor of the assignee or the creator (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `task_id` - ID of the disputed task
+   * * `pay_assignee` - True to release the escrow to the assignee, false to refund the creator
+   */
+  resolve_dispute: ({deployer, task_id, pay_assignee}: {deployer: string, task_id: u64, pay_assignee: boolean}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a set_dispute_fee_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set whether resolving a dispute in the assignee's favor takes a platform fee
+   * (only deployer can call)
+   * 
+   * # Arguments
+   * * `deployer` - Address of the contract deployer
+   * * `policy` - Fee policy applied by `resolve_dispute`
+   */
+  set_dispute_fee_policy: ({deployer, policy}: {deployer: string, policy: DisputeFeePolicy}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_dispute_fee_policy transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the fee policy applied when resolving disputes
+   * 
+   * # Returns
+   * The configured policy, `NoFeeOnRefund` by default
+   */
+  get_dispute_fee_policy: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<DisputeFeePolicy>>
+
+  /**
+   * Construct and simulate a get_platform_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get current accumulated platform fees
+   * 
+   * # Returns
+   * The total amount of accumulated platform fees
+   */
+  get_platform_fees: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a has_withdrawable_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether the deployer could withdraw platform fees right now
+   * 
+   * # Returns
+   * True if fees have accrued and the contract holds enough to pay them out while solvent
+   */
+  has_withdrawable_fees: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a check_solvency transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Compare the contract's token balance against what it owes
+   * 
+   * Escrow owed is read from the running ledger totals rather than summed over every task.
+   * 
+   * # Returns
+   * Tuple of (token balance held, escrowed funds plus unwithdrawn fees,
+   * whether the balance covers the obligations)
+   */
+  check_solvency: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i128, i128, boolean]>>
+
+  /**
+   * Construct and simulate a get_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get contract-wide totals for analytics
+   * 
+   * # Returns
+   * Task counts, escrowed value and fee totals accumulated since deployment
+   */
+  get_stats: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Stats>>
+
+  /**
+   * Construct and simulate a get_ledger_summary transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the running totals needed to reconcile the contract's books
+   * 
+   * Total escrowed always equals paid plus refunded plus fees earned plus the escrow still
+   * locked in open tasks. Fees charged in a separate fee token never come out of escrow, so
+   * they are reported on their own and left out of that sum.
+   * 
+   * # Returns
+   * Cumulative escrow, payout, refund and fee totals since deployment
+   */
+  get_ledger_summary: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<LedgerSummary>>
+
+  /**
+   * Construct and simulate a get_take_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the totals needed to compute the platform's realized take rate
+   * 
+   * # Returns
+   * Cumulative released volume (fees included) and the platform fees taken on it
+   */
+  get_take_rate: (options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i128, i128]>>
+
+  /**
+   * Construct and simulate a reassign_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reassign an expired task to a new assignee
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the task to reassign
+   * * `new_assignee` - Address of the new assignee
+   */
+  reassign_task: ({creator, task_id, new_assignee}: {creator: string, task_id: u64, new_assignee: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a reassign_all_expired transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reassign all of a creator's expired tasks held by one assignee to another
+   * 
+   * Reassigns at most `MAX_REASSIGN_BATCH` tasks per call; call again to continue with
+   * the rest.
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `old_assignee` - Assignee whose expired tasks are handed over
+   * * `new_assignee` - Address of the new assignee
+   * * `new_deadline` - Unix timestamp for the new deadline of every reassigned task
+   * 
+   * # Returns
+   * IDs of the tasks that were reassigned
+   */
+  reassign_all_expired: ({creator, old_assignee, new_assignee, new_deadline}: {creator: string, old_assignee: string, new_assignee: string, new_deadline: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a reassign_and_extend transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reassign an expired task and give it a new deadline in one step
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the expired task
+   * * `new_assignee` - Address of the new assignee
+   * * `new_deadline` - Unix timestamp for the new task deadline
+   */
+  reassign_and_extend: ({creator, task_id, new_assignee, new_deadline}: {creator: string, task_id: u64, new_assignee: string, new_deadline: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a reopen_to_pool transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Return an expired task to the open pool with a new deadline so any worker can claim it
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `task_id` - ID of the expired task
+   * * `new_deadline` - Unix timestamp for the new task deadline
+   */
+  reopen_to_pool: ({creator, task_id, new_deadline}: {creator: string, task_id: u64, new_deadline: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a claim_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Claim a task from the open pool as its assignee
+   * 
+   * # Arguments
+   * * `worker` - Address of the worker taking the task
+   * * `task_id` - ID of the pooled task
+   */
+  claim_task: ({worker, task_id}: {worker: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a transfer_creatorship transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Hand the creator role and its refund rights over to another address
+   * 
+   * # Arguments
+   * * `current_creator` - Address of the current task creator
+   * * `task_id` - ID of the task to transfer
+   * * `new_creator` - Address taking over as creator
+   */
+  transfer_creatorship: ({current_creator, task_id, new_creator}: {current_creator: string, task_id: u64, new_creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_task transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get task details by ID
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task to retrieve
+   * 
+   * # Returns
+   * The task details
+   */
+  get_task: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Task>>
+
+  /**
+   * Construct and simulate a get_task_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the creator of a task
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * The creator's address
+   */
+  get_task_creator: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_task_assignee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the assignee of a task
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * The assignee's address, or `None` if the task is unassigned
+   */
+  get_task_assignee: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a get_task_amount_decimals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the decimals of the token a task is funded in
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * Number of decimals used to display the task's funding amount
+   */
+  get_task_amount_decimals: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_remaining_time transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the time left until a task's deadline
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * Seconds until the deadline, or 0 if it has passed
+   */
+  get_remaining_time: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u64>>
+
+  /**
+   * Construct and simulate a get_task_sla_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Report which of a task's service-level windows have been breached
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * Flags for a lapsed acceptance window, a missed deadline, and a release left waiting
+   * past the release timeout
+   */
+  get_task_sla_status: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<SlaStatus>>
+
+  /**
+   * Construct and simulate a can_extend_deadline transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether the creator could still extend a task's deadline
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * True if the task is assigned or in progress and its deadline has not passed
+   */
+  can_extend_deadline: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_blocking_tasks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the dependencies of a task that have not finished yet
+   * 
+   * # Arguments
+   * * `task_id` - ID of the task
+   * 
+   * # Returns
+   * IDs of dependencies not in `Completed` or `FundsReleased` state
+   */
+  get_blocking_tasks: ({task_id}: {task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_user_tasks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all tasks created by a user
+   * 
+   * Very prolific creators can exceed return size limits; use `get_user_tasks_paged` instead.
+   * 
+   * # Arguments
+   * * `user` - Address of the user
+   * 
+   * # Returns
+   * Vector of task IDs created by the user
+   */
+  get_user_tasks: ({user}: {user: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_user_tasks_paged transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the tasks created by a user
+   * 
+   * # Arguments
+   * * `user` - Address of the user
+   * * `start` - Number of task IDs to skip
+   * * `limit` - Maximum number of task IDs to return (capped at 50)
+   * 
+   * # Returns
+   * Slice of the task IDs created by the user, in creation order
+   */
+  get_user_tasks_paged: ({user, start, limit}: {user: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_pending_approvals transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a creator's completed tasks that are waiting for fund release
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * 
+   * # Returns
+   * Vector of IDs of the creator's tasks in Completed status
+   */
+  get_pending_approvals: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_creator_tasks_by_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a creator's tasks in a given status
+   * 
+   * # Arguments
+   * * `creator` - Address of the task creator
+   * * `status` - Status to filter by
+   * * `start` - Number of matching tasks to skip
+   * * `limit` - Maximum number of tasks to return (capped at 50)
+   * 
+   * # Returns
+   * The creator's tasks in `status`, in creation order
+   */
+  get_creator_tasks_by_status: ({creator, status, start, limit}: {creator: string, status: TaskStatus, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Task>>>
+
+  /**
+   * Construct and simulate a get_creator_fees_paid transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the platform fees taken from a creator's released tasks
+   * 
+   * # Arguments
+   * * `creator` - Address of the creator
+   * 
+   * # Returns
+   * Cumulative platform fees paid on the creator's payouts
+   */
+  get_creator_fees_paid: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_user_financials transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a user's lifetime payment totals
+   * 
+   * # Arguments
+   * * `user` - Address of the user
+   * 
+   * # Returns
+   * Tuple of (earned as assignee, spent as creator, currently locked in escrow)
+   */
+  get_user_financials: ({user}: {user: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [i128, i128, i128]>>
+
+  /**
+   * Construct and simulate a rate_creator transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Rate the creator of a finished task
+   * 
+   * # Arguments
+   * * `assignee` - Address of the task assignee
+   * * `task_id` - ID of the released or cancelled task
+   * * `score` - Rating from 1 to 5
+   */
+  rate_creator: ({assignee, task_id, score}: {assignee: string, task_id: u64, score: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_creator_rating transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the aggregate rating assignees have given a creator
+   * 
+   * # Arguments
+   * * `creator` - Address of the creator
+   * 
+   * # Returns
+   * Tuple of (sum of all scores, number of ratings)
+   */
+  get_creator_rating: ({creator}: {creator: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [u64, u64]>>
+
+  /**
+   * Construct and simulate a get_reputation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a user's track record as an assignee
+   * 
+   * # Arguments
+   * * `user` - Address of the user
+   * 
+   * # Returns
+   * Counts of the user's paid-out and abandoned tasks
+   */
+  get_reputation: ({user}: {user: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<AssigneeReputation>>
+
+  /**
+   * Construct and simulate a get_success_rate transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the share of an assignee's finished tasks that were paid out
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * 
+   * # Returns
+   * Percentage (0-100) of completed tasks, or `u32::MAX` if the assignee has no history
+   */
+  get_success_rate: ({assignee}: {assignee: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_assigned_tasks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get all tasks assigned to a user
+   * 
+   * Very busy assignees can exceed return size limits; use `get_assigned_tasks_paged` instead.
+   * 
+   * # Arguments
+   * * `user` - Address of the user
+   * 
+   * # Returns
+   * Vector of task IDs assigned to the user
+   */
+  get_assigned_tasks: ({user}: {user: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_assigned_tasks_paged transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a page of the tasks assigned to a user
+   * 
    * # Arguments
    * * `user` - Address of the user
+   * * `start` - Number of task IDs to skip
+   * * `limit` - Maximum number of task IDs to return (capped at 50)
    * 
    * # Returns
-   * Vector of task IDs assigned to the user
+   * Slice of the task IDs assigned to the user, in assignment order
    */
-  get_assigned_tasks: ({user}: {user: string}, options?: {
+  get_assigned_tasks_paged: ({user, start, limit}: {user: string, start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_assignee_actionable transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get an assignee's tasks where the next action is theirs
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * 
+   * # Returns
+   * IDs of the assignee's tasks still to be started (Assigned) or completed (InProgress),
+   * leaving out any already past their deadline
+   */
+  get_assignee_actionable: ({assignee}: {assignee: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_assignee_workload transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Summarize the work an assignee currently has on their plate
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * 
+   * # Returns
+   * Tuple of (number of assigned or in-progress tasks, funding still escrowed for them)
+   */
+  get_assignee_workload: ({assignee}: {assignee: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<readonly [u64, i128]>>
+
+  /**
+   * Construct and simulate a get_assigned_tasks_by_priority transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get active tasks assigned to a user ordered for triage
+   * 
+   * # Arguments
+   * * `user` - Address of the assignee
+   * 
+   * # Returns
+   * IDs of the user's assigned or in-progress tasks, sorted by descending
+   * priority and then by ascending deadline
+   */
+  get_assigned_tasks_by_priority: ({user}: {user: string}, options?: {
     /**
      * The fee to pay for the transaction. Default: BASE_FEE
      */
@@ -582,6 +3645,174 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<Array<u64>>>
 
+  /**
+   * Construct and simulate a get_assignee_queue transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get an assignee's active tasks as a work queue, most urgent first
+   * 
+   * # Arguments
+   * * `assignee` - Address of the assignee
+   * 
+   * # Returns
+   * The assignee's assigned or in-progress tasks, sorted by ascending deadline
+   */
+  get_assignee_queue: ({assignee}: {assignee: string}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Task>>>
+
+  /**
+   * Construct and simulate a get_tasks_needing_reminder transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get active tasks whose deadline is approaching and whose assignee has not been reminded
+   * 
+   * # Arguments
+   * * `within_secs` - How far ahead of now a deadline counts as approaching
+   * * `start` - Task ID to start scanning from (use the previous page's cursor)
+   * * `limit` - Maximum number of tasks to return (capped at 50)
+   * 
+   * # Returns
+   * Assigned or in-progress tasks due within the window, in ID order, and a cursor for the
+   * next page
+   */
+  get_tasks_needing_reminder: ({within_secs, start, limit}: {within_secs: u64, start: u64, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<TaskPage>>
+
+  /**
+   * Construct and simulate a mark_reminded transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Record that a task's assignee has been reminded of the deadline
+   * 
+   * # Arguments
+   * * `caller` - Address of the task creator or assignee
+   * * `task_id` - ID of the task
+   */
+  mark_reminded: ({caller, task_id}: {caller: string, task_id: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_github_tasks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get tasks that reference a GitHub link
+   * 
+   * # Arguments
+   * * `start` - Number of linked tasks to skip
+   * * `limit` - Maximum number of tasks to return (capped at 50)
+   * 
+   * # Returns
+   * Linked tasks in ID order
+   */
+  get_github_tasks: ({start, limit}: {start: u32, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Task>>>
+
+  /**
+   * Construct and simulate a get_disputed_tasks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get disputed tasks awaiting arbitration
+   * 
+   * # Arguments
+   * * `start` - Task ID to start scanning from (use the previous page's cursor)
+   * * `limit` - Maximum number of tasks to return (capped at 50)
+   * 
+   * # Returns
+   * Disputed tasks in ID order and a cursor for the next page
+   */
+  get_disputed_tasks: ({start, limit}: {start: u64, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<TaskPage>>
+
+  /**
+   * Construct and simulate a get_tasks_completed_between transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get tasks completed within a time range
+   * 
+   * # Arguments
+   * * `from` - Start of the range (inclusive, Unix timestamp)
+   * * `to` - End of the range (inclusive, Unix timestamp)
+   * * `start` - Task ID to start scanning from (use the previous page's cursor)
+   * * `limit` - Maximum number of tasks to return (capped at 50)
+   * 
+   * # Returns
+   * Tasks whose `completed_at` falls in `[from, to]` and a cursor for the next page
+   */
+  get_tasks_completed_between: ({from, to, start, limit}: {from: u64, to: u64, start: u64, limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<TaskPage>>
+
   /**
    * Construct and simulate a get_task_count transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Get total number of tasks
@@ -603,6 +3834,61 @@ export interface Client {
     simulate?: boolean;
   }) => Promise<AssembledTransaction<u64>>
 
+  /**
+   * Construct and simulate a get_task_ids_in_range transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the IDs of existing tasks in a range
+   * 
+   * # Arguments
+   * * `start` - First task ID of the range (inclusive)
+   * * `end` - Last task ID of the range (exclusive, span capped at 100 IDs)
+   * 
+   * # Returns
+   * Vector of task IDs in the range that exist, in ascending order
+   */
+  get_task_ids_in_range: ({start, end}: {start: u64, end: u64}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<u64>>>
+
+  /**
+   * Construct and simulate a get_recent_tasks transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the most recently created tasks
+   * 
+   * # Arguments
+   * * `limit` - Maximum number of tasks to return (capped at 10)
+   * 
+   * # Returns
+   * Vector of the newest tasks, ordered by descending ID
+   */
+  get_recent_tasks: ({limit}: {limit: u32}, options?: {
+    /**
+     * The fee to pay for the transaction. Default: BASE_FEE
+     */
+    fee?: number;
+
+    /**
+     * The maximum amount of time to wait for the transaction to complete. Default: DEFAULT_TIMEOUT
+     */
+    timeoutInSeconds?: number;
+
+    /**
+     * Whether to automatically simulate the transaction when constructing the AssembledTransaction. Default: true
+     */
+    simulate?: boolean;
+  }) => Promise<AssembledTransaction<Array<Task>>>
+
 }
 export class Client extends ContractClient {
   static async deploy<T = Client>(
@@ -624,52 +3910,311 @@ export class Client extends ContractClient {
       new ContractSpec([ "AAAAAgAAAAAAAAAAAAAAClRhc2tTdGF0dXMAAAAAAAgAAAAAAAAAAAAAAAdDcmVhdGVkAAAAAAAAAAAAAAAACEFzc2lnbmVkAAAAAAAAAAAAAAAKSW5Qcm9ncmVzcwAAAAAAAAAAAAAAAAAJQ29tcGxldGVkAAAAAAAAAAAAAAAAAAAIQXBwcm92ZWQAAAAAAAAAAAAAAA1GdW5kc1JlbGVhc2VkAAAAAAAAAAAAAAAAAAAHRXhwaXJlZAAAAAAAAAAAAAAAAAlDYW5jZWxsZWQAAAA=",
         "AAAAAQAAAAAAAAAAAAAAC1VzZXJQcm9maWxlAAAAAAMAAAAAAAAAB2FkZHJlc3MAAAAAEwAAAAAAAAAKY3JlYXRlZF9hdAAAAAAABgAAAAAAAAAIdXNlcm5hbWUAAAAQ",
         "AAAAAQAAAAAAAAAAAAAAD1Rhc2tBcHBsaWNhdGlvbgAAAAAEAAAAAAAAAAlhcHBsaWNhbnQAAAAAAAATAAAAAAAAAAphcHBsaWVkX2F0AAAAAAAGAAAAAAAAAAdtZXNzYWdlAAAAABAAAAAAAAAACHVzZXJuYW1lAAAAEA==",
-        "AAAAAQAAAAAAAAAAAAAABFRhc2sAAAAOAAAAAAAAAAxhcHBsaWNhdGlvbnMAAAPqAAAH0AAAAA9UYXNrQXBwbGljYXRpb24AAAAAAAAAAAhhc3NpZ25lZQAAA+gAAAATAAAAAAAAABFhc3NpZ25lZV9hcHByb3ZlZAAAAAAAAAEAAAAAAAAADGNvbXBsZXRlZF9hdAAAA+gAAAAGAAAAAAAAAApjcmVhdGVkX2F0AAAAAAAGAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAEGNyZWF0b3JfYXBwcm92ZWQAAAABAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAADmZ1bmRpbmdfYW1vdW50AAAAAAALAAAAAAAAAAtnaXRodWJfbGluawAAAAAQAAAAAAAAAAJpZAAAAAAABgAAAAAAAAAGc3RhdHVzAAAAAAfQAAAAClRhc2tTdGF0dXMAAAAAAAAAAAAFdGl0bGUAAAAAAAAQ",
-        "AAAAAAAAAL1Jbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIHRva2VuIGFkZHJlc3MKCiMgQXJndW1lbnRzCiogYHRva2VuYCAtIEFkZHJlc3Mgb2YgdGhlIHRva2VuIGNvbnRyYWN0IGZvciBwYXltZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llciB3aG8gd2lsbCByZWNlaXZlIHBsYXRmb3JtIGZlZXMAAAAAAAAKaW5pdGlhbGl6ZQAAAAAAAgAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAAAAAAhkZXBsb3llcgAAABMAAAAA",
+        "AAAAAQAAAAAAAAAAAAAAC1Rhc2tDb21tZW50AAAAAAMAAAAAAAAABmF1dGhvcgAAAAAAEwAAAAAAAAAJcG9zdGVkX2F0AAAAAAAABgAAAAAAAAAEdGV4dAAAABA=",
+        "AAAAAQAAAAAAAAAAAAAABFRhc2sAAAAsAAAAAAAAAAlhYmFuZG9uZWQAAAAAAAABAAAAAAAAAAlhY2NlcHRfYnkAAAAAAAPoAAAABgAAAAAAAAANYWNjZXB0X3dpbmRvdwAAAAAAAAYAAAAAAAAADGFwcGxpY2F0aW9ucwAAA+oAAAfQAAAAD1Rhc2tBcHBsaWNhdGlvbgAAAAAAAAAACGFzc2lnbmVlAAAD6AAAABMAAAAAAAAAEWFzc2lnbmVlX2FwcHJvdmVkAAAAAAAAAQAAAAAAAAAMYXV0b19yZWxlYXNlAAAAAQAAAAAAAAATY2FuY2VsX3JlcXVlc3RlZF9hdAAAAAPoAAAABgAAAAAAAAAIY29tbWVudHMAAAPqAAAH0AAAAAtUYXNrQ29tbWVudAAAAAAAAAAADGNvbXBsZXRlZF9hdAAAA+gAAAAGAAAAAAAAABBjb21wbGV0aW9uX3Byb29mAAAD6AAAABAAAAAAAAAACmNyZWF0ZWRfYXQAAAAAAAYAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAQY3JlYXRvcl9hcHByb3ZlZAAAAAEAAAAAAAAADWNyZWF0b3JfcmF0ZWQAAAAAAAABAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAAEGRlbGl2ZXJhYmxlX2hhc2gAAAPoAAAD7gAAACAAAAAAAAAACmRlcGVuZHNfb24AAAAAA+oAAAAGAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAhkaXNwdXRlZAAAAAEAAAAAAAAADWV4cGlyeV9hY3Rpb24AAAAAAAfQAAAADEV4cGlyeUFjdGlvbgAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAA5mdW5kaW5nX2Ftb3VudAAAAAAACwAAAAAAAAALZ2l0aHViX2xpbmsAAAAD6AAAABAAAAAAAAAAAmlkAAAAAAAGAAAAAAAAABFub3RpZnlfb25fcmVsZWFzZQAAAAAAAAEAAAAAAAAACW9wZW5fcG9vbAAAAAAAAAEAAAAAAAAABm9yYWNsZQAAAAAD6AAAABMAAAAAAAAACW9yYWNsZV9mbgAAAAAAA+gAAAARAAAAAAAAAA5wYXlvdXRfYWRkcmVzcwAAAAAD6AAAABMAAAAAAAAAB3BsZWRnZWQAAAAAAQAAAAAAAAAScHJldmlvdXNfYXNzaWduZWVzAAAAAAPqAAAAEwAAAAAAAAAIcHJpb3JpdHkAAAAEAAAAAAAAAApwcm9jZXNzaW5nAAAAAAABAAAAAAAAAA5yZWZ1bmRfYWRkcmVzcwAAAAAD6AAAABMAAAAAAAAAD3JlbGVhc2VkX3NvX2ZhcgAAAAALAAAAAAAAAA1yZWxpc3RlZF9mcm9tAAAAAAAD6AAAAAYAAAAAAAAACHJlbWluZGVkAAAAAQAAAAAAAAAKc3RhcnRlZF9hdAAAAAAD6AAAAAYAAAAAAAAABnN0YXR1cwAAAAAH0AAAAApUYXNrU3RhdHVzAAAAAAAAAAAABXRpdGxlAAAAAAAAEAAAAAAAAAAOdG9rZW5fZGVjaW1hbHMAAAAAAAQAAAAAAAAADXZhdWx0X2RlcG9zaXQAAAAAAAALAAAAAAAAAAt3YXNfZXhwaXJlZAAAAAAB",
+        "AAAAAgAAAAAAAAAAAAAADEV4cGlyeUFjdGlvbgAAAAMAAAAAAAAAAAAAAAZSZWZ1bmQAAAAAAAEAAAAAAAAAFEF1dG9SZWFzc2lnblRvQmFja3VwAAAAAQAAABMAAAAAAAAAAAAAAAxSZW9wZW5Ub1Bvb2w=",
+        "AAAAAgAAAAAAAAAAAAAADEFkbWluT3V0Y29tZQAAAAMAAAAAAAAAAAAAAAtQYXlBc3NpZ25lZQAAAAAAAAAAAAAAAA1SZWZ1bmRDcmVhdG9yAAAAAAAAAQAAAAAAAAAFU3BsaXQAAAAAAAABAAAABA==",
+        "AAAAAgAAAAAAAAAAAAAAEERpc3B1dGVGZWVQb2xpY3kAAAACAAAAAAAAAAAAAAANTm9GZWVPblJlZnVuZAAAAAAAAAAAAAAAAAAAE0ZlZU9uRGlzcHV0ZWRQYXlvdXQA",
+        "AAAAAgAAAAAAAAAAAAAACEZlZVBheWVyAAAAAgAAAAAAAAAAAAAACEFzc2lnbmVlAAAAAAAAAAAAAAAHQ3JlYXRvcgA=",
+        "AAAABQAAAAAAAAAAAAAADUFkbWluUmVzb2x2ZWQAAAAAAAABAAAADmFkbWluX3Jlc29sdmVkAAAAAAAGAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAAAAAAAAdvdXRjb21lAAAAB9AAAAAMQWRtaW5PdXRjb21lAAAAAAAAAAAAAAAPYXNzaWduZWVfYW1vdW50AAAAAAsAAAAAAAAAAAAAAA1yZWZ1bmRfYW1vdW50AAAAAAAACwAAAAAAAAAAAAAADHBsYXRmb3JtX2ZlZQAAAAsAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAADVRhc2tDYW5jZWxsZWQAAAAAAAABAAAADnRhc2tfY2FuY2VsbGVkAAAAAAADAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAAAAAAA1yZWZ1bmRfYW1vdW50AAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADVRhc2tBYmFuZG9uZWQAAAAAAAABAAAADnRhc2tfYWJhbmRvbmVkAAAAAAADAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAAAAAAAAhhc3NpZ25lZQAAABMAAAAAAAAAAAAAAA1yZWZ1bmRfYW1vdW50AAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAAE1Rhc2tFeHBpcmVkUmVmdW5kZWQAAAAAAQAAABV0YXNrX2V4cGlyZWRfcmVmdW5kZWQAAAAAAAADAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAAAAAAA1yZWZ1bmRfYW1vdW50AAAAAAAACwAAAAAAAAAC",
+        "AAAABQAAAAAAAAAAAAAADURpc3B1dGVSYWlzZWQAAAAAAAABAAAADmRpc3B1dGVfcmFpc2VkAAAAAAACAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAAAAAAAAlyYWlzZWRfYnkAAAAAAAATAAAAAAAAAAI=",
+        "AAAABQAAAAAAAAAAAAAADFRhc2tSZWxpc3RlZAAAAAEAAAANdGFza19yZWxpc3RlZAAAAAAAAAMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAEAAAAAAAAAC25ld190YXNrX2lkAAAAAAYAAAAAAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAAg==",
+        "AAAABQAAAAAAAAAAAAAAFkNvbXBsZXRpb25Qcm9vZlVwZGF0ZWQAAAAAAAEAAAAYY29tcGxldGlvbl9wcm9vZl91cGRhdGVkAAAAAwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAQAAAAAAAAAIYXNzaWduZWUAAAATAAAAAAAAAAAAAAAFcHJvb2YAAAAAAAAQAAAAAAAAAAI=",
+        "AAAAAQAAAAAAAAAAAAAACFRhc2tQYWdlAAAAAgAAAAAAAAALbmV4dF9jdXJzb3IAAAAD6AAAAAYAAAAAAAAABXRhc2tzAAAAAAAD6gAAB9AAAAAEVGFzaw==",
+        "AAAAAQAAAAAAAAAAAAAAC1Rhc2tPcHRpb25zAAAAAAkAAAAAAAAADWFjY2VwdF93aW5kb3cAAAAAAAAGAAAAAAAAAAxhdXRvX3JlbGVhc2UAAAABAAAAAAAAAApjbGllbnRfcmVmAAAAAAPoAAAAEAAAAAAAAAAKZGVwZW5kc19vbgAAAAAD6gAAAAYAAAAAAAAADWV4cGlyeV9hY3Rpb24AAAAAAAfQAAAADEV4cGlyeUFjdGlvbgAAAAAAAAARbm90aWZ5X29uX3JlbGVhc2UAAAAAAAABAAAAAAAAAAZvcmFjbGUAAAAAA+gAAAATAAAAAAAAAAlvcmFjbGVfZm4AAAAAAAPoAAAAEQAAAAAAAAAIcHJpb3JpdHkAAAAE",
+        "AAAAAQAAAAAAAAAAAAAADlJlbGVhc2VPdXRjb21lAAAAAAAEAAAAAAAAABBhY2N1bXVsYXRlZF9mZWVzAAAACwAAAAAAAAAIYXNzaWduZWUAAAATAAAAAAAAAA9hc3NpZ25lZV9hbW91bnQAAAAACwAAAAAAAAAMcGxhdGZvcm1fZmVlAAAACw==",
+        "AAAAAQAAAAAAAAAAAAAADlVzZXJGaW5hbmNpYWxzAAAAAAADAAAAAAAAAAZlYXJuZWQAAAAAAAsAAAAAAAAABmxvY2tlZAAAAAAACwAAAAAAAAAFc3BlbnQAAAAAAAAL",
+        "AAAAAQAAAAAAAAAAAAAAEkFzc2lnbmVlUmVwdXRhdGlvbgAAAAAAAgAAAAAAAAAJY29tcGxldGVkAAAAAAAABAAAAAAAAAAGZmFpbGVkAAAAAAAE",
+        "AAAAAQAAAAAAAAAAAAAAEEZ1bmRpbmdCcmVha2Rvd24AAAAEAAAAAAAAAA9hc3NpZ25lZV9wYXlvdXQAAAAACwAAAAAAAAALZXNjcm93X2hlbGQAAAAACwAAAAAAAAANZ3Jvc3NfZnVuZGluZwAAAAAAAAsAAAAAAAAADHBsYXRmb3JtX2ZlZQAAAAs=",
+        "AAAAAQAAAAAAAAAAAAAACVNsYVN0YXR1cwAAAAAAAAMAAAAAAAAAB292ZXJkdWUAAAAAAQAAAAAAAAAPcmVsZWFzZV9vdmVyZHVlAAAAAAEAAAAAAAAACnVuYWNjZXB0ZWQAAAAAAAE=",
+        "AAAAAQAAAAAAAAAAAAAADFJlbGlzdFBhcmFtcwAAAAYAAAAAAAAACGFzc2lnbmVlAAAD6AAAABMAAAAAAAAACGRlYWRsaW5lAAAABgAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAOZnVuZGluZ19hbW91bnQAAAAAAAsAAAAAAAAAC2dpdGh1Yl9saW5rAAAAABAAAAAAAAAABXRpdGxlAAAAAAAAEA==",
+        "AAAAAgAAAAAAAAAAAAAAEFdpdGhkcmF3YWxBY3Rpb24AAAADAAAAAQAAAAAAAAAMUGxhdGZvcm1GZWVzAAAAAgAAABMAAAALAAAAAQAAAAAAAAAMRmVlVG9rZW5GZWVzAAAAAgAAABMAAAALAAAAAQAAAAAAAAAJQXV0b1N3ZWVwAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAQAAAAAAAAAAAAAAEVdpdGhkcmF3YWxSZXF1ZXN0AAAAAAAAAwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAAEFdpdGhkcmF3YWxBY3Rpb24AAAAAAAAACWFwcHJvdmFscwAAAAAAA+oAAAATAAAAAAAAAAJpZAAAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAACFNldHRpbmdzAAAAEgAAAAAAAAAXYmxvY2tfZGVwbG95ZXJfYXNzaWduZWUAAAAAAQAAAAAAAAAOY2FuY2VsX2ZlZV9icHMAAAAAAAQAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAASZGlzcHV0ZV9mZWVfcG9saWN5AAAAAAfQAAAAEERpc3B1dGVGZWVQb2xpY3kAAAAAAAAACWZlZV90b2tlbgAAAAAAA+gAAAATAAAAAAAAAAxmZWVfdHJlYXN1cnkAAAPoAAAAEwAAAAAAAAAWbWF4X2FjdGl2ZV9hc3NpZ25tZW50cwAAAAAABAAAAAAAAAAHbWluX2ZlZQAAAAALAAAAAAAAABFtaW5fdGFza19kdXJhdGlvbgAAAAAAAAYAAAAAAAAADW1pbl93b3JrX3RpbWUAAAAAAAAGAAAAAAAAABdwbGF0Zm9ybV9mZWVfcGVyY2VudGFnZQAAAAAEAAAAAAAAABBwcmlvcml0eV9mZWVfYnBzAAAD6gAAAAQAAAAAAAAAD3JlbGVhc2VfdGltZW91dAAAAAAGAAAAAAAAABNyZXF1aXJlX2dpdGh1Yl9saW5rAAAAAAEAAAAAAAAAD3Jlc3RyaWN0X2V4cGlyeQAAAAABAAAAAAAAAAV0b2tlbgAAAAAAABMAAAAAAAAABXZhdWx0AAAAAAAD6AAAABMAAAAAAAAAFHdpdGhkcmF3YWxfdGhyZXNob2xkAAAABA==",
+        "AAAAAQAAAAAAAAAAAAAABVN0YXRzAAAAAAAACwAAAAAAAAAMYWN0aXZlX3Rhc2tzAAAABgAAAAAAAAAPY29tcGxldGVkX3Rhc2tzAAAAAAYAAAAAAAAADmZlZV90b2tlbl9mZWVzAAAAAAALAAAAAAAAAAxyZWxlYXNlX2ZlZXMAAAALAAAAAAAAAA9yZWxlYXNlZF92b2x1bWUAAAAACwAAAAAAAAAOdG90YWxfZXNjcm93ZWQAAAAAAAsAAAAAAAAAEXRvdGFsX2ZlZXNfZWFybmVkAAAAAAAACwAAAAAAAAAUdG90YWxfZmVlc193aXRoZHJhd24AAAALAAAAAAAAAAp0b3RhbF9wYWlkAAAAAAALAAAAAAAAAA50b3RhbF9yZWZ1bmRlZAAAAAAACwAAAAAAAAALdG90YWxfdGFza3MAAAAABg==",
+        "AAAAAQAAAAAAAAAAAAAADUxlZGdlclN1bW1hcnkAAAAAAAAGAAAAAAAAAA5mZWVfdG9rZW5fZmVlcwAAAAAACwAAAAAAAAAOdG90YWxfZXNjcm93ZWQAAAAAAAsAAAAAAAAAEXRvdGFsX2ZlZXNfZWFybmVkAAAAAAAACwAAAAAAAAAUdG90YWxfZmVlc193aXRoZHJhd24AAAALAAAAAAAAAAp0b3RhbF9wYWlkAAAAAAALAAAAAAAAAA50b3RhbF9yZWZ1bmRlZAAAAAAACw==",
+        "AAAABAAAAAAAAAAAAAAACVRhc2tFcnJvcgAAAAAAAAYAAAAAAAAACkVtcHR5VGl0bGUAAAAAAAEAAAAAAAAAEEVtcHR5RGVzY3JpcHRpb24AAAACAAAAAAAAABJOb25Qb3NpdGl2ZUZ1bmRpbmcAAAAAAAMAAAAAAAAAD0Z1bmRpbmdUb29MYXJnZQAAAAAEAAAAAAAAAA5EZWFkbGluZUluUGFzdAAAAAAABQAAAAAAAAAPRGVhZGxpbmVUb29Tb29uAAAAAAY=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAKAAAAAAAAAAAAAAABVRhc2tzAAAAAAAAAQAAAAAAAAAJVXNlclRhc2tzAAAAAAAAAQAAABMAAAABAAAAAAAAAA1Bc3NpZ25lZFRhc2tzAAAAAAAAAQAAABMAAAAAAAAAAAAAAAtUYXNrQ291bnRlcgAAAAAAAAAAAAAAAAVUb2tlbgAAAAAAAAAAAAAAAAAACERlcGxveWVyAAAAAAAAAAAAAAAMUGxhdGZvcm1GZWVzAAAAAAAAAAAAAAAMVXNlclByb2ZpbGVzAAAAAQAAAAAAAAAIVXNlcm5hbWUAAAABAAAAEwAAAAAAAAAAAAAADlJlbGVhc2VUaW1lb3V0AAAAAAAAAAAAAAAAAApGZWVTaWduZXJzAAAAAAAAAAAAAAAAAA1TaWduVGhyZXNob2xkAAAAAAAAAAAAAAAAAAAQV2l0aGRyYXdSZXF1ZXN0cwAAAAAAAAAAAAAAD1dpdGhkcmF3Q291bnRlcgAAAAAAAAAAAAAAAAlNYXhBY3RpdmUAAAAAAAAAAAAAAAAAAA5Vc2VyRmluYW5jaWFscwAAAAAAAAAAAAAAAAAKUmVwdXRhdGlvbgAAAAAAAAAAAAAAAAALTWluRHVyYXRpb24AAAAAAAAAAAAAAAALUmVxdWlyZUxpbmsAAAAAAAAAAAAAAAAMQ2FuY2VsRmVlQnBzAAAAAAAAAAAAAAAKQ2xpZW50UmVmcwAAAAAAAAAAAAAAAAAOQ3JlYXRvclJhdGluZ3MAAAAAAAAAAAAAAAAAC0NvbW1pdG1lbnRzAAAAAAAAAAAAAAAAC0NyZWF0b3JGZWVzAAAAAAAAAAAAAAAABVZhdWx0AAAAAAAAAAAAAAAAAAAMVmF1bHRCYWxhbmNlAAAAAAAAAAAAAAAGTWluRmVlAAAAAAAAAAAAAAAAAAVTdGF0cwAAAAAAAAAAAAAAAAAADUJsb2NrRGVwbG95ZXIAAAAAAAAAAAAAAAAAAA5SZXN0cmljdEV4cGlyeQAAAAAAAAAAAAAAAAAKQ2FuZGlkYXRlcwAAAAAAAAAAAAAAAAAMUHJpb3JpdHlGZWVzAAAAAAAAAAAAAAALR2l0aHViVGFza3MAAAAAAAAAAAAAAAAIRmVlVG9rZW4AAAAAAAAAAAAAAAxGZWVUb2tlbkZlZXMAAAAAAAAAAAAAAA1GZWVUb2tlbkRlYnRzAAAAAAAAAAAAAAAAAAAKRGlzcHV0ZUZlZQAAAAAAAAAAAAAAAAALTWluV29ya1RpbWUAAAAAAAAAAAAAAAALRmVlVHJlYXN1cnkAAAAAAAAAAAAAAAAHVmVyc2lvbgA=",
+        "AAAAAAAAAZpJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIHRva2VuIGFkZHJlc3MKCiMgQXJndW1lbnRzCiogYHRva2VuYCAtIEFkZHJlc3Mgb2YgdGhlIHRva2VuIGNvbnRyYWN0IGZvciBwYXltZW50cyAodGhlIG5hdGl2ZSBYTE0KU3RlbGxhciBBc3NldCBDb250cmFjdCBvciBhbnkgU0VQLTQxIHRva2VuKQoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llciB3aG8gd2lsbCByZWNlaXZlIHBsYXRmb3JtIGZlZXMKKiBgc2lnbmVyc2AgLSBBZGRyZXNzZXMgYWxsb3dlZCB0byBhcHByb3ZlIGZlZSB3aXRoZHJhd2FscyAoZW1wdHkgZm9yIG5vbmUpCiogYHRocmVzaG9sZGAgLSBOdW1iZXIgb2Ygc2lnbmVyIGFwcHJvdmFscyByZXF1aXJlZCBwZXIgd2l0aGRyYXdhbCAoMCBpZiBubyBzaWduZXJzKQAAAAAACmluaXRpYWxpemUAAAAAAAQAAAAAAAAABXRva2VuAAAAAAAAEwAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAAdzaWduZXJzAAAAA+oAAAATAAAAAAAAAAl0aHJlc2hvbGQAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAIdHZXQgdGhlIHN0b3JhZ2UgbGF5b3V0IHZlcnNpb24gdGhlIGNvbnRyYWN0IHdhcyBpbml0aWFsaXplZCB3aXRoCgojIFJldHVybnMKVGhlIGxheW91dCB2ZXJzaW9uLCBvciAwIGlmIHRoZSBjb250cmFjdCBpcyBub3QgaW5pdGlhbGl6ZWQAAAAAE2dldF9zdG9yYWdlX3ZlcnNpb24AAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAGNDaGVjayB3aGV0aGVyIHRoZSBjb250cmFjdCBoYXMgYmVlbiBpbml0aWFsaXplZAoKIyBSZXR1cm5zCmB0cnVlYCBvbmNlIGBpbml0aWFsaXplYCBoYXMgYmVlbiBjYWxsZWQAAAAADmlzX2luaXRpYWxpemVkAAAAAAAAAAAAAQAAAAE=",
         "AAAAAAAAAIxSZWdpc3RlciBhIHVzZXIgcHJvZmlsZSB3aXRoIGEgcGVybWFuZW50IHVzZXJuYW1lCgojIEFyZ3VtZW50cwoqIGB1c2VyYCAtIEFkZHJlc3Mgb2YgdGhlIHVzZXIKKiBgdXNlcm5hbWVgIC0gUGVybWFuZW50IHVzZXJuYW1lIGZvciB0aGUgdXNlcgAAAA1yZWdpc3Rlcl91c2VyAAAAAAAAAgAAAAAAAAAEdXNlcgAAABMAAAAAAAAACHVzZXJuYW1lAAAAEAAAAAA=",
         "AAAAAAAAAG1HZXQgdXNlciBwcm9maWxlIGJ5IGFkZHJlc3MKCiMgQXJndW1lbnRzCiogYHVzZXJgIC0gQWRkcmVzcyBvZiB0aGUgdXNlcgoKIyBSZXR1cm5zClRoZSB1c2VyIHByb2ZpbGUgaWYgZXhpc3RzAAAAAAAAEGdldF91c2VyX3Byb2ZpbGUAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAPoAAAH0AAAAAtVc2VyUHJvZmlsZQA=",
         "AAAAAAAAAJtBcHBseSBmb3IgYSB0YXNrCgojIEFyZ3VtZW50cwoqIGBhcHBsaWNhbnRgIC0gQWRkcmVzcyBvZiB0aGUgYXBwbGljYW50CiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gYXBwbHkgZm9yCiogYG1lc3NhZ2VgIC0gT3B0aW9uYWwgYXBwbGljYXRpb24gbWVzc2FnZQAAAAAOYXBwbHlfZm9yX3Rhc2sAAAAAAAMAAAAAAAAACWFwcGxpY2FudAAAAAAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAAAAAAHbWVzc2FnZQAAAAAQAAAAAA==",
         "AAAAAAAAAHxHZXQgYWxsIGFwcGxpY2F0aW9ucyBmb3IgYSB0YXNrCgojIEFyZ3VtZW50cwoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrCgojIFJldHVybnMKVmVjdG9yIG9mIGFsbCBhcHBsaWNhdGlvbnMgZm9yIHRoZSB0YXNrAAAAFWdldF90YXNrX2FwcGxpY2F0aW9ucwAAAAAAAAEAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAEAAAPqAAAH0AAAAA9UYXNrQXBwbGljYXRpb24A",
+        "AAAAAAAAAJ1Qb3N0IGEgY29tbWVudCBvbiBhIHRhc2sKCiMgQXJndW1lbnRzCiogYGF1dGhvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3Igb3IgYXNzaWduZWUKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayB0byBjb21tZW50IG9uCiogYHRleHRgIC0gQ29tbWVudCBib2R5AAAAAAAAC2FkZF9jb21tZW50AAAAAAMAAAAAAAAABmF1dGhvcgAAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAAAAAAR0ZXh0AAAAEAAAAAA=",
+        "AAAAAAAAAHtHZXQgYWxsIGNvbW1lbnRzIG9uIGEgdGFzawoKIyBBcmd1bWVudHMKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzawoKIyBSZXR1cm5zClZlY3RvciBvZiB0aGUgdGFzaydzIGNvbW1lbnRzLCBvbGRlc3QgZmlyc3QAAAAADGdldF9jb21tZW50cwAAAAEAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAEAAAPqAAAH0AAAAAtUYXNrQ29tbWVudAA=",
         "AAAAAAAAAKRBc3NpZ24gYSB0YXNrIHRvIGFuIGFwcGxpY2FudAoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzawoqIGBhcHBsaWNhbnRgIC0gQWRkcmVzcyBvZiB0aGUgYXBwbGljYW50IHRvIGFzc2lnbgAAABNhc3NpZ25fdG9fYXBwbGljYW50AAAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAAAAAAlhcHBsaWNhbnQAAAAAAAATAAAAAA==",
-        "AAAAAAAAAYxDcmVhdGUgYSBuZXcgdGFzayB3aXRoIGZ1bmRpbmcgKHdpdGhvdXQgYXNzaWduaW5nKQoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgdGl0bGVgIC0gVGFzayB0aXRsZQoqIGBkZXNjcmlwdGlvbmAgLSBEZXRhaWxlZCBkZXNjcmlwdGlvbiBvZiB0aGUgdGFzawoqIGBnaXRodWJfbGlua2AgLSBHaXRIdWIgcmVwb3NpdG9yeSBsaW5rIChjYW4gYmUgZW1wdHkgc3RyaW5nKQoqIGBmdW5kaW5nX2Ftb3VudGAgLSBBbW91bnQgdG8gZnVuZCB0aGUgdGFzayAoaW4gc3Ryb29wcykKKiBgZGVhZGxpbmVgIC0gVW5peCB0aW1lc3RhbXAgZm9yIHRoZSB0YXNrIGRlYWRsaW5lCgojIFJldHVybnMKVGhlIElEIG9mIHRoZSBuZXdseSBjcmVhdGVkIHRhc2sAAAALY3JlYXRlX3Rhc2sAAAAABgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAC2dpdGh1Yl9saW5rAAAAABAAAAAAAAAADmZ1bmRpbmdfYW1vdW50AAAAAAALAAAAAAAAAAhkZWFkbGluZQAAAAYAAAABAAAABg==",
+        "AAAAAAAAAY5DcmVhdGUgYSBuZXcgdGFzayB3aXRoIGZ1bmRpbmcgKHdpdGhvdXQgYXNzaWduaW5nKQoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgdGl0bGVgIC0gVGFzayB0aXRsZQoqIGBkZXNjcmlwdGlvbmAgLSBEZXRhaWxlZCBkZXNjcmlwdGlvbiBvZiB0aGUgdGFzawoqIGBnaXRodWJfbGlua2AgLSBHaXRIdWIgcmVwb3NpdG9yeSBsaW5rIChlbXB0eSBzdHJpbmcgZm9yIG5vbmUpCiogYGZ1bmRpbmdfYW1vdW50YCAtIEFtb3VudCB0byBmdW5kIHRoZSB0YXNrIChpbiBzdHJvb3BzKQoqIGBkZWFkbGluZWAgLSBVbml4IHRpbWVzdGFtcCBmb3IgdGhlIHRhc2sgZGVhZGxpbmUKCiMgUmV0dXJucwpUaGUgSUQgb2YgdGhlIG5ld2x5IGNyZWF0ZWQgdGFzawAAAAAAC2NyZWF0ZV90YXNrAAAAAAYAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAFdGl0bGUAAAAAAAAQAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAtnaXRodWJfbGluawAAAAAQAAAAAAAAAA5mdW5kaW5nX2Ftb3VudAAAAAAACwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAQAAAAY=",
+        "AAAAAAAAAcdDcmVhdGUgYSBuZXcgdGFzayB3aXRoIGZ1bmRpbmcgYW5kIGFkZGl0aW9uYWwgb3B0aW9ucwoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgdGl0bGVgIC0gVGFzayB0aXRsZQoqIGBkZXNjcmlwdGlvbmAgLSBEZXRhaWxlZCBkZXNjcmlwdGlvbiBvZiB0aGUgdGFzawoqIGBnaXRodWJfbGlua2AgLSBHaXRIdWIgcmVwb3NpdG9yeSBsaW5rIChlbXB0eSBzdHJpbmcgZm9yIG5vbmUpCiogYGZ1bmRpbmdfYW1vdW50YCAtIEFtb3VudCB0byBmdW5kIHRoZSB0YXNrIChpbiBzdHJvb3BzKQoqIGBkZWFkbGluZWAgLSBVbml4IHRpbWVzdGFtcCBmb3IgdGhlIHRhc2sgZGVhZGxpbmUKKiBgb3B0aW9uc2AgLSBPcHRpb25hbCB0YXNrIHNldHRpbmdzIHN1Y2ggYXMgcHJpb3JpdHkKCiMgUmV0dXJucwpUaGUgSUQgb2YgdGhlIG5ld2x5IGNyZWF0ZWQgdGFzawAAAAAYY3JlYXRlX3Rhc2tfd2l0aF9vcHRpb25zAAAABwAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAC2dpdGh1Yl9saW5rAAAAABAAAAAAAAAADmZ1bmRpbmdfYW1vdW50AAAAAAALAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAAB29wdGlvbnMAAAAH0AAAAAtUYXNrT3B0aW9ucwAAAAABAAAABg==",
+        "AAAAAAAAAkZQb3N0IGEgdGFzayB3aG9zZSBmdW5kaW5nIGlzIG9ubHkgZXNjcm93ZWQgb25jZSBhIHdvcmtlciB0YWtlcyBpdCBvbgoKVGhlIHRhc2sgaXMgb3BlbiBmb3IgYW55IHdvcmtlciB0byBjbGFpbS4gVGhlIGNyZWF0b3IgbXVzdCBhcHByb3ZlIHRoaXMgY29udHJhY3QKdG8gc3BlbmQgYGZ1bmRpbmdfYW1vdW50YCwgd2hpY2ggaXMgcHVsbGVkIHdoZW4gdGhlIHRhc2sgaXMgYXNzaWduZWQgb3IgY2xhaW1lZC4KCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHRpdGxlYCAtIFRhc2sgdGl0bGUKKiBgZGVzY3JpcHRpb25gIC0gRGV0YWlsZWQgZGVzY3JpcHRpb24gb2YgdGhlIHRhc2sKKiBgZ2l0aHViX2xpbmtgIC0gR2l0SHViIHJlcG9zaXRvcnkgbGluayAoZW1wdHkgc3RyaW5nIGZvciBub25lKQoqIGBmdW5kaW5nX2Ftb3VudGAgLSBBbW91bnQgcGxlZGdlZCB0byB0aGUgdGFzayAoaW4gc3Ryb29wcykKKiBgZGVhZGxpbmVgIC0gVW5peCB0aW1lc3RhbXAgZm9yIHRoZSB0YXNrIGRlYWRsaW5lCgojIFJldHVybnMKVGhlIElEIG9mIHRoZSBuZXdseSBjcmVhdGVkIHRhc2sAAAAAABJjcmVhdGVfcGxlZGdlX3Rhc2sAAAAAAAYAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAFdGl0bGUAAAAAAAAQAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAtnaXRodWJfbGluawAAAAAQAAAAAAAAAA5mdW5kaW5nX2Ftb3VudAAAAAAACwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAQAAAAY=",
+        "AAAAAAAAAetDcmVhdGUgYSB0YXNrIG9wZXJhdGVkIGJ5IGEgbWFuYWdlciBidXQgZnVuZGVkIGJ5IGFub3RoZXIgYWNjb3VudAoKIyBBcmd1bWVudHMKKiBgbWFuYWdlcmAgLSBBZGRyZXNzIGF1dGhvcml6aW5nIHRoZSBjcmVhdGlvbgoqIGBmdW5kZXJgIC0gQWRkcmVzcyB3aG9zZSB0b2tlbnMgZnVuZCB0aGUgdGFzazsgcmVjb3JkZWQgYXMgdGhlIGNyZWF0b3IKKiBgdGl0bGVgIC0gVGFzayB0aXRsZQoqIGBkZXNjcmlwdGlvbmAgLSBEZXRhaWxlZCBkZXNjcmlwdGlvbiBvZiB0aGUgdGFzawoqIGBnaXRodWJfbGlua2AgLSBHaXRIdWIgcmVwb3NpdG9yeSBsaW5rIChlbXB0eSBzdHJpbmcgZm9yIG5vbmUpCiogYGZ1bmRpbmdfYW1vdW50YCAtIEFtb3VudCB0byBmdW5kIHRoZSB0YXNrIChpbiBzdHJvb3BzKQoqIGBkZWFkbGluZWAgLSBVbml4IHRpbWVzdGFtcCBmb3IgdGhlIHRhc2sgZGVhZGxpbmUKCiMgUmV0dXJucwpUaGUgSUQgb2YgdGhlIG5ld2x5IGNyZWF0ZWQgdGFzawAAAAAPY3JlYXRlX3Rhc2tfZm9yAAAAAAcAAAAAAAAAB21hbmFnZXIAAAAAEwAAAAAAAAAGZnVuZGVyAAAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAC2dpdGh1Yl9saW5rAAAAABAAAAAAAAAADmZ1bmRpbmdfYW1vdW50AAAAAAALAAAAAAAAAAhkZWFkbGluZQAAAAYAAAABAAAABg==",
+        "AAAAAAAAAfRDcmVhdGUgYSBuZXcgdGFzayB3aG9zZSBkZWFkbGluZSBpcyBhIGR1cmF0aW9uIGZyb20gdGhlIGN1cnJlbnQgbGVkZ2VyIHRpbWUKCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHRpdGxlYCAtIFRhc2sgdGl0bGUKKiBgZGVzY3JpcHRpb25gIC0gRGV0YWlsZWQgZGVzY3JpcHRpb24gb2YgdGhlIHRhc2sKKiBgZ2l0aHViX2xpbmtgIC0gR2l0SHViIHJlcG9zaXRvcnkgbGluayAoZW1wdHkgc3RyaW5nIGZvciBub25lKQoqIGBmdW5kaW5nX2Ftb3VudGAgLSBBbW91bnQgdG8gZnVuZCB0aGUgdGFzayAoaW4gc3Ryb29wcykKKiBgZHVyYXRpb25fc2Vjc2AgLSBTZWNvbmRzIGZyb20gbm93IHVudGlsIHRoZSB0YXNrIGRlYWRsaW5lCiogYGFzc2lnbmVlYCAtIE9wdGlvbmFsIGFkZHJlc3MgdG8gYXNzaWduIHRoZSB0YXNrIHRvIHN0cmFpZ2h0IGF3YXkKCiMgUmV0dXJucwpUaGUgSUQgb2YgdGhlIG5ld2x5IGNyZWF0ZWQgdGFzawAAAA5jcmVhdGVfdGFza19pbgAAAAAABwAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAC2dpdGh1Yl9saW5rAAAAABAAAAAAAAAADmZ1bmRpbmdfYW1vdW50AAAAAAALAAAAAAAAAA1kdXJhdGlvbl9zZWNzAAAAAAAABgAAAAAAAAAIYXNzaWduZWUAAAPoAAAAEwAAAAEAAAAG",
+        "AAAAAAAAAg9DcmVhdGUgYSBuZXcgdGFzayByZXNlcnZlZCBmb3IgYW4gYXNzaWduZWUgd2hvc2UgaWRlbnRpdHkgc3RheXMgaGlkZGVuIHVudGlsIHRoZXkgcmV2ZWFsCgojIEFyZ3VtZW50cwoqIGBjcmVhdG9yYCAtIEFkZHJlc3Mgb2YgdGhlIHRhc2sgY3JlYXRvcgoqIGB0aXRsZWAgLSBUYXNrIHRpdGxlCiogYGRlc2NyaXB0aW9uYCAtIERldGFpbGVkIGRlc2NyaXB0aW9uIG9mIHRoZSB0YXNrCiogYGdpdGh1Yl9saW5rYCAtIEdpdEh1YiByZXBvc2l0b3J5IGxpbmsgKGVtcHR5IHN0cmluZyBmb3Igbm9uZSkKKiBgZnVuZGluZ19hbW91bnRgIC0gQW1vdW50IHRvIGZ1bmQgdGhlIHRhc2sgKGluIHN0cm9vcHMpCiogYGRlYWRsaW5lYCAtIFVuaXggdGltZXN0YW1wIGZvciB0aGUgdGFzayBkZWFkbGluZQoqIGBhc3NpZ25lZV9jb21taXRtZW50YCAtIFNIQS0yNTYgb2YgdGhlIGFzc2lnbmVlJ3MgWERSLWVuY29kZWQgYWRkcmVzcyBmb2xsb3dlZCBieSBhIHNhbHQKCiMgUmV0dXJucwpUaGUgSUQgb2YgdGhlIG5ld2x5IGNyZWF0ZWQgdGFzawAAAAAVY3JlYXRlX3Rhc2tfY29tbWl0dGVkAAAAAAAABwAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAV0aXRsZQAAAAAAABAAAAAAAAAAC2Rlc2NyaXB0aW9uAAAAABAAAAAAAAAAC2dpdGh1Yl9saW5rAAAAABAAAAAAAAAADmZ1bmRpbmdfYW1vdW50AAAAAAALAAAAAAAAAAhkZWFkbGluZQAAAAYAAAAAAAAAE2Fzc2lnbmVlX2NvbW1pdG1lbnQAAAAD7gAAACAAAAABAAAABg==",
+        "AAAAAAAAANBSZXZlYWwgYXMgdGhlIGNvbW1pdHRlZCBhc3NpZ25lZSBvZiBhIHRhc2sgYW5kIGFjY2VwdCBpdAoKIyBBcmd1bWVudHMKKiBgYXNzaWduZWVgIC0gQWRkcmVzcyBvZiB0aGUgY29tbWl0dGVkIGFzc2lnbmVlCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIGNvbW1pdHRlZCB0YXNrCiogYHNhbHRgIC0gU2FsdCB1c2VkIHdoZW4gY29tcHV0aW5nIHRoZSBjb21taXRtZW50AAAAEXJldmVhbF9hbmRfYWNjZXB0AAAAAAAAAwAAAAAAAAAIYXNzaWduZWUAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAAAAAABHNhbHQAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAAetDcmVhdGUgYSBuZXcgdGFzayBvZmZlcmVkIHRvIHNldmVyYWwgY2FuZGlkYXRlcywgdGhlIGZpcnN0IG9mIHdob20gdG8gY2xhaW0gaXQgaXMgYXNzaWduZWQKCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHRpdGxlYCAtIFRhc2sgdGl0bGUKKiBgZGVzY3JpcHRpb25gIC0gRGV0YWlsZWQgZGVzY3JpcHRpb24gb2YgdGhlIHRhc2sKKiBgZ2l0aHViX2xpbmtgIC0gR2l0SHViIHJlcG9zaXRvcnkgbGluayAoZW1wdHkgc3RyaW5nIGZvciBub25lKQoqIGBmdW5kaW5nX2Ftb3VudGAgLSBBbW91bnQgdG8gZnVuZCB0aGUgdGFzayAoaW4gc3Ryb29wcykKKiBgZGVhZGxpbmVgIC0gVW5peCB0aW1lc3RhbXAgZm9yIHRoZSB0YXNrIGRlYWRsaW5lCiogYGNhbmRpZGF0ZXNgIC0gQWRkcmVzc2VzIGFsbG93ZWQgdG8gY2xhaW0gdGhlIHRhc2sKCiMgUmV0dXJucwpUaGUgSUQgb2YgdGhlIG5ld2x5IGNyZWF0ZWQgdGFzawAAAAAbY3JlYXRlX3Rhc2tfd2l0aF9jYW5kaWRhdGVzAAAAAAcAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAFdGl0bGUAAAAAAAAQAAAAAAAAAAtkZXNjcmlwdGlvbgAAAAAQAAAAAAAAAAtnaXRodWJfbGluawAAAAAQAAAAAAAAAA5mdW5kaW5nX2Ftb3VudAAAAAAACwAAAAAAAAAIZGVhZGxpbmUAAAAGAAAAAAAAAApjYW5kaWRhdGVzAAAAAAPqAAAAEwAAAAEAAAAG",
+        "AAAAAAAAAJZDbGFpbSBhIHRhc2sgYXMgb25lIG9mIGl0cyBjYW5kaWRhdGVzCgojIEFyZ3VtZW50cwoqIGBhc3NpZ25lZWAgLSBBZGRyZXNzIG9mIHRoZSBjbGFpbWluZyBjYW5kaWRhdGUKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayBvZmZlcmVkIHRvIGNhbmRpZGF0ZXMAAAAAABRjbGFpbV9jYW5kaWRhdGVfdGFzawAAAAIAAAAAAAAACGFzc2lnbmVlAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAA==",
         "AAAAAAAAAM1Bc3NpZ24gYSB0YXNrIHRvIGEgdXNlciAob25seSBpZiBub3QgYWxyZWFkeSBhc3NpZ25lZCkKCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gYXNzaWduCiogYGFzc2lnbmVlYCAtIEFkZHJlc3Mgb2YgdGhlIHVzZXIgdG8gYXNzaWduIHRoZSB0YXNrIHRvAAAAAAAAC2Fzc2lnbl90YXNrAAAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAAAAAAhhc3NpZ25lZQAAABMAAAAA",
+        "AAAAAAAAARhFZGl0IGEgdGFzaydzIGRldGFpbHMgYmVmb3JlIHRoZSBhc3NpZ25lZSBzdGFydHMgd29yawoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayB0byBlZGl0CiogYHRpdGxlYCAtIE5ldyB0YXNrIHRpdGxlCiogYGRlc2NyaXB0aW9uYCAtIE5ldyB0YXNrIGRlc2NyaXB0aW9uCiogYGdpdGh1Yl9saW5rYCAtIE5ldyBHaXRIdWIgcmVwb3NpdG9yeSBsaW5rIChlbXB0eSBzdHJpbmcgZm9yIG5vbmUpAAAAE3VwZGF0ZV90YXNrX2RldGFpbHMAAAAABQAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAAAAAABXRpdGxlAAAAAAAAEAAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAALZ2l0aHViX2xpbmsAAAAAEAAAAAA=",
         "AAAAAAAAAIRNYXJrIGEgdGFzayBhcyBjb21wbGV0ZSBieSB0aGUgYXNzaWduZWUKCiMgQXJndW1lbnRzCiogYGFzc2lnbmVlYCAtIEFkZHJlc3Mgb2YgdGhlIGFzc2lnbmVlCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gY29tcGxldGUAAAANY29tcGxldGVfdGFzawAAAAAAAAIAAAAAAAAACGFzc2lnbmVlAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAA==",
+        "AAAAAAAAAPpNYXJrIGEgdGFzayBhcyBjb21wbGV0ZSwgcmVjb3JkaW5nIGEgaGFzaCBvZiB0aGUgZGVsaXZlcmVkIGFydGlmYWN0CgojIEFyZ3VtZW50cwoqIGBhc3NpZ25lZWAgLSBBZGRyZXNzIG9mIHRoZSBhc3NpZ25lZQoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrIHRvIGNvbXBsZXRlCiogYGRlbGl2ZXJhYmxlX2hhc2hgIC0gU0hBLTI1NiBoYXNoIG9mIHRoZSBkZWxpdmVyYWJsZSwgZm9yIHRoZSBjcmVhdG9yIHRvIHZlcmlmeSBhZ2FpbnN0AAAAAAAXY29tcGxldGVfdGFza193aXRoX2hhc2gAAAAAAwAAAAAAAAAIYXNzaWduZWUAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAAAAAAEGRlbGl2ZXJhYmxlX2hhc2gAAAPuAAAAIAAAAAA=",
+        "AAAAAAAAANhSZXBsYWNlIHRoZSBjb21wbGV0aW9uIHByb29mIG9mIGEgdGFzayBhd2FpdGluZyBmdW5kIHJlbGVhc2UKCiMgQXJndW1lbnRzCiogYGFzc2lnbmVlYCAtIEFkZHJlc3Mgb2YgdGhlIGFzc2lnbmVlCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIGNvbXBsZXRlZCB0YXNrCiogYHByb29mYCAtIE5ldyBkZWxpdmVyYWJsZSByZWZlcmVuY2UgKGUuZy4gYSBwdWxsIHJlcXVlc3QgbGluaykAAAAXdXBkYXRlX2NvbXBsZXRpb25fcHJvb2YAAAAAAwAAAAAAAAAIYXNzaWduZWUAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAAAAAABXByb29mAAAAAAAAEAAAAAA=",
+        "AAAAAAAAAMdTZW5kIHRoaXMgdGFzaydzIHBheW91dCB0byBhIHdhbGxldCBvdGhlciB0aGFuIHRoZSBhc3NpZ25lZSdzCgojIEFyZ3VtZW50cwoqIGBhc3NpZ25lZWAgLSBBZGRyZXNzIG9mIHRoZSBhc3NpZ25lZQoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrCiogYHBheW91dF9hZGRyYCAtIEFkZHJlc3MgdGhhdCBzaG91bGQgcmVjZWl2ZSB0aGUgcGF5b3V0AAAAABJzZXRfcGF5b3V0X2FkZHJlc3MAAAAAAAMAAAAAAAAACGFzc2lnbmVlAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAAAAAAtwYXlvdXRfYWRkcgAAAAATAAAAAA==",
+        "AAAAAAAAAMdTZW5kIHRoaXMgdGFzaydzIHJlZnVuZHMgdG8gYSB3YWxsZXQgb3RoZXIgdGhhbiB0aGUgY3JlYXRvcidzCgojIEFyZ3VtZW50cwoqIGBjcmVhdG9yYCAtIEFkZHJlc3Mgb2YgdGhlIHRhc2sgY3JlYXRvcgoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrCiogYHJlZnVuZF9hZGRyYCAtIEFkZHJlc3MgdGhhdCBzaG91bGQgcmVjZWl2ZSByZWZ1bmRzAAAAABJzZXRfcmVmdW5kX2FkZHJlc3MAAAAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAAAAAAtyZWZ1bmRfYWRkcgAAAAATAAAAAA==",
         "AAAAAAAAAHpVcGRhdGUgdGFzayBzdGF0dXMgdG8gSW5Qcm9ncmVzcwoKIyBBcmd1bWVudHMKKiBgYXNzaWduZWVgIC0gQWRkcmVzcyBvZiB0aGUgYXNzaWduZWUKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayB0byBzdGFydAAAAAAACnN0YXJ0X3Rhc2sAAAAAAAIAAAAAAAAACGFzc2lnbmVlAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAA==",
         "AAAAAAAAAJ1SZWxlYXNlIGZ1bmRzIHRvIHRoZSBhc3NpZ25lZSBhZnRlciBjcmVhdG9yIGFwcHJvdmFsCgojIEFyZ3VtZW50cwoqIGBjcmVhdG9yYCAtIEFkZHJlc3Mgb2YgdGhlIHRhc2sgY3JlYXRvcgoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrIHRvIHJlbGVhc2UgZnVuZHMgZm9yAAAAAAAADXJlbGVhc2VfZnVuZHMAAAAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAPlSZWxlYXNlIGZ1bmRzIHRvIHRoZSBhc3NpZ25lZSBhbmQgYWRkIGEgYm9udXMgcGFpZCBieSB0aGUgY3JlYXRvcgoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayB0byByZWxlYXNlIGZ1bmRzIGZvcgoqIGB0aXBgIC0gRXh0cmEgYW1vdW50IHNlbnQgZnJvbSB0aGUgY3JlYXRvciB0byB0aGUgYXNzaWduZWUgKG5vIHBsYXRmb3JtIGZlZSkAAAAAAAAWcmVsZWFzZV9mdW5kc193aXRoX3RpcAAAAAAAAwAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAAAAAAA3RpcAAAAAALAAAAAA==",
+        "AAAAAAAAAKZMZXQgdGhlIGFzc2lnbmVlIGNsYWltIHBheW1lbnQgd2hlbiB0aGUgY3JlYXRvciBoYXMgbm90IHJlbGVhc2VkIGl0IGluIHRpbWUKCiMgQXJndW1lbnRzCiogYGFzc2lnbmVlYCAtIEFkZHJlc3Mgb2YgdGhlIGFzc2lnbmVlCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIGNvbXBsZXRlZCB0YXNrAAAAAAATY2xhaW1fYWZ0ZXJfdGltZW91dAAAAAACAAAAAAAAAAhhc3NpZ25lZQAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAL5TZXQgaG93IGxvbmcgdGhlIGNyZWF0b3IgaGFzIHRvIHJlbGVhc2UgZnVuZHMgYmVmb3JlIHRoZSBhc3NpZ25lZSBtYXkgY2xhaW0gdGhlbQoKIyBBcmd1bWVudHMKKiBgZGVwbG95ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29udHJhY3QgZGVwbG95ZXIKKiBgdGltZW91dGAgLSBXaW5kb3cgaW4gc2Vjb25kcyBhZnRlciBjb21wbGV0aW9uAAAAAAATc2V0X3JlbGVhc2VfdGltZW91dAAAAAACAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAAB3RpbWVvdXQAAAAABgAAAAA=",
+        "AAAAAAAAAM1TZXQgdGhlIG1heGltdW0gbnVtYmVyIG9mIGFjdGl2ZSB0YXNrcyBhIHNpbmdsZSBhc3NpZ25lZSBtYXkgaG9sZAoKIyBBcmd1bWVudHMKKiBgZGVwbG95ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29udHJhY3QgZGVwbG95ZXIKKiBgY2FwYCAtIE1heGltdW0gYEFzc2lnbmVkYC9gSW5Qcm9ncmVzc2AgdGFza3MgcGVyIGFzc2lnbmVlICgwIGZvciB1bmxpbWl0ZWQpAAAAAAAAGnNldF9tYXhfYWN0aXZlX2Fzc2lnbm1lbnRzAAAAAAACAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAAA2NhcAAAAAAEAAAAAA==",
+        "AAAAAAAAANRTZXQgdGhlIG1pbmltdW0gdGltZSBiZXR3ZWVuIGNyZWF0aW9uIGFuZCBhIG5ldyB0YXNrJ3MgZGVhZGxpbmUgKG9ubHkgZGVwbG95ZXIgY2FuIGNhbGwpCgojIEFyZ3VtZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llcgoqIGBkdXJhdGlvbmAgLSBNaW5pbXVtIHRhc2sgZHVyYXRpb24gaW4gc2Vjb25kcyAoMCB0byBkaXNhYmxlKQAAABVzZXRfbWluX3Rhc2tfZHVyYXRpb24AAAAAAAACAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAACGR1cmF0aW9uAAAABgAAAAA=",
+        "AAAAAAAAAIFHZXQgdGhlIG1pbmltdW0gdGltZSBiZXR3ZWVuIGNyZWF0aW9uIGFuZCBhIG5ldyB0YXNrJ3MgZGVhZGxpbmUKCiMgUmV0dXJucwpUaGUgbWluaW11bSB0YXNrIGR1cmF0aW9uIGluIHNlY29uZHMsIG9yIDAgaWYgZGlzYWJsZWQAAAAAAAAVZ2V0X21pbl90YXNrX2R1cmF0aW9uAAAAAAAAAAAAAAEAAAAG",
+        "AAAAAAAAANFTZXQgdGhlIG1pbmltdW0gdGltZSBiZXR3ZWVuIHN0YXJ0aW5nIGFuZCBjb21wbGV0aW5nIGEgdGFzayAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYG1pbl93b3JrX3RpbWVgIC0gTWluaW11bSB3b3JrIHRpbWUgaW4gc2Vjb25kcyAoMCB0byBkaXNhYmxlKQAAAAAAABFzZXRfbWluX3dvcmtfdGltZQAAAAAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAANbWluX3dvcmtfdGltZQAAAAAAAAYAAAAA",
+        "AAAAAAAAAHlHZXQgdGhlIG1pbmltdW0gdGltZSBiZXR3ZWVuIHN0YXJ0aW5nIGFuZCBjb21wbGV0aW5nIGEgdGFzawoKIyBSZXR1cm5zClRoZSBtaW5pbXVtIHdvcmsgdGltZSBpbiBzZWNvbmRzLCBvciAwIGlmIGRpc2FibGVkAAAAAAAAEWdldF9taW5fd29ya190aW1lAAAAAAAAAAAAAAEAAAAG",
+        "AAAAAAAAAPFTZXQgdGhlIHBsYXRmb3JtIGZlZSByYXRlIGZvciBuZXcgdGFza3Mgb2YgYSBwcmlvcml0eSBsZXZlbCAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYHByaW9yaXR5YCAtIFByaW9yaXR5IGxldmVsIHRoZSByYXRlIGFwcGxpZXMgdG8KKiBgYnBzYCAtIEZlZSByYXRlIGluIGJhc2lzIHBvaW50cyAoMzAwID0gMyUpAAAAAAAAFHNldF9wcmlvcml0eV9mZWVfYnBzAAAAAwAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAAhwcmlvcml0eQAAAAQAAAAAAAAAA2JwcwAAAAAEAAAAAA==",
+        "AAAAAAAAALhHZXQgdGhlIHBsYXRmb3JtIGZlZSByYXRlIGFwcGxpZWQgdG8gbmV3IHRhc2tzIG9mIGEgcHJpb3JpdHkgbGV2ZWwKCiMgQXJndW1lbnRzCiogYHByaW9yaXR5YCAtIFByaW9yaXR5IGxldmVsCgojIFJldHVybnMKVGhlIGNvbmZpZ3VyZWQgZmVlIHJhdGUgaW4gYmFzaXMgcG9pbnRzLCBvciB0aGUgZGVmYXVsdCAzJSByYXRlAAAAFGdldF9wcmlvcml0eV9mZWVfYnBzAAAAAQAAAAAAAAAIcHJpb3JpdHkAAAAEAAAAAQAAAAQ=",
+        "AAAAAAAAANhTZXQgdGhlIHNtYWxsZXN0IHBsYXRmb3JtIGZlZSB0YWtlbiBvbiBhIHBheW91dCAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYG1pbl9mZWVgIC0gRmVlIGZsb29yIGluIHRva2VuIHVuaXRzLCBjYXBwZWQgYXQgdGhlIHBheW91dCBpdHNlbGYgKDAgdG8gZGlzYWJsZSkAAAALc2V0X21pbl9mZWUAAAAAAgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAAdtaW5fZmVlAAAAAAsAAAAA",
+        "AAAAAAAAAGlHZXQgdGhlIHNtYWxsZXN0IHBsYXRmb3JtIGZlZSB0YWtlbiBvbiBhIHBheW91dAoKIyBSZXR1cm5zClRoZSBmZWUgZmxvb3IgaW4gdG9rZW4gdW5pdHMsIG9yIDAgaWYgZGlzYWJsZWQAAAAAAAALZ2V0X21pbl9mZWUAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAAMpTZXQgd2hldGhlciBuZXcgdGFza3MgbXVzdCByZWZlcmVuY2UgYSBHaXRIdWIgbGluayAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYHJlcXVpcmVkYCAtIFdoZXRoZXIgY3JlYXRpbmcgYSB0YXNrIHdpdGhvdXQgYSBsaW5rIGlzIHJlamVjdGVkAAAAAAAXc2V0X3JlcXVpcmVfZ2l0aHViX2xpbmsAAAAAAgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAAhyZXF1aXJlZAAAAAEAAAAA",
+        "AAAAAAAAAGpHZXQgd2hldGhlciBuZXcgdGFza3MgbXVzdCByZWZlcmVuY2UgYSBHaXRIdWIgbGluawoKIyBSZXR1cm5zClRydWUgaWYgdGFzayBjcmVhdGlvbiByZXF1aXJlcyBhIEdpdEh1YiBsaW5rAAAAAAAXZ2V0X3JlcXVpcmVfZ2l0aHViX2xpbmsAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAAORTZXQgd2hldGhlciB0aGUgZGVwbG95ZXIgaXMgYmFycmVkIGZyb20gYmVpbmcgYXNzaWduZWQgdGFza3MgKG9ubHkgZGVwbG95ZXIgY2FuIGNhbGwpCgojIEFyZ3VtZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llcgoqIGBibG9ja2VkYCAtIFdoZXRoZXIgYXNzaWduaW5nIG9yIHJlYXNzaWduaW5nIGEgdGFzayB0byB0aGUgZGVwbG95ZXIgaXMgcmVqZWN0ZWQAAAAbc2V0X2Jsb2NrX2RlcGxveWVyX2Fzc2lnbmVlAAAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAHYmxvY2tlZAAAAAABAAAAAA==",
+        "AAAAAAAAAHhHZXQgd2hldGhlciB0aGUgZGVwbG95ZXIgaXMgYmFycmVkIGZyb20gYmVpbmcgYXNzaWduZWQgdGFza3MKCiMgUmV0dXJucwpUcnVlIGlmIHRhc2tzIGNhbm5vdCBiZSBhc3NpZ25lZCB0byB0aGUgZGVwbG95ZXIAAAAbZ2V0X2Jsb2NrX2RlcGxveWVyX2Fzc2lnbmVlAAAAAAAAAAABAAAAAQ==",
+        "AAAAAAAAAORTZXQgd2hldGhlciBtYXJraW5nIHRhc2tzIGV4cGlyZWQgaXMgbGltaXRlZCB0byBwYXJ0aWNpcGFudHMgKG9ubHkgZGVwbG95ZXIgY2FuIGNhbGwpCgojIEFyZ3VtZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llcgoqIGByZXN0cmljdGVkYCAtIFdoZXRoZXIgb25seSB0aGUgY3JlYXRvciwgYXNzaWduZWUgb3IgZGVwbG95ZXIgbWF5IGV4cGlyZSBhIHRhc2sAAAATc2V0X3Jlc3RyaWN0X2V4cGlyeQAAAAACAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAACnJlc3RyaWN0ZWQAAAAAAAEAAAAA",
+        "AAAAAAAAAG9HZXQgd2hldGhlciBtYXJraW5nIHRhc2tzIGV4cGlyZWQgaXMgcmVzdHJpY3RlZCB0byBwYXJ0aWNpcGFudHMKCiMgUmV0dXJucwpUcnVlIGlmIGFub255bW91cyBleHBpcnkgaXMgZGlzYWJsZWQAAAAAE2dldF9yZXN0cmljdF9leHBpcnkAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAALJTd2l0Y2ggdGhlIHBheW1lbnQgdG9rZW4gdXNlZCBieSBuZXcgdGFza3MgKG9ubHkgZGVwbG95ZXIgY2FuIGNhbGwpCgojIEFyZ3VtZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llcgoqIGBuZXdfdG9rZW5gIC0gQWRkcmVzcyBvZiB0aGUgbmV3IHRva2VuIGNvbnRyYWN0AAAAAAAJc2V0X3Rva2VuAAAAAAAAAgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAAluZXdfdG9rZW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAANhTZXQgdGhlIHlpZWxkIHZhdWx0IHRoYXQgaG9sZHMgaWRsZSBlc2Nyb3cgKG9ubHkgZGVwbG95ZXIgY2FuIGNhbGwpCgojIEFyZ3VtZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llcgoqIGB2YXVsdGAgLSBWYXVsdCBjb250cmFjdCBmb3IgbmV3IHRhc2sgZXNjcm93cywgb3IgTm9uZSB0byBrZWVwIGVzY3JvdyBpbiB0aGUgY29udHJhY3QAAAAJc2V0X3ZhdWx0AAAAAAAAAgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAAV2YXVsdAAAAAAAA+gAAAATAAAAAA==",
+        "AAAAAAAAAS5HaXZlIHVwIG9uIGEgdmF1bHQgdGhhdCBjYW5ub3QgcmV0dXJuIGl0cyBkZXBvc2l0cyAob25seSBkZXBsb3llciBjYW4gY2FsbCkKClRoZSBkZXBsb3llciBtdXN0IGZpcnN0IG1ha2UgZ29vZCB0aGUgbG9zdCBkZXBvc2l0cyBieSB0cmFuc2ZlcnJpbmcgdG9rZW5zIHRvIHRoaXMKY29udHJhY3Q7IHRoZSB2YXVsdCBpcyB0aGVuIGZvcmdvdHRlbiBhbmQgZXZlcnkgdGFzaydzIGVzY3JvdyBpcyBoZWxkIGhlcmUgYWdhaW4uCgojIEFyZ3VtZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llcgAAAAAAD3dyaXRlX29mZl92YXVsdAAAAAABAAAAAAAAAAhkZXBsb3llcgAAABMAAAAA",
+        "AAAAAAAAAHBHZXQgdGhlIHlpZWxkIHZhdWx0IHRoYXQgaG9sZHMgaWRsZSBlc2Nyb3cKCiMgUmV0dXJucwpUaGUgdmF1bHQgYWRkcmVzcywgb3IgTm9uZSBpZiBlc2Nyb3cgc3RheXMgaW4gdGhlIGNvbnRyYWN0AAAACWdldF92YXVsdAAAAAAAAAAAAAABAAAD6AAAABM=",
+        "AAAAAAAAAdBTd2VlcCBlYWNoIHJlbGVhc2UncyBwbGF0Zm9ybSBmZWUgc3RyYWlnaHQgdG8gYSB0cmVhc3VyeSAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCldoaWxlIHNldCwgcmVsZWFzZSBmZWVzIGJ5cGFzcyB0aGUgYWNjdW11bGF0b3I7IGNhbmNlbGxhdGlvbiBhbmQgZGlzcHV0ZSBmZWVzIHN0aWxsCmFjY3J1ZSBmb3IgbWFudWFsIHdpdGhkcmF3YWwuIFNpbmNlIHRoZSB0cmVhc3VyeSByZWNlaXZlcyBmZWVzIHdpdGhvdXQgYSB3aXRoZHJhd2FsLApjaGFuZ2luZyBpdCBuZWVkcyBzaWduZXJzIHRvIGhhdmUgYXBwcm92ZWQgYSByZXF1ZXN0IG5hbWluZyB0aGUgbmV3IHRyZWFzdXJ5LgoKIyBBcmd1bWVudHMKKiBgZGVwbG95ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29udHJhY3QgZGVwbG95ZXIKKiBgdHJlYXN1cnlgIC0gQWRkcmVzcyByZWNlaXZpbmcgcmVsZWFzZSBmZWVzLCBvciBOb25lIHRvIGFjY3VtdWxhdGUgdGhlbQAAABNzZXRfYXV0b19zd2VlcF9mZWVzAAAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAIdHJlYXN1cnkAAAPoAAAAEwAAAAA=",
+        "AAAAAAAAAHxHZXQgdGhlIHRyZWFzdXJ5IHJlbGVhc2UgZmVlcyBhcmUgc3dlcHQgdG8KCiMgUmV0dXJucwpUaGUgdHJlYXN1cnkgYWRkcmVzcywgb3IgTm9uZSBpZiBmZWVzIGFjY3VtdWxhdGUgZm9yIG1hbnVhbCB3aXRoZHJhd2FsAAAAE2dldF9hdXRvX3N3ZWVwX2ZlZXMAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAfdDaGFyZ2UgcmVsZWFzZSBmZWVzIGluIGEgc2VwYXJhdGUgdG9rZW4gcHVsbGVkIGZyb20gdGhlIGNyZWF0b3IgKG9ubHkgZGVwbG95ZXIgY2FuIGNhbGwpCgpXaGlsZSBzZXQsIHJlbGVhc2VzIHBheSB0aGUgYXNzaWduZWUgdGhlIHdob2xlIGVzY3JvdyBhbmQgY2hhcmdlIHRoZSBmZWUgdG8gdGhlIGNyZWF0b3IgaW4KdGhlIGZlZSB0b2tlbi4gQSByZWxlYXNlIHRoZSBjcmVhdG9yIHNpZ25zIGNvbGxlY3RzIHRoZSBmZWUgYXQgb25jZTsgdGltZW91dCBjbGFpbXMgYW5kCmF1dG8tcmVsZWFzZXMgcmVjb3JkIGl0IGFzIGEgZGVidCB0aGUgY3JlYXRvciBtdXN0IHNldHRsZSBiZWZvcmUgY3JlYXRpbmcgbW9yZSB0YXNrcy4KCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYGZlZV90b2tlbmAgLSBUb2tlbiBjb250cmFjdCBmZWVzIGFyZSBjaGFyZ2VkIGluLCBvciBOb25lIHRvIGRlZHVjdCBmZWVzIGZyb20gcGF5b3V0cwAAAAANc2V0X2ZlZV90b2tlbgAAAAAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAJZmVlX3Rva2VuAAAAAAAD6AAAABMAAAAA",
+        "AAAAAAAAAG1HZXQgdGhlIHRva2VuIHJlbGVhc2UgZmVlcyBhcmUgY2hhcmdlZCBpbgoKIyBSZXR1cm5zClRoZSBmZWUgdG9rZW4sIG9yIE5vbmUgaWYgZmVlcyBhcmUgZGVkdWN0ZWQgZnJvbSBwYXlvdXRzAAAAAAAADWdldF9mZWVfdG9rZW4AAAAAAAAAAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAHBHZXQgdGhlIHBsYXRmb3JtIGZlZXMgYWNjcnVlZCBpbiB0aGUgZmVlIHRva2VuCgojIFJldHVybnMKVGhlIHVud2l0aGRyYXduIGZlZS10b2tlbiBiYWxhbmNlIG93ZWQgdG8gdGhlIHBsYXRmb3JtAAAAEmdldF9mZWVfdG9rZW5fZmVlcwAAAAAAAAAAAAEAAAAL",
+        "AAAAAAAAAL5HZXQgdGhlIGZlZS10b2tlbiBmZWVzIGEgY3JlYXRvciBvd2VzIGZvciByZWxlYXNlcyB0aGV5IGRpZCBub3Qgc2lnbgoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKCiMgUmV0dXJucwpUaGUgdW5zZXR0bGVkIGZlZS10b2tlbiBhbW91bnQsIG9yIDAgaWYgbm90aGluZyBpcyBvd2VkAAAAAAASZ2V0X2ZlZV90b2tlbl9kZWJ0AAAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAItQYXkgdGhlIGZlZS10b2tlbiBmZWVzIG93ZWQgZm9yIHRpbWVvdXQgY2xhaW1zIGFuZCBhdXRvLXJlbGVhc2VzCgojIEFyZ3VtZW50cwoqIGBjcmVhdG9yYCAtIEFkZHJlc3Mgb2YgdGhlIHRhc2sgY3JlYXRvciBzZXR0bGluZyB0aGVpciBkZWJ0AAAAABVzZXR0bGVfZmVlX3Rva2VuX2RlYnQAAAAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAA",
+        "AAAAAAAAAP5XaXRoZHJhdyB0aGUgcGxhdGZvcm0gZmVlcyBhY2NydWVkIGluIHRoZSBmZWUgdG9rZW4gKG9ubHkgZGVwbG95ZXIgY2FuIGNhbGwpCgpXaXRoIG11bHRpLXNpZyBjb25maWd1cmVkLCBzaWduZXJzIG11c3QgaGF2ZSBhcHByb3ZlZCBhIHJlcXVlc3QgdG8gcGF5IHRoZSB3aG9sZQpmZWUtdG9rZW4gYmFsYW5jZSB0byB0aGUgZGVwbG95ZXIuCgojIEFyZ3VtZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llcgAAAAAAF3dpdGhkcmF3X2ZlZV90b2tlbl9mZWVzAAAAAAEAAAAAAAAACGRlcGxveWVyAAAAEwAAAAA=",
+        "AAAAAAAAANVTZXQgdGhlIGZlZSBjaGFyZ2VkIG9uIGNyZWF0b3IgY2FuY2VsbGF0aW9ucyAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYGJwc2AgLSBDYW5jZWxsYXRpb24gZmVlIGluIGJhc2lzIHBvaW50cyBvZiB0aGUgZXNjcm93ICgwIGZvciBmcmVlIGNhbmNlbGxhdGlvbikAAAAAAAASc2V0X2NhbmNlbF9mZWVfYnBzAAAAAAACAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAAA2JwcwAAAAAEAAAAAA==",
+        "AAAAAAAAAHpHZXQgdGhlIGZlZSBjaGFyZ2VkIG9uIGNyZWF0b3IgY2FuY2VsbGF0aW9ucwoKIyBSZXR1cm5zClRoZSBjYW5jZWxsYXRpb24gZmVlIGluIGJhc2lzIHBvaW50cywgb3IgMCBpZiBjYW5jZWxsYXRpb24gaXMgZnJlZQAAAAAAEmdldF9jYW5jZWxfZmVlX2JwcwAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAHRHZXQgdGhlIG1heGltdW0gbnVtYmVyIG9mIGFjdGl2ZSB0YXNrcyBhIHNpbmdsZSBhc3NpZ25lZSBtYXkgaG9sZAoKIyBSZXR1cm5zClRoZSBwZXItYXNzaWduZWUgY2FwLCBvciAwIGlmIHVubGltaXRlZAAAABpnZXRfbWF4X2FjdGl2ZV9hc3NpZ25tZW50cwAAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAAG9HZXQgdGhlIHdpbmRvdyBhZnRlciBjb21wbGV0aW9uIGJlZm9yZSB0aGUgYXNzaWduZWUgbWF5IGNsYWltIHBheW1lbnQKCiMgUmV0dXJucwpUaGUgcmVsZWFzZSB0aW1lb3V0IGluIHNlY29uZHMAAAAAE2dldF9yZWxlYXNlX3RpbWVvdXQAAAAAAAAAAAEAAAAG",
+        "AAAAAAAAAF5HZXQgZXZlcnkgY29uZmlndXJhYmxlIGNvbnRyYWN0IHBhcmFtZXRlciBpbiBvbmUgY2FsbAoKIyBSZXR1cm5zClRoZSBjdXJyZW50IGNvbnRyYWN0IHNldHRpbmdzAAAAAAAMZ2V0X3NldHRpbmdzAAAAAAAAAAEAAAfQAAAACFNldHRpbmdz",
+        "AAAAAAAAAOhQcmV2aWV3IHRoZSBvdXRjb21lIG9mIHJlbGVhc2luZyBmdW5kcyBmb3IgYSB0YXNrIHdpdGhvdXQgbXV0YXRpbmcgc3RhdGUKCiMgQXJndW1lbnRzCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gc2ltdWxhdGUgdGhlIHJlbGVhc2UgZm9yCgojIFJldHVybnMKVGhlIGFzc2lnbmVlLCB0aGVpciBwYXlvdXQsIHRoZSBwbGF0Zm9ybSBmZWUgYW5kIHRoZSByZXN1bHRpbmcgZmVlIGFjY3VtdWxhdG9yAAAAEHNpbXVsYXRlX3JlbGVhc2UAAAABAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAH0AAAAA5SZWxlYXNlT3V0Y29tZQAA",
+        "AAAAAAAAAMJCcmVhayBhIHRhc2sncyBmdW5kaW5nIGRvd24gaW50byBmZWUsIHBheW91dCwgYW5kIGVzY3JvdyBjb21wb25lbnRzCgojIEFyZ3VtZW50cwoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrCgojIFJldHVybnMKVGhlIHRhc2sncyBncm9zcyBmdW5kaW5nLCBwbGF0Zm9ybSBmZWUsIG5ldCBwYXlvdXQsIGFuZCBlc2Nyb3cgc3RpbGwgaGVsZAAAAAAAGmdldF90YXNrX2Z1bmRpbmdfYnJlYWtkb3duAAAAAAABAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAH0AAAABBGdW5kaW5nQnJlYWtkb3du",
+        "AAAAAAAAATxHZXQgdGhlIGFtb3VudCBoZWxkIGluIGVzY3JvdyBmb3IgYSB0YXNrCgpTdW1tZWQgb3ZlciBhbGwgdGFza3MgdGhpcyBtYXRjaGVzIHRoZSBjb250cmFjdCdzIHRva2VuIGJhbGFuY2UgbGVzcyBhY2NydWVkIHBsYXRmb3JtCmZlZXMsIHdpdGggYW55IGVzY3JvdyBkZXBvc2l0ZWQgaW4gdGhlIHlpZWxkIHZhdWx0IGhlbGQgdGhlcmUgaW5zdGVhZC4KCiMgQXJndW1lbnRzCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sKCiMgUmV0dXJucwpUaGUgdGFzaydzIGZ1bmRpbmcgbWludXMgZXZlcnl0aGluZyBhbHJlYWR5IHBhaWQgb3V0IG9yIHJlZnVuZGVkAAAAD2dldF90YXNrX2VzY3JvdwAAAAABAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAACw==",
+        "AAAAAAAAAVxDaGVjayB3aGV0aGVyIGEgdGFzayB3aXRoIHRoZSBnaXZlbiBpbnB1dHMgY291bGQgYmUgY3JlYXRlZCwgd2l0aG91dCBjcmVhdGluZyBpdAoKIyBBcmd1bWVudHMKKiBgdGl0bGVgIC0gUHJvcG9zZWQgdGFzayB0aXRsZQoqIGBkZXNjcmlwdGlvbmAgLSBQcm9wb3NlZCB0YXNrIGRlc2NyaXB0aW9uCiogYGZ1bmRpbmdfYW1vdW50YCAtIFByb3Bvc2VkIGZ1bmRpbmcgYW1vdW50CiogYGRlYWRsaW5lYCAtIFByb3Bvc2VkIGRlYWRsaW5lIHRpbWVzdGFtcAoKIyBSZXR1cm5zCk9rIGlmIGNyZWF0aW9uIHdvdWxkIHBhc3MgdmFsaWRhdGlvbiwgb3RoZXJ3aXNlIHRoZSByZWFzb24gaXQgd291bGQgYmUgcmVqZWN0ZWQAAAANdmFsaWRhdGVfdGFzawAAAAAAAAQAAAAAAAAABXRpdGxlAAAAAAAAEAAAAAAAAAALZGVzY3JpcHRpb24AAAAAEAAAAAAAAAAOZnVuZGluZ19hbW91bnQAAAAAAAsAAAAAAAAACGRlYWRsaW5lAAAABgAAAAEAAAPpAAAAAgAAB9AAAAAJVGFza0Vycm9yAAAA",
+        "AAAAAAAAAXBHZXQgdGhlIHRva2VuIGFtb3VudCBhIGNyZWF0b3IgbXVzdCBoYXZlIGF2YWlsYWJsZSB0byBmdW5kIGEgbmV3IHRhc2sKCkZlZXMgYXJlIHF1b3RlZCBhdCB0aGUgZGVmYXVsdCBwbGF0Zm9ybSByYXRlLCByYWlzZWQgdG8gdGhlIG1pbmltdW0gZmVlLgoKIyBBcmd1bWVudHMKKiBgZnVuZGluZ19hbW91bnRgIC0gQW1vdW50IHRoZSB0YXNrIGlzIHRvIGJlIGZ1bmRlZCB3aXRoCiogYGZlZV9wYXllcmAgLSBXaGV0aGVyIHRoZSBhc3NpZ25lZSBvciB0aGUgY3JlYXRvciBiZWFycyB0aGUgcGxhdGZvcm0gZmVlCgojIFJldHVybnMKVGhlIGZ1bmRpbmcgYW1vdW50LCBwbHVzIHRoZSBwbGF0Zm9ybSBmZWUgaWYgdGhlIGNyZWF0b3IgYWJzb3JicyBpdAAAABByZXF1aXJlZF9mdW5kaW5nAAAAAgAAAAAAAAAOZnVuZGluZ19hbW91bnQAAAAAAAsAAAAAAAAACWZlZV9wYXllcgAAAAAAB9AAAAAIRmVlUGF5ZXIAAAABAAAACw==",
+        "AAAAAAAAAQpQcmV2aWV3IGEgdGFzaydzIHBheW91dCBzcGxpdCB1bmRlciBhIGh5cG90aGV0aWNhbCBwbGF0Zm9ybSBmZWUgcmF0ZQoKIyBBcmd1bWVudHMKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayB0byBwcmV2aWV3CiogYGh5cG90aGV0aWNhbF9mZWVfYnBzYCAtIEZlZSByYXRlIGluIGJhc2lzIHBvaW50cyAoMzAwID0gMyUpCgojIFJldHVybnMKVHVwbGUgb2YgKGFzc2lnbmVlIHBheW91dCwgcGxhdGZvcm0gZmVlKSBmb3IgdGhlIGZ1bmRzIHN0aWxsIGluIGVzY3JvdwAAAAAAGHByZXZpZXdfcmVsZWFzZV93aXRoX2ZlZQAAAAIAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAAAAAAUaHlwb3RoZXRpY2FsX2ZlZV9icHMAAAAEAAAAAQAAA+0AAAACAAAACwAAAAs=",
         "AAAAAAAAAIJDYW5jZWwgYSB0YXNrIGFuZCByZWZ1bmQgdGhlIGNyZWF0b3IKCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gY2FuY2VsAAAAAAALY2FuY2VsX3Rhc2sAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAA",
+        "AAAAAAAAAOdDYW5jZWwgYSBjcmVhdG9yJ3MgYXNzaWduZWQgYW5kIGluLXByb2dyZXNzIHRhc2tzLCByZWZ1bmRpbmcgZWFjaAoKQ2FuY2VscyBhdCBtb3N0IDUwIHRhc2tzIHBlciBjYWxsOyBjYWxsIGFnYWluIHRvIGNvbnRpbnVlIHdpdGggdGhlIHJlc3QuCgojIEFyZ3VtZW50cwoqIGBjcmVhdG9yYCAtIEFkZHJlc3Mgb2YgdGhlIHRhc2sgY3JlYXRvcgoKIyBSZXR1cm5zCk51bWJlciBvZiB0YXNrcyBjYW5jZWxsZWQAAAAACmNhbmNlbF9hbGwAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAAE",
+        "AAAAAAAAATJDYW5jZWwgYSB0YXNrIGFuZCByZS1wb3N0IGl0IHdpdGggbmV3IHBhcmFtZXRlcnMsIG5ldHRpbmcgdGhlIHJlZnVuZCBhZ2FpbnN0IHRoZSBuZXcgZnVuZGluZwoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayB0byBjYW5jZWwKKiBgcGFyYW1zYCAtIFRpdGxlLCBkZXNjcmlwdGlvbiwgbGluaywgZnVuZGluZywgZGVhZGxpbmUgYW5kIGFzc2lnbmVlIG9mIHRoZSBuZXcgdGFzawoKIyBSZXR1cm5zClRoZSBJRCBvZiB0aGUgbmV3IHRhc2sAAAAAAAtyZWxpc3RfdGFzawAAAAADAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAAAAAAGcGFyYW1zAAAAAAfQAAAADFJlbGlzdFBhcmFtcwAAAAEAAAAG",
+        "AAAAAAAAAKBHaXZlIHRoZSBhc3NpZ25lZSBub3RpY2UgdGhhdCB0aGUgY3JlYXRvciBpbnRlbmRzIHRvIGNhbmNlbCBhIHRhc2sKCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gY2FuY2VsAAAADnJlcXVlc3RfY2FuY2VsAAAAAAACAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAKRDYW5jZWwgYSB0YXNrIGFuZCByZWZ1bmQgdGhlIGNyZWF0b3Igb25jZSB0aGUgbm90aWNlIHBlcmlvZCBoYXMgcGFzc2VkCgojIEFyZ3VtZW50cwoqIGBjcmVhdG9yYCAtIEFkZHJlc3Mgb2YgdGhlIHRhc2sgY3JlYXRvcgoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrIHRvIGNhbmNlbAAAAA9maW5hbGl6ZV9jYW5jZWwAAAAAAgAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAA",
+        "AAAAAAAAAJhHaXZlIHVwIGFuIGFzc2lnbmVkIHRhc2sgYW5kIHJlZnVuZCB0aGUgY3JlYXRvciBpbiBmdWxsCgojIEFyZ3VtZW50cwoqIGBhc3NpZ25lZWAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGFzc2lnbmVlCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gYWJhbmRvbgAAAA9hc3NpZ25lZV9jYW5jZWwAAAAAAgAAAAAAAAAIYXNzaWduZWUAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAA",
         "AAAAAAAAAFhIYW5kbGUgZXhwaXJlZCB0YXNrcyAtIG1hcmsgYXMgZXhwaXJlZAoKIyBBcmd1bWVudHMKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgZXhwaXJlZCB0YXNrAAAADG1hcmtfZXhwaXJlZAAAAAEAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
-        "AAAAAAAAAHtSZWNsYWltIGZ1bmRzIGZyb20gZXhwaXJlZCB0YXNrCgojIEFyZ3VtZW50cwoqIGBjcmVhdG9yYCAtIEFkZHJlc3Mgb2YgdGhlIHRhc2sgY3JlYXRvcgoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSBleHBpcmVkIHRhc2sAAAAAFXJlY2xhaW1fZXhwaXJlZF9mdW5kcwAAAAAAAAIAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAA==",
-        "AAAAAAAAAHhXaXRoZHJhdyBhY2N1bXVsYXRlZCBwbGF0Zm9ybSBmZWVzIChvbmx5IGRlcGxveWVyIGNhbiBjYWxsKQoKIyBBcmd1bWVudHMKKiBgZGVwbG95ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29udHJhY3QgZGVwbG95ZXIAAAAWd2l0aGRyYXdfcGxhdGZvcm1fZmVlcwAAAAAAAQAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAA==",
+        "AAAAAAAAAMFNYXJrIGEgdGFzayBhcyBleHBpcmVkIG9uIGJlaGFsZiBvZiBhbiBpZGVudGlmaWVkIGNhbGxlcgoKIyBBcmd1bWVudHMKKiBgY2FsbGVyYCAtIEFkZHJlc3MgbWFya2luZyB0aGUgdGFzazsgbXVzdCBiZSBhIHBhcnRpY2lwYW50IHdoZW4gZXhwaXJ5IGlzIHJlc3RyaWN0ZWQKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgZXhwaXJlZCB0YXNrAAAAAAAAD21hcmtfZXhwaXJlZF9ieQAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
+        "AAAAAAAAARRMYXBzZSBhbiBhc3NpZ25tZW50IHRoZSBhc3NpZ25lZSBkaWQgbm90IHN0YXJ0IHdpdGhpbiB0aGUgYWNjZXB0YW5jZSB3aW5kb3cKClRoZSB0YXNrIGlzIGV4cGlyZWQgYW5kIGl0cyBleHBpcnkgYWN0aW9uIGNhcnJpZWQgb3V0LCBzbyBhIHJlZnVuZGluZyB0YXNrIGNhbiBiZQpyZWNsYWltZWQgb3IgcmVhc3NpZ25lZCBieSB0aGUgY3JlYXRvciBiZWZvcmUgdGhlIHRhc2sgZGVhZGxpbmUuCgojIEFyZ3VtZW50cwoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB1bmFjY2VwdGVkIHRhc2sAAAAPbWFya191bmFjY2VwdGVkAAAAAAEAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAMxMYXBzZSBhbiB1bmFjY2VwdGVkIGFzc2lnbm1lbnQgb24gYmVoYWxmIG9mIGFuIGlkZW50aWZpZWQgY2FsbGVyCgojIEFyZ3VtZW50cwoqIGBjYWxsZXJgIC0gQWRkcmVzcyBsYXBzaW5nIHRoZSB0YXNrOyBtdXN0IGJlIGEgcGFydGljaXBhbnQgd2hlbiBleHBpcnkgaXMgcmVzdHJpY3RlZAoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB1bmFjY2VwdGVkIHRhc2sAAAASbWFya191bmFjY2VwdGVkX2J5AAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAOdNYXJrIHNldmVyYWwgb3ZlcmR1ZSB0YXNrcyBhcyBleHBpcmVkIGluIG9uZSBjYWxsLCBza2lwcGluZyBhbnkgdGhhdCBhcmUgbm90IGVsaWdpYmxlCgojIEFyZ3VtZW50cwoqIGB0YXNrX2lkc2AgLSBJRHMgb2YgdGhlIHRhc2tzIHRvIGV4cGlyZSAoYXQgbW9zdCBgTUFYX0tFRVBFUl9CQVRDSGApCgojIFJldHVybnMKSURzIG9mIHRoZSB0YXNrcyB0aGF0IHdlcmUgYWN0dWFsbHkgbWFya2VkIGV4cGlyZWQAAAAAEm1hcmtfZXhwaXJlZF9iYXRjaAAAAAAAAQAAAAAAAAAIdGFza19pZHMAAAPqAAAABgAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAUhSZWNsYWltIGZ1bmRzIGZyb20gZXhwaXJlZCB0YXNrCgpBbnlvbmUgbWF5IHJlY2xhaW0gYSB0YXNrIG9uY2UgaXQgaXMgbWFya2VkIGV4cGlyZWQsIG9yIGFuIG92ZXJkdWUgdGFzayB0aGF0IHdhcyBuZXZlcgpzdGFydGVkIHdoaWxlIGV4cGlyeSBpcyB1bnJlc3RyaWN0ZWQ7IG90aGVyd2lzZSB0aGUgY3JlYXRvciBtdXN0IGF1dGhvcml6ZSB0aGUgcmVjbGFpbS4KU2luY2UgdGhlIGNhbGxlciBtYXkgYmUgYW55b25lLCB0aGUgcmVmdW5kIGFsd2F5cyBnb2VzIHRvIHRoZSBjcmVhdG9yLgoKIyBBcmd1bWVudHMKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgZXhwaXJlZCB0YXNrAAAAFXJlY2xhaW1fZXhwaXJlZF9mdW5kcwAAAAAAAAEAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAO9XaXRoZHJhdyBhY2N1bXVsYXRlZCBwbGF0Zm9ybSBmZWVzIChvbmx5IGRlcGxveWVyIGNhbiBjYWxsKQoKV2l0aCBtdWx0aS1zaWcgY29uZmlndXJlZCwgc2lnbmVycyBtdXN0IGhhdmUgYXBwcm92ZWQgYSByZXF1ZXN0IHRvIHBheSB0aGUgd2hvbGUKYWNjdW11bGF0ZWQgYmFsYW5jZSB0byB0aGUgZGVwbG95ZXIuCgojIEFyZ3VtZW50cwoqIGBkZXBsb3llcmAgLSBBZGRyZXNzIG9mIHRoZSBjb250cmFjdCBkZXBsb3llcgAAAAAWd2l0aGRyYXdfcGxhdGZvcm1fZmVlcwAAAAAAAQAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAA==",
+        "AAAAAAAAAYBXaXRoZHJhdyBwYXJ0IG9mIHRoZSBhY2N1bXVsYXRlZCBwbGF0Zm9ybSBmZWVzIHRvIGEgY2hvc2VuIHJlY2lwaWVudAoKV2l0aCBtdWx0aS1zaWcgY29uZmlndXJlZCwgc2lnbmVycyBtdXN0IGhhdmUgYXBwcm92ZWQgYSByZXF1ZXN0IGZvciB0aGlzIHJlY2lwaWVudAphbmQgYW1vdW50LCBzbyBhcHByb3ZhbHMgZ2l2ZW4gZm9yIG9uZSB3aXRoZHJhd2FsIGNhbm5vdCBiZSBzcGVudCBvbiBhbm90aGVyLgoKIyBBcmd1bWVudHMKKiBgZGVwbG95ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29udHJhY3QgZGVwbG95ZXIKKiBgcmVjaXBpZW50YCAtIEFkZHJlc3MgcmVjZWl2aW5nIHRoZSB3aXRoZHJhd24gZmVlcwoqIGBhbW91bnRgIC0gQW1vdW50IG9mIGZlZXMgdG8gd2l0aGRyYXcAAAAZd2l0aGRyYXdfcGxhdGZvcm1fZmVlc190bwAAAAAAAAMAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAJcmVjaXBpZW50AAAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAA==",
+        "AAAAAAAAANhSZWNvdmVyIHRva2VucyBoZWxkIG91dHNpZGUgb2YgZXNjcm93IGFuZCBmZWUgYWNjb3VudGluZyAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYGFtb3VudGAgLSBBbW91bnQgdG8gcmVjb3ZlciwgYXQgbW9zdCB0aGUgYmFsYW5jZSBhYm92ZSBhbGwgb2JsaWdhdGlvbnMAAAASZW1lcmdlbmN5X3dpdGhkcmF3AAAAAAACAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAA=",
+        "AAAAAAAAAT1Qcm9wb3NlIGEgZmVlIG91dGZsb3cgZm9yIHRoZSBzaWduZXJzIHRvIGFwcHJvdmUgKG9ubHkgZGVwbG95ZXIgY2FuIGNhbGwpCgpUaGUgb3V0ZmxvdyBjYW4gb25seSBiZSBjYXJyaWVkIG91dCBvbmNlIGVub3VnaCBzaWduZXJzIGFwcHJvdmUgdGhpcyBleGFjdCByZXF1ZXN0LgoKIyBBcmd1bWVudHMKKiBgZGVwbG95ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29udHJhY3QgZGVwbG95ZXIKKiBgYWN0aW9uYCAtIFdpdGhkcmF3YWwgb3IgdHJlYXN1cnkgY2hhbmdlIHRvIGFwcHJvdmUKCiMgUmV0dXJucwpJRCBvZiB0aGUgbmV3IHdpdGhkcmF3YWwgcmVxdWVzdAAAAAAAABJwcm9wb3NlX3dpdGhkcmF3YWwAAAAAAAIAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAGYWN0aW9uAAAAAAfQAAAAEFdpdGhkcmF3YWxBY3Rpb24AAAABAAAABg==",
+        "AAAAAAAAALFBcHByb3ZlIGEgcGVuZGluZyBmZWUgd2l0aGRyYXdhbCByZXF1ZXN0IGFzIG9uZSBvZiB0aGUgY29uZmlndXJlZCBzaWduZXJzCgojIEFyZ3VtZW50cwoqIGBzaWduZXJgIC0gQWRkcmVzcyBvZiB0aGUgYXBwcm92aW5nIHNpZ25lcgoqIGByZXF1ZXN0X2lkYCAtIElEIG9mIHRoZSB3aXRoZHJhd2FsIHJlcXVlc3QAAAAAAAASYXBwcm92ZV93aXRoZHJhd2FsAAAAAAACAAAAAAAAAAZzaWduZXIAAAAAABMAAAAAAAAACnJlcXVlc3RfaWQAAAAAAAYAAAAA",
+        "AAAAAAAAAKlEcm9wIGEgcGVuZGluZyBmZWUgd2l0aGRyYXdhbCByZXF1ZXN0IChvbmx5IGRlcGxveWVyIGNhbiBjYWxsKQoKIyBBcmd1bWVudHMKKiBgZGVwbG95ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29udHJhY3QgZGVwbG95ZXIKKiBgcmVxdWVzdF9pZGAgLSBJRCBvZiB0aGUgd2l0aGRyYXdhbCByZXF1ZXN0AAAAAAAAEWNhbmNlbF93aXRoZHJhd2FsAAAAAAAAAgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAApyZXF1ZXN0X2lkAAAAAAAGAAAAAA==",
+        "AAAAAAAAAMBHZXQgYSBwZW5kaW5nIGZlZSB3aXRoZHJhd2FsIHJlcXVlc3QKCiMgQXJndW1lbnRzCiogYHJlcXVlc3RfaWRgIC0gSUQgb2YgdGhlIHdpdGhkcmF3YWwgcmVxdWVzdAoKIyBSZXR1cm5zClRoZSByZXF1ZXN0IGFuZCBpdHMgYXBwcm92YWxzLCBvciBOb25lIGlmIGl0IHdhcyBjYXJyaWVkIG91dCwgY2FuY2VsbGVkIG9yIG5ldmVyIG1hZGUAAAAWZ2V0X3dpdGhkcmF3YWxfcmVxdWVzdAAAAAAAAQAAAAAAAAAKcmVxdWVzdF9pZAAAAAAABgAAAAEAAAPoAAAH0AAAABFXaXRoZHJhd2FsUmVxdWVzdAAAAA==",
+        "AAAAAAAAALJGbGFnIGEgdGFzayBhcyBkaXNwdXRlZCwgZnJlZXppbmcgaXRzIGZ1bmRzIHVudGlsIGFuIGFyYml0ZXIgcmVzb2x2ZXMgaXQKCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3Igb3IgYXNzaWduZWUKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayB0byBkaXNwdXRlAAAAAAANcmFpc2VfZGlzcHV0ZQAAAAAAAAIAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAA==",
+        "AAAAAAAAAOJGb3JjZS1yZXNvbHZlIGEgc3R1Y2sgdGFzayAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gcmVzb2x2ZQoqIGBvdXRjb21lYCAtIFBheSB0aGUgYXNzaWduZWUsIHJlZnVuZCB0aGUgY3JlYXRvciwgb3Igc3BsaXQgYmV0d2VlbiB0aGVtAAAAAAANYWRtaW5fcmVzb2x2ZQAAAAAAAAMAAAAAAAAACGRlcGxveWVyAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAAAAAAdvdXRjb21lAAAAB9AAAAAMQWRtaW5PdXRjb21lAAAAAA==",
+        "AAAAAAAAARVTZXR0bGUgYSBkaXNwdXRlZCB0YXNrIGluIGZhdm9yIG9mIHRoZSBhc3NpZ25lZSBvciB0aGUgY3JlYXRvciAob25seSBkZXBsb3llciBjYW4gY2FsbCkKCiMgQXJndW1lbnRzCiogYGRlcGxveWVyYCAtIEFkZHJlc3Mgb2YgdGhlIGNvbnRyYWN0IGRlcGxveWVyCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIGRpc3B1dGVkIHRhc2sKKiBgcGF5X2Fzc2lnbmVlYCAtIFRydWUgdG8gcmVsZWFzZSB0aGUgZXNjcm93IHRvIHRoZSBhc3NpZ25lZSwgZmFsc2UgdG8gcmVmdW5kIHRoZSBjcmVhdG9yAAAAAAAAD3Jlc29sdmVfZGlzcHV0ZQAAAAADAAAAAAAAAAhkZXBsb3llcgAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAAAAAAMcGF5X2Fzc2lnbmVlAAAAAQAAAAA=",
+        "AAAAAAAAANdTZXQgd2hldGhlciByZXNvbHZpbmcgYSBkaXNwdXRlIGluIHRoZSBhc3NpZ25lZSdzIGZhdm9yIHRha2VzIGEgcGxhdGZvcm0gZmVlCihvbmx5IGRlcGxveWVyIGNhbiBjYWxsKQoKIyBBcmd1bWVudHMKKiBgZGVwbG95ZXJgIC0gQWRkcmVzcyBvZiB0aGUgY29udHJhY3QgZGVwbG95ZXIKKiBgcG9saWN5YCAtIEZlZSBwb2xpY3kgYXBwbGllZCBieSBgcmVzb2x2ZV9kaXNwdXRlYAAAAAAWc2V0X2Rpc3B1dGVfZmVlX3BvbGljeQAAAAAAAgAAAAAAAAAIZGVwbG95ZXIAAAATAAAAAAAAAAZwb2xpY3kAAAAAB9AAAAAQRGlzcHV0ZUZlZVBvbGljeQAAAAA=",
+        "AAAAAAAAAG9HZXQgdGhlIGZlZSBwb2xpY3kgYXBwbGllZCB3aGVuIHJlc29sdmluZyBkaXNwdXRlcwoKIyBSZXR1cm5zClRoZSBjb25maWd1cmVkIHBvbGljeSwgYE5vRmVlT25SZWZ1bmRgIGJ5IGRlZmF1bHQAAAAAFmdldF9kaXNwdXRlX2ZlZV9wb2xpY3kAAAAAAAAAAAABAAAH0AAAABBEaXNwdXRlRmVlUG9saWN5",
         "AAAAAAAAAF5HZXQgY3VycmVudCBhY2N1bXVsYXRlZCBwbGF0Zm9ybSBmZWVzCgojIFJldHVybnMKVGhlIHRvdGFsIGFtb3VudCBvZiBhY2N1bXVsYXRlZCBwbGF0Zm9ybSBmZWVzAAAAAAARZ2V0X3BsYXRmb3JtX2ZlZXMAAAAAAAAAAAAAAQAAAAs=",
+        "AAAAAAAAAKJDaGVjayB3aGV0aGVyIHRoZSBkZXBsb3llciBjb3VsZCB3aXRoZHJhdyBwbGF0Zm9ybSBmZWVzIHJpZ2h0IG5vdwoKIyBSZXR1cm5zClRydWUgaWYgZmVlcyBoYXZlIGFjY3J1ZWQgYW5kIHRoZSBjb250cmFjdCBob2xkcyBlbm91Z2ggdG8gcGF5IHRoZW0gb3V0IHdoaWxlIHNvbHZlbnQAAAAAABVoYXNfd2l0aGRyYXdhYmxlX2ZlZXMAAAAAAAAAAAAAAQAAAAE=",
+        "AAAAAAAAAQxDb21wYXJlIHRoZSBjb250cmFjdCdzIHRva2VuIGJhbGFuY2UgYWdhaW5zdCB3aGF0IGl0IG93ZXMKCkVzY3JvdyBvd2VkIGlzIHJlYWQgZnJvbSB0aGUgcnVubmluZyBsZWRnZXIgdG90YWxzIHJhdGhlciB0aGFuIHN1bW1lZCBvdmVyIGV2ZXJ5IHRhc2suCgojIFJldHVybnMKVHVwbGUgb2YgKHRva2VuIGJhbGFuY2UgaGVsZCwgZXNjcm93ZWQgZnVuZHMgcGx1cyB1bndpdGhkcmF3biBmZWVzLAp3aGV0aGVyIHRoZSBiYWxhbmNlIGNvdmVycyB0aGUgb2JsaWdhdGlvbnMpAAAADmNoZWNrX3NvbHZlbmN5AAAAAAAAAAAAAQAAA+0AAAADAAAACwAAAAsAAAAB",
+        "AAAAAAAAAHlHZXQgY29udHJhY3Qtd2lkZSB0b3RhbHMgZm9yIGFuYWx5dGljcwoKIyBSZXR1cm5zClRhc2sgY291bnRzLCBlc2Nyb3dlZCB2YWx1ZSBhbmQgZmVlIHRvdGFscyBhY2N1bXVsYXRlZCBzaW5jZSBkZXBsb3ltZW50AAAAAAAACWdldF9zdGF0cwAAAAAAAAAAAAABAAAH0AAAAAVTdGF0cwAAAA==",
+        "AAAAAAAAAXVHZXQgdGhlIHJ1bm5pbmcgdG90YWxzIG5lZWRlZCB0byByZWNvbmNpbGUgdGhlIGNvbnRyYWN0J3MgYm9va3MKClRvdGFsIGVzY3Jvd2VkIGFsd2F5cyBlcXVhbHMgcGFpZCBwbHVzIHJlZnVuZGVkIHBsdXMgZmVlcyBlYXJuZWQgcGx1cyB0aGUgZXNjcm93IHN0aWxsCmxvY2tlZCBpbiBvcGVuIHRhc2tzLiBGZWVzIGNoYXJnZWQgaW4gYSBzZXBhcmF0ZSBmZWUgdG9rZW4gbmV2ZXIgY29tZSBvdXQgb2YgZXNjcm93LCBzbwp0aGV5IGFyZSByZXBvcnRlZCBvbiB0aGVpciBvd24gYW5kIGxlZnQgb3V0IG9mIHRoYXQgc3VtLgoKIyBSZXR1cm5zCkN1bXVsYXRpdmUgZXNjcm93LCBwYXlvdXQsIHJlZnVuZCBhbmQgZmVlIHRvdGFscyBzaW5jZSBkZXBsb3ltZW50AAAAAAAAEmdldF9sZWRnZXJfc3VtbWFyeQAAAAAAAAAAAAEAAAfQAAAADUxlZGdlclN1bW1hcnkAAAA=",
+        "AAAAAAAAAJpHZXQgdGhlIHRvdGFscyBuZWVkZWQgdG8gY29tcHV0ZSB0aGUgcGxhdGZvcm0ncyByZWFsaXplZCB0YWtlIHJhdGUKCiMgUmV0dXJucwpDdW11bGF0aXZlIHJlbGVhc2VkIHZvbHVtZSAoZmVlcyBpbmNsdWRlZCkgYW5kIHRoZSBwbGF0Zm9ybSBmZWVzIHRha2VuIG9uIGl0AAAAAAANZ2V0X3Rha2VfcmF0ZQAAAAAAAAAAAAABAAAD7QAAAAIAAAALAAAACw==",
         "AAAAAAAAALlSZWFzc2lnbiBhbiBleHBpcmVkIHRhc2sgdG8gYSBuZXcgYXNzaWduZWUKCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sgdG8gcmVhc3NpZ24KKiBgbmV3X2Fzc2lnbmVlYCAtIEFkZHJlc3Mgb2YgdGhlIG5ldyBhc3NpZ25lZQAAAAAAAA1yZWFzc2lnbl90YXNrAAAAAAAAAwAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAAAAAAADG5ld19hc3NpZ25lZQAAABMAAAAA",
+        "AAAAAAAAAc5SZWFzc2lnbiBhbGwgb2YgYSBjcmVhdG9yJ3MgZXhwaXJlZCB0YXNrcyBoZWxkIGJ5IG9uZSBhc3NpZ25lZSB0byBhbm90aGVyCgpSZWFzc2lnbnMgYXQgbW9zdCBgTUFYX1JFQVNTSUdOX0JBVENIYCB0YXNrcyBwZXIgY2FsbDsgY2FsbCBhZ2FpbiB0byBjb250aW51ZSB3aXRoCnRoZSByZXN0LgoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgb2xkX2Fzc2lnbmVlYCAtIEFzc2lnbmVlIHdob3NlIGV4cGlyZWQgdGFza3MgYXJlIGhhbmRlZCBvdmVyCiogYG5ld19hc3NpZ25lZWAgLSBBZGRyZXNzIG9mIHRoZSBuZXcgYXNzaWduZWUKKiBgbmV3X2RlYWRsaW5lYCAtIFVuaXggdGltZXN0YW1wIGZvciB0aGUgbmV3IGRlYWRsaW5lIG9mIGV2ZXJ5IHJlYXNzaWduZWQgdGFzawoKIyBSZXR1cm5zCklEcyBvZiB0aGUgdGFza3MgdGhhdCB3ZXJlIHJlYXNzaWduZWQAAAAAABRyZWFzc2lnbl9hbGxfZXhwaXJlZAAAAAQAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAMb2xkX2Fzc2lnbmVlAAAAEwAAAAAAAAAMbmV3X2Fzc2lnbmVlAAAAEwAAAAAAAAAMbmV3X2RlYWRsaW5lAAAABgAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAQZSZWFzc2lnbiBhbiBleHBpcmVkIHRhc2sgYW5kIGdpdmUgaXQgYSBuZXcgZGVhZGxpbmUgaW4gb25lIHN0ZXAKCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIGV4cGlyZWQgdGFzawoqIGBuZXdfYXNzaWduZWVgIC0gQWRkcmVzcyBvZiB0aGUgbmV3IGFzc2lnbmVlCiogYG5ld19kZWFkbGluZWAgLSBVbml4IHRpbWVzdGFtcCBmb3IgdGhlIG5ldyB0YXNrIGRlYWRsaW5lAAAAAAATcmVhc3NpZ25fYW5kX2V4dGVuZAAAAAAEAAAAAAAAAAdjcmVhdG9yAAAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAAAAAAMbmV3X2Fzc2lnbmVlAAAAEwAAAAAAAAAMbmV3X2RlYWRsaW5lAAAABgAAAAA=",
+        "AAAAAAAAAO5SZXR1cm4gYW4gZXhwaXJlZCB0YXNrIHRvIHRoZSBvcGVuIHBvb2wgd2l0aCBhIG5ldyBkZWFkbGluZSBzbyBhbnkgd29ya2VyIGNhbiBjbGFpbSBpdAoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgZXhwaXJlZCB0YXNrCiogYG5ld19kZWFkbGluZWAgLSBVbml4IHRpbWVzdGFtcCBmb3IgdGhlIG5ldyB0YXNrIGRlYWRsaW5lAAAAAAAOcmVvcGVuX3RvX3Bvb2wAAAAAAAMAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAAAAAAxuZXdfZGVhZGxpbmUAAAAGAAAAAA==",
+        "AAAAAAAAAJNDbGFpbSBhIHRhc2sgZnJvbSB0aGUgb3BlbiBwb29sIGFzIGl0cyBhc3NpZ25lZQoKIyBBcmd1bWVudHMKKiBgd29ya2VyYCAtIEFkZHJlc3Mgb2YgdGhlIHdvcmtlciB0YWtpbmcgdGhlIHRhc2sKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgcG9vbGVkIHRhc2sAAAAACmNsYWltX3Rhc2sAAAAAAAIAAAAAAAAABndvcmtlcgAAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAA==",
+        "AAAAAAAAAORIYW5kIHRoZSBjcmVhdG9yIHJvbGUgYW5kIGl0cyByZWZ1bmQgcmlnaHRzIG92ZXIgdG8gYW5vdGhlciBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBjdXJyZW50X2NyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgY3VycmVudCB0YXNrIGNyZWF0b3IKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzayB0byB0cmFuc2ZlcgoqIGBuZXdfY3JlYXRvcmAgLSBBZGRyZXNzIHRha2luZyBvdmVyIGFzIGNyZWF0b3IAAAAUdHJhbnNmZXJfY3JlYXRvcnNoaXAAAAADAAAAAAAAAA9jdXJyZW50X2NyZWF0b3IAAAAAEwAAAAAAAAAHdGFza19pZAAAAAAGAAAAAAAAAAtuZXdfY3JlYXRvcgAAAAATAAAAAA==",
         "AAAAAAAAAGhHZXQgdGFzayBkZXRhaWxzIGJ5IElECgojIEFyZ3VtZW50cwoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrIHRvIHJldHJpZXZlCgojIFJldHVybnMKVGhlIHRhc2sgZGV0YWlscwAAAAhnZXRfdGFzawAAAAEAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAEAAAfQAAAABFRhc2s=",
-        "AAAAAAAAAH1HZXQgYWxsIHRhc2tzIGNyZWF0ZWQgYnkgYSB1c2VyCgojIEFyZ3VtZW50cwoqIGB1c2VyYCAtIEFkZHJlc3Mgb2YgdGhlIHVzZXIKCiMgUmV0dXJucwpWZWN0b3Igb2YgdGFzayBJRHMgY3JlYXRlZCBieSB0aGUgdXNlcgAAAAAAAA5nZXRfdXNlcl90YXNrcwAAAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAD6gAAAAY=",
-        "AAAAAAAAAH9HZXQgYWxsIHRhc2tzIGFzc2lnbmVkIHRvIGEgdXNlcgoKIyBBcmd1bWVudHMKKiBgdXNlcmAgLSBBZGRyZXNzIG9mIHRoZSB1c2VyCgojIFJldHVybnMKVmVjdG9yIG9mIHRhc2sgSURzIGFzc2lnbmVkIHRvIHRoZSB1c2VyAAAAABJnZXRfYXNzaWduZWRfdGFza3MAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+oAAAAG",
-        "AAAAAAAAABlHZXQgdG90YWwgbnVtYmVyIG9mIHRhc2tzAAAAAAAADmdldF90YXNrX2NvdW50AAAAAAAAAAAAAQAAAAY=" ]),
+        "AAAAAAAAAGRHZXQgdGhlIGNyZWF0b3Igb2YgYSB0YXNrCgojIEFyZ3VtZW50cwoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrCgojIFJldHVybnMKVGhlIGNyZWF0b3IncyBhZGRyZXNzAAAAEGdldF90YXNrX2NyZWF0b3IAAAABAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAAEw==",
+        "AAAAAAAAAItHZXQgdGhlIGFzc2lnbmVlIG9mIGEgdGFzawoKIyBBcmd1bWVudHMKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzawoKIyBSZXR1cm5zClRoZSBhc3NpZ25lZSdzIGFkZHJlc3MsIG9yIGBOb25lYCBpZiB0aGUgdGFzayBpcyB1bmFzc2lnbmVkAAAAABFnZXRfdGFza19hc3NpZ25lZQAAAAAAAAEAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAKNHZXQgdGhlIGRlY2ltYWxzIG9mIHRoZSB0b2tlbiBhIHRhc2sgaXMgZnVuZGVkIGluCgojIEFyZ3VtZW50cwoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSB0YXNrCgojIFJldHVybnMKTnVtYmVyIG9mIGRlY2ltYWxzIHVzZWQgdG8gZGlzcGxheSB0aGUgdGFzaydzIGZ1bmRpbmcgYW1vdW50AAAAABhnZXRfdGFza19hbW91bnRfZGVjaW1hbHMAAAABAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAABA==",
+        "AAAAAAAAAJBHZXQgdGhlIHRpbWUgbGVmdCB1bnRpbCBhIHRhc2sncyBkZWFkbGluZQoKIyBBcmd1bWVudHMKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzawoKIyBSZXR1cm5zClNlY29uZHMgdW50aWwgdGhlIGRlYWRsaW5lLCBvciAwIGlmIGl0IGhhcyBwYXNzZWQAAAASZ2V0X3JlbWFpbmluZ190aW1lAAAAAAABAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAABg==",
+        "AAAAAAAAAONSZXBvcnQgd2hpY2ggb2YgYSB0YXNrJ3Mgc2VydmljZS1sZXZlbCB3aW5kb3dzIGhhdmUgYmVlbiBicmVhY2hlZAoKIyBBcmd1bWVudHMKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzawoKIyBSZXR1cm5zCkZsYWdzIGZvciBhIGxhcHNlZCBhY2NlcHRhbmNlIHdpbmRvdywgYSBtaXNzZWQgZGVhZGxpbmUsIGFuZCBhIHJlbGVhc2UgbGVmdCB3YWl0aW5nCnBhc3QgdGhlIHJlbGVhc2UgdGltZW91dAAAAAATZ2V0X3Rhc2tfc2xhX3N0YXR1cwAAAAABAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAH0AAAAAlTbGFTdGF0dXMAAAA=",
+        "AAAAAAAAAL9DaGVjayB3aGV0aGVyIHRoZSBjcmVhdG9yIGNvdWxkIHN0aWxsIGV4dGVuZCBhIHRhc2sncyBkZWFkbGluZQoKIyBBcmd1bWVudHMKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzawoKIyBSZXR1cm5zClRydWUgaWYgdGhlIHRhc2sgaXMgYXNzaWduZWQgb3IgaW4gcHJvZ3Jlc3MgYW5kIGl0cyBkZWFkbGluZSBoYXMgbm90IHBhc3NlZAAAAAATY2FuX2V4dGVuZF9kZWFkbGluZQAAAAABAAAAAAAAAAd0YXNrX2lkAAAAAAYAAAABAAAAAQ==",
+        "AAAAAAAAAK5HZXQgdGhlIGRlcGVuZGVuY2llcyBvZiBhIHRhc2sgdGhhdCBoYXZlIG5vdCBmaW5pc2hlZCB5ZXQKCiMgQXJndW1lbnRzCiogYHRhc2tfaWRgIC0gSUQgb2YgdGhlIHRhc2sKCiMgUmV0dXJucwpJRHMgb2YgZGVwZW5kZW5jaWVzIG5vdCBpbiBgQ29tcGxldGVkYCBvciBgRnVuZHNSZWxlYXNlZGAgc3RhdGUAAAAAABJnZXRfYmxvY2tpbmdfdGFza3MAAAAAAAEAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAEAAAPqAAAABg==",
+        "AAAAAAAAANhHZXQgYWxsIHRhc2tzIGNyZWF0ZWQgYnkgYSB1c2VyCgpWZXJ5IHByb2xpZmljIGNyZWF0b3JzIGNhbiBleGNlZWQgcmV0dXJuIHNpemUgbGltaXRzOyB1c2UgYGdldF91c2VyX3Rhc2tzX3BhZ2VkYCBpbnN0ZWFkLgoKIyBBcmd1bWVudHMKKiBgdXNlcmAgLSBBZGRyZXNzIG9mIHRoZSB1c2VyCgojIFJldHVybnMKVmVjdG9yIG9mIHRhc2sgSURzIGNyZWF0ZWQgYnkgdGhlIHVzZXIAAAAOZ2V0X3VzZXJfdGFza3MAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+oAAAAG",
+        "AAAAAAAAAQRHZXQgYSBwYWdlIG9mIHRoZSB0YXNrcyBjcmVhdGVkIGJ5IGEgdXNlcgoKIyBBcmd1bWVudHMKKiBgdXNlcmAgLSBBZGRyZXNzIG9mIHRoZSB1c2VyCiogYHN0YXJ0YCAtIE51bWJlciBvZiB0YXNrIElEcyB0byBza2lwCiogYGxpbWl0YCAtIE1heGltdW0gbnVtYmVyIG9mIHRhc2sgSURzIHRvIHJldHVybiAoY2FwcGVkIGF0IDUwKQoKIyBSZXR1cm5zClNsaWNlIG9mIHRoZSB0YXNrIElEcyBjcmVhdGVkIGJ5IHRoZSB1c2VyLCBpbiBjcmVhdGlvbiBvcmRlcgAAABRnZXRfdXNlcl90YXNrc19wYWdlZAAAAAMAAAAAAAAABHVzZXIAAAATAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAABg==",
+        "AAAAAAAAALxHZXQgYSBjcmVhdG9yJ3MgY29tcGxldGVkIHRhc2tzIHRoYXQgYXJlIHdhaXRpbmcgZm9yIGZ1bmQgcmVsZWFzZQoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3IKCiMgUmV0dXJucwpWZWN0b3Igb2YgSURzIG9mIHRoZSBjcmVhdG9yJ3MgdGFza3MgaW4gQ29tcGxldGVkIHN0YXR1cwAAABVnZXRfcGVuZGluZ19hcHByb3ZhbHMAAAAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAD6gAAAAY=",
+        "AAAAAAAAASdHZXQgYSBjcmVhdG9yJ3MgdGFza3MgaW4gYSBnaXZlbiBzdGF0dXMKCiMgQXJndW1lbnRzCiogYGNyZWF0b3JgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBjcmVhdG9yCiogYHN0YXR1c2AgLSBTdGF0dXMgdG8gZmlsdGVyIGJ5CiogYHN0YXJ0YCAtIE51bWJlciBvZiBtYXRjaGluZyB0YXNrcyB0byBza2lwCiogYGxpbWl0YCAtIE1heGltdW0gbnVtYmVyIG9mIHRhc2tzIHRvIHJldHVybiAoY2FwcGVkIGF0IDUwKQoKIyBSZXR1cm5zClRoZSBjcmVhdG9yJ3MgdGFza3MgaW4gYHN0YXR1c2AsIGluIGNyZWF0aW9uIG9yZGVyAAAAABtnZXRfY3JlYXRvcl90YXNrc19ieV9zdGF0dXMAAAAABAAAAAAAAAAHY3JlYXRvcgAAAAATAAAAAAAAAAZzdGF0dXMAAAAAB9AAAAAKVGFza1N0YXR1cwAAAAAAAAAAAAVzdGFydAAAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAARUYXNr",
+        "AAAAAAAAAK9HZXQgdGhlIHBsYXRmb3JtIGZlZXMgdGFrZW4gZnJvbSBhIGNyZWF0b3IncyByZWxlYXNlZCB0YXNrcwoKIyBBcmd1bWVudHMKKiBgY3JlYXRvcmAgLSBBZGRyZXNzIG9mIHRoZSBjcmVhdG9yCgojIFJldHVybnMKQ3VtdWxhdGl2ZSBwbGF0Zm9ybSBmZWVzIHBhaWQgb24gdGhlIGNyZWF0b3IncyBwYXlvdXRzAAAAABVnZXRfY3JlYXRvcl9mZWVzX3BhaWQAAAAAAAABAAAAAAAAAAdjcmVhdG9yAAAAABMAAAABAAAACw==",
+        "AAAAAAAAAKdHZXQgYSB1c2VyJ3MgbGlmZXRpbWUgcGF5bWVudCB0b3RhbHMKCiMgQXJndW1lbnRzCiogYHVzZXJgIC0gQWRkcmVzcyBvZiB0aGUgdXNlcgoKIyBSZXR1cm5zClR1cGxlIG9mIChlYXJuZWQgYXMgYXNzaWduZWUsIHNwZW50IGFzIGNyZWF0b3IsIGN1cnJlbnRseSBsb2NrZWQgaW4gZXNjcm93KQAAAAATZ2V0X3VzZXJfZmluYW5jaWFscwAAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAPtAAAAAwAAAAsAAAALAAAACw==",
+        "AAAAAAAAAK5SYXRlIHRoZSBjcmVhdG9yIG9mIGEgZmluaXNoZWQgdGFzawoKIyBBcmd1bWVudHMKKiBgYXNzaWduZWVgIC0gQWRkcmVzcyBvZiB0aGUgdGFzayBhc3NpZ25lZQoqIGB0YXNrX2lkYCAtIElEIG9mIHRoZSByZWxlYXNlZCBvciBjYW5jZWxsZWQgdGFzawoqIGBzY29yZWAgLSBSYXRpbmcgZnJvbSAxIHRvIDUAAAAAAAxyYXRlX2NyZWF0b3IAAAADAAAAAAAAAAhhc3NpZ25lZQAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAAAAAAFc2NvcmUAAAAAAAAEAAAAAA==",
+        "AAAAAAAAAKRHZXQgdGhlIGFnZ3JlZ2F0ZSByYXRpbmcgYXNzaWduZWVzIGhhdmUgZ2l2ZW4gYSBjcmVhdG9yCgojIEFyZ3VtZW50cwoqIGBjcmVhdG9yYCAtIEFkZHJlc3Mgb2YgdGhlIGNyZWF0b3IKCiMgUmV0dXJucwpUdXBsZSBvZiAoc3VtIG9mIGFsbCBzY29yZXMsIG51bWJlciBvZiByYXRpbmdzKQAAABJnZXRfY3JlYXRvcl9yYXRpbmcAAAAAAAEAAAAAAAAAB2NyZWF0b3IAAAAAEwAAAAEAAAPtAAAAAgAAAAYAAAAG",
+        "AAAAAAAAAJFHZXQgYSB1c2VyJ3MgdHJhY2sgcmVjb3JkIGFzIGFuIGFzc2lnbmVlCgojIEFyZ3VtZW50cwoqIGB1c2VyYCAtIEFkZHJlc3Mgb2YgdGhlIHVzZXIKCiMgUmV0dXJucwpDb3VudHMgb2YgdGhlIHVzZXIncyBwYWlkLW91dCBhbmQgYWJhbmRvbmVkIHRhc2tzAAAAAAAADmdldF9yZXB1dGF0aW9uAAAAAAABAAAAAAAAAAR1c2VyAAAAEwAAAAEAAAfQAAAAEkFzc2lnbmVlUmVwdXRhdGlvbgAA",
+        "AAAAAAAAANNHZXQgdGhlIHNoYXJlIG9mIGFuIGFzc2lnbmVlJ3MgZmluaXNoZWQgdGFza3MgdGhhdCB3ZXJlIHBhaWQgb3V0CgojIEFyZ3VtZW50cwoqIGBhc3NpZ25lZWAgLSBBZGRyZXNzIG9mIHRoZSBhc3NpZ25lZQoKIyBSZXR1cm5zClBlcmNlbnRhZ2UgKDAtMTAwKSBvZiBjb21wbGV0ZWQgdGFza3MsIG9yIGB1MzI6Ok1BWGAgaWYgdGhlIGFzc2lnbmVlIGhhcyBubyBoaXN0b3J5AAAAABBnZXRfc3VjY2Vzc19yYXRlAAAAAQAAAAAAAAAIYXNzaWduZWUAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAANtHZXQgYWxsIHRhc2tzIGFzc2lnbmVkIHRvIGEgdXNlcgoKVmVyeSBidXN5IGFzc2lnbmVlcyBjYW4gZXhjZWVkIHJldHVybiBzaXplIGxpbWl0czsgdXNlIGBnZXRfYXNzaWduZWRfdGFza3NfcGFnZWRgIGluc3RlYWQuCgojIEFyZ3VtZW50cwoqIGB1c2VyYCAtIEFkZHJlc3Mgb2YgdGhlIHVzZXIKCiMgUmV0dXJucwpWZWN0b3Igb2YgdGFzayBJRHMgYXNzaWduZWQgdG8gdGhlIHVzZXIAAAAAEmdldF9hc3NpZ25lZF90YXNrcwAAAAAAAQAAAAAAAAAEdXNlcgAAABMAAAABAAAD6gAAAAY=",
+        "AAAAAAAAAQhHZXQgYSBwYWdlIG9mIHRoZSB0YXNrcyBhc3NpZ25lZCB0byBhIHVzZXIKCiMgQXJndW1lbnRzCiogYHVzZXJgIC0gQWRkcmVzcyBvZiB0aGUgdXNlcgoqIGBzdGFydGAgLSBOdW1iZXIgb2YgdGFzayBJRHMgdG8gc2tpcAoqIGBsaW1pdGAgLSBNYXhpbXVtIG51bWJlciBvZiB0YXNrIElEcyB0byByZXR1cm4gKGNhcHBlZCBhdCA1MCkKCiMgUmV0dXJucwpTbGljZSBvZiB0aGUgdGFzayBJRHMgYXNzaWduZWQgdG8gdGhlIHVzZXIsIGluIGFzc2lnbm1lbnQgb3JkZXIAAAAYZ2V0X2Fzc2lnbmVkX3Rhc2tzX3BhZ2VkAAAAAwAAAAAAAAAEdXNlcgAAABMAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAAG",
+        "AAAAAAAAAPhHZXQgYW4gYXNzaWduZWUncyB0YXNrcyB3aGVyZSB0aGUgbmV4dCBhY3Rpb24gaXMgdGhlaXJzCgojIEFyZ3VtZW50cwoqIGBhc3NpZ25lZWAgLSBBZGRyZXNzIG9mIHRoZSBhc3NpZ25lZQoKIyBSZXR1cm5zCklEcyBvZiB0aGUgYXNzaWduZWUncyB0YXNrcyBzdGlsbCB0byBiZSBzdGFydGVkIChBc3NpZ25lZCkgb3IgY29tcGxldGVkIChJblByb2dyZXNzKSwKbGVhdmluZyBvdXQgYW55IGFscmVhZHkgcGFzdCB0aGVpciBkZWFkbGluZQAAABdnZXRfYXNzaWduZWVfYWN0aW9uYWJsZQAAAAABAAAAAAAAAAhhc3NpZ25lZQAAABMAAAABAAAD6gAAAAY=",
+        "AAAAAAAAAM5TdW1tYXJpemUgdGhlIHdvcmsgYW4gYXNzaWduZWUgY3VycmVudGx5IGhhcyBvbiB0aGVpciBwbGF0ZQoKIyBBcmd1bWVudHMKKiBgYXNzaWduZWVgIC0gQWRkcmVzcyBvZiB0aGUgYXNzaWduZWUKCiMgUmV0dXJucwpUdXBsZSBvZiAobnVtYmVyIG9mIGFzc2lnbmVkIG9yIGluLXByb2dyZXNzIHRhc2tzLCBmdW5kaW5nIHN0aWxsIGVzY3Jvd2VkIGZvciB0aGVtKQAAAAAAFWdldF9hc3NpZ25lZV93b3JrbG9hZAAAAAAAAAEAAAAAAAAACGFzc2lnbmVlAAAAEwAAAAEAAAPtAAAAAgAAAAYAAAAL",
+        "AAAAAAAAAN9HZXQgYWN0aXZlIHRhc2tzIGFzc2lnbmVkIHRvIGEgdXNlciBvcmRlcmVkIGZvciB0cmlhZ2UKCiMgQXJndW1lbnRzCiogYHVzZXJgIC0gQWRkcmVzcyBvZiB0aGUgYXNzaWduZWUKCiMgUmV0dXJucwpJRHMgb2YgdGhlIHVzZXIncyBhc3NpZ25lZCBvciBpbi1wcm9ncmVzcyB0YXNrcywgc29ydGVkIGJ5IGRlc2NlbmRpbmcKcHJpb3JpdHkgYW5kIHRoZW4gYnkgYXNjZW5kaW5nIGRlYWRsaW5lAAAAAB5nZXRfYXNzaWduZWRfdGFza3NfYnlfcHJpb3JpdHkAAAAAAAEAAAAAAAAABHVzZXIAAAATAAAAAQAAA+oAAAAG",
+        "AAAAAAAAAMtHZXQgYW4gYXNzaWduZWUncyBhY3RpdmUgdGFza3MgYXMgYSB3b3JrIHF1ZXVlLCBtb3N0IHVyZ2VudCBmaXJzdAoKIyBBcmd1bWVudHMKKiBgYXNzaWduZWVgIC0gQWRkcmVzcyBvZiB0aGUgYXNzaWduZWUKCiMgUmV0dXJucwpUaGUgYXNzaWduZWUncyBhc3NpZ25lZCBvciBpbi1wcm9ncmVzcyB0YXNrcywgc29ydGVkIGJ5IGFzY2VuZGluZyBkZWFkbGluZQAAAAASZ2V0X2Fzc2lnbmVlX3F1ZXVlAAAAAAABAAAAAAAAAAhhc3NpZ25lZQAAABMAAAABAAAD6gAAB9AAAAAEVGFzaw==",
+        "AAAAAAAAAaFHZXQgYWN0aXZlIHRhc2tzIHdob3NlIGRlYWRsaW5lIGlzIGFwcHJvYWNoaW5nIGFuZCB3aG9zZSBhc3NpZ25lZSBoYXMgbm90IGJlZW4gcmVtaW5kZWQKCiMgQXJndW1lbnRzCiogYHdpdGhpbl9zZWNzYCAtIEhvdyBmYXIgYWhlYWQgb2Ygbm93IGEgZGVhZGxpbmUgY291bnRzIGFzIGFwcHJvYWNoaW5nCiogYHN0YXJ0YCAtIFRhc2sgSUQgdG8gc3RhcnQgc2Nhbm5pbmcgZnJvbSAodXNlIHRoZSBwcmV2aW91cyBwYWdlJ3MgY3Vyc29yKQoqIGBsaW1pdGAgLSBNYXhpbXVtIG51bWJlciBvZiB0YXNrcyB0byByZXR1cm4gKGNhcHBlZCBhdCA1MCkKCiMgUmV0dXJucwpBc3NpZ25lZCBvciBpbi1wcm9ncmVzcyB0YXNrcyBkdWUgd2l0aGluIHRoZSB3aW5kb3csIGluIElEIG9yZGVyLCBhbmQgYSBjdXJzb3IgZm9yIHRoZQpuZXh0IHBhZ2UAAAAAAAAaZ2V0X3Rhc2tzX25lZWRpbmdfcmVtaW5kZXIAAAAAAAMAAAAAAAAAC3dpdGhpbl9zZWNzAAAAAAYAAAAAAAAABXN0YXJ0AAAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAB9AAAAAIVGFza1BhZ2U=",
+        "AAAAAAAAAJ5SZWNvcmQgdGhhdCBhIHRhc2sncyBhc3NpZ25lZSBoYXMgYmVlbiByZW1pbmRlZCBvZiB0aGUgZGVhZGxpbmUKCiMgQXJndW1lbnRzCiogYGNhbGxlcmAgLSBBZGRyZXNzIG9mIHRoZSB0YXNrIGNyZWF0b3Igb3IgYXNzaWduZWUKKiBgdGFza19pZGAgLSBJRCBvZiB0aGUgdGFzawAAAAAADW1hcmtfcmVtaW5kZWQAAAAAAAACAAAAAAAAAAZjYWxsZXIAAAAAABMAAAAAAAAAB3Rhc2tfaWQAAAAABgAAAAA=",
+        "AAAAAAAAAL9HZXQgdGFza3MgdGhhdCByZWZlcmVuY2UgYSBHaXRIdWIgbGluawoKIyBBcmd1bWVudHMKKiBgc3RhcnRgIC0gTnVtYmVyIG9mIGxpbmtlZCB0YXNrcyB0byBza2lwCiogYGxpbWl0YCAtIE1heGltdW0gbnVtYmVyIG9mIHRhc2tzIHRvIHJldHVybiAoY2FwcGVkIGF0IDUwKQoKIyBSZXR1cm5zCkxpbmtlZCB0YXNrcyBpbiBJRCBvcmRlcgAAAAAQZ2V0X2dpdGh1Yl90YXNrcwAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABAAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAABFRhc2s=",
+        "AAAAAAAAAQJHZXQgZGlzcHV0ZWQgdGFza3MgYXdhaXRpbmcgYXJiaXRyYXRpb24KCiMgQXJndW1lbnRzCiogYHN0YXJ0YCAtIFRhc2sgSUQgdG8gc3RhcnQgc2Nhbm5pbmcgZnJvbSAodXNlIHRoZSBwcmV2aW91cyBwYWdlJ3MgY3Vyc29yKQoqIGBsaW1pdGAgLSBNYXhpbXVtIG51bWJlciBvZiB0YXNrcyB0byByZXR1cm4gKGNhcHBlZCBhdCA1MCkKCiMgUmV0dXJucwpEaXNwdXRlZCB0YXNrcyBpbiBJRCBvcmRlciBhbmQgYSBjdXJzb3IgZm9yIHRoZSBuZXh0IHBhZ2UAAAAAABJnZXRfZGlzcHV0ZWRfdGFza3MAAAAAAAIAAAAAAAAABXN0YXJ0AAAAAAAABgAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAB9AAAAAIVGFza1BhZ2U=",
+        "AAAAAAAAAYhHZXQgdGFza3MgY29tcGxldGVkIHdpdGhpbiBhIHRpbWUgcmFuZ2UKCiMgQXJndW1lbnRzCiogYGZyb21gIC0gU3RhcnQgb2YgdGhlIHJhbmdlIChpbmNsdXNpdmUsIFVuaXggdGltZXN0YW1wKQoqIGB0b2AgLSBFbmQgb2YgdGhlIHJhbmdlIChpbmNsdXNpdmUsIFVuaXggdGltZXN0YW1wKQoqIGBzdGFydGAgLSBUYXNrIElEIHRvIHN0YXJ0IHNjYW5uaW5nIGZyb20gKHVzZSB0aGUgcHJldmlvdXMgcGFnZSdzIGN1cnNvcikKKiBgbGltaXRgIC0gTWF4aW11bSBudW1iZXIgb2YgdGFza3MgdG8gcmV0dXJuIChjYXBwZWQgYXQgNTApCgojIFJldHVybnMKVGFza3Mgd2hvc2UgYGNvbXBsZXRlZF9hdGAgZmFsbHMgaW4gYFtmcm9tLCB0b11gIGFuZCBhIGN1cnNvciBmb3IgdGhlIG5leHQgcGFnZQAAABtnZXRfdGFza3NfY29tcGxldGVkX2JldHdlZW4AAAAABAAAAAAAAAAEZnJvbQAAAAYAAAAAAAAAAnRvAAAAAAAGAAAAAAAAAAVzdGFydAAAAAAAAAYAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAfQAAAACFRhc2tQYWdl",
+        "AAAAAAAAABlHZXQgdG90YWwgbnVtYmVyIG9mIHRhc2tzAAAAAAAADmdldF90YXNrX2NvdW50AAAAAAAAAAAAAQAAAAY=",
+        "AAAAAAAAAPpHZXQgdGhlIElEcyBvZiBleGlzdGluZyB0YXNrcyBpbiBhIHJhbmdlCgojIEFyZ3VtZW50cwoqIGBzdGFydGAgLSBGaXJzdCB0YXNrIElEIG9mIHRoZSByYW5nZSAoaW5jbHVzaXZlKQoqIGBlbmRgIC0gTGFzdCB0YXNrIElEIG9mIHRoZSByYW5nZSAoZXhjbHVzaXZlLCBzcGFuIGNhcHBlZCBhdCAxMDAgSURzKQoKIyBSZXR1cm5zClZlY3RvciBvZiB0YXNrIElEcyBpbiB0aGUgcmFuZ2UgdGhhdCBleGlzdCwgaW4gYXNjZW5kaW5nIG9yZGVyAAAAAAAVZ2V0X3Rhc2tfaWRzX2luX3JhbmdlAAAAAAAAAgAAAAAAAAAFc3RhcnQAAAAAAAAGAAAAAAAAAANlbmQAAAAABgAAAAEAAAPqAAAABg==",
+        "AAAAAAAAAK1HZXQgdGhlIG1vc3QgcmVjZW50bHkgY3JlYXRlZCB0YXNrcwoKIyBBcmd1bWVudHMKKiBgbGltaXRgIC0gTWF4aW11bSBudW1iZXIgb2YgdGFza3MgdG8gcmV0dXJuIChjYXBwZWQgYXQgMTApCgojIFJldHVybnMKVmVjdG9yIG9mIHRoZSBuZXdlc3QgdGFza3MsIG9yZGVyZWQgYnkgZGVzY2VuZGluZyBJRAAAAAAAABBnZXRfcmVjZW50X3Rhc2tzAAAAAQAAAAAAAAAFbGltaXQAAAAAAAAEAAAAAQAAA+oAAAfQAAAABFRhc2s=" ]),
       options
     )
   }
   public readonly fromJSON = {
     initialize: this.txFromJSON<null>,
+        get_storage_version: this.txFromJSON<u32>,
+        is_initialized: this.txFromJSON<boolean>,
         register_user: this.txFromJSON<null>,
         get_user_profile: this.txFromJSON<Option<UserProfile>>,
         apply_for_task: this.txFromJSON<null>,
         get_task_applications: this.txFromJSON<Array<TaskApplication>>,
+        add_comment: this.txFromJSON<null>,
+        get_comments: this.txFromJSON<Array<TaskComment>>,
         assign_to_applicant: this.txFromJSON<null>,
         create_task: this.txFromJSON<u64>,
+        create_task_with_options: this.txFromJSON<u64>,
+        create_pledge_task: this.txFromJSON<u64>,
+        create_task_for: this.txFromJSON<u64>,
+        create_task_in: this.txFromJSON<u64>,
+        create_task_committed: this.txFromJSON<u64>,
+        reveal_and_accept: this.txFromJSON<null>,
+        create_task_with_candidates: this.txFromJSON<u64>,
+        claim_candidate_task: this.txFromJSON<null>,
         assign_task: this.txFromJSON<null>,
+        update_task_details: this.txFromJSON<null>,
         complete_task: this.txFromJSON<null>,
+        complete_task_with_hash: this.txFromJSON<null>,
+        update_completion_proof: this.txFromJSON<null>,
+        set_payout_address: this.txFromJSON<null>,
+        set_refund_address: this.txFromJSON<null>,
         start_task: this.txFromJSON<null>,
         release_funds: this.txFromJSON<null>,
+        release_funds_with_tip: this.txFromJSON<null>,
+        claim_after_timeout: this.txFromJSON<null>,
+        set_release_timeout: this.txFromJSON<null>,
+        set_max_active_assignments: this.txFromJSON<null>,
+        set_min_task_duration: this.txFromJSON<null>,
+        get_min_task_duration: this.txFromJSON<u64>,
+        set_min_work_time: this.txFromJSON<null>,
+        get_min_work_time: this.txFromJSON<u64>,
+        set_priority_fee_bps: this.txFromJSON<null>,
+        get_priority_fee_bps: this.txFromJSON<u32>,
+        set_min_fee: this.txFromJSON<null>,
+        get_min_fee: this.txFromJSON<i128>,
+        set_require_github_link: this.txFromJSON<null>,
+        get_require_github_link: this.txFromJSON<boolean>,
+        set_block_deployer_assignee: this.txFromJSON<null>,
+        get_block_deployer_assignee: this.txFromJSON<boolean>,
+        set_restrict_expiry: this.txFromJSON<null>,
+        get_restrict_expiry: this.txFromJSON<boolean>,
+        set_token: this.txFromJSON<null>,
+        set_vault: this.txFromJSON<null>,
+        write_off_vault: this.txFromJSON<null>,
+        get_vault: this.txFromJSON<Option<string>>,
+        set_auto_sweep_fees: this.txFromJSON<null>,
+        get_auto_sweep_fees: this.txFromJSON<Option<string>>,
+        set_fee_token: this.txFromJSON<null>,
+        get_fee_token: this.txFromJSON<Option<string>>,
+        get_fee_token_fees: this.txFromJSON<i128>,
+        get_fee_token_debt: this.txFromJSON<i128>,
+        settle_fee_token_debt: this.txFromJSON<null>,
+        withdraw_fee_token_fees: this.txFromJSON<null>,
+        set_cancel_fee_bps: this.txFromJSON<null>,
+        get_cancel_fee_bps: this.txFromJSON<u32>,
+        get_max_active_assignments: this.txFromJSON<u32>,
+        get_release_timeout: this.txFromJSON<u64>,
+        get_settings: this.txFromJSON<Settings>,
+        simulate_release: this.txFromJSON<ReleaseOutcome>,
+        get_task_funding_breakdown: this.txFromJSON<FundingBreakdown>,
+        get_task_escrow: this.txFromJSON<i128>,
+        validate_task: this.txFromJSON<Result<void>>,
+        required_funding: this.txFromJSON<i128>,
+        preview_release_with_fee: this.txFromJSON<readonly [i128, i128]>,
         cancel_task: this.txFromJSON<null>,
+        cancel_all: this.txFromJSON<u32>,
+        relist_task: this.txFromJSON<u64>,
+        request_cancel: this.txFromJSON<null>,
+        finalize_cancel: this.txFromJSON<null>,
+        assignee_cancel: this.txFromJSON<null>,
         mark_expired: this.txFromJSON<null>,
+        mark_expired_by: this.txFromJSON<null>,
+        mark_unaccepted: this.txFromJSON<null>,
+        mark_unaccepted_by: this.txFromJSON<null>,
+        mark_expired_batch: this.txFromJSON<Array<u64>>,
         reclaim_expired_funds: this.txFromJSON<null>,
         withdraw_platform_fees: this.txFromJSON<null>,
+        withdraw_platform_fees_to: this.txFromJSON<null>,
+        emergency_withdraw: this.txFromJSON<null>,
+        propose_withdrawal: this.txFromJSON<u64>,
+        approve_withdrawal: this.txFromJSON<null>,
+        cancel_withdrawal: this.txFromJSON<null>,
+        get_withdrawal_request: this.txFromJSON<Option<WithdrawalRequest>>,
+        raise_dispute: this.txFromJSON<null>,
+        admin_resolve: this.txFromJSON<null>,
+        resolve_dispute: this.txFromJSON<null>,
+        set_dispute_fee_policy: this.txFromJSON<null>,
+        get_dispute_fee_policy: this.txFromJSON<DisputeFeePolicy>,
         get_platform_fees: this.txFromJSON<i128>,
+        has_withdrawable_fees: this.txFromJSON<boolean>,
+        check_solvency: this.txFromJSON<readonly [i128, i128, boolean]>,
+        get_stats: this.txFromJSON<Stats>,
+        get_ledger_summary: this.txFromJSON<LedgerSummary>,
+        get_take_rate: this.txFromJSON<readonly [i128, i128]>,
         reassign_task: this.txFromJSON<null>,
+        reassign_all_expired: this.txFromJSON<Array<u64>>,
+        reassign_and_extend: this.txFromJSON<null>,
+        reopen_to_pool: this.txFromJSON<null>,
+        claim_task: this.txFromJSON<null>,
+        transfer_creatorship: this.txFromJSON<null>,
         get_task: this.txFromJSON<Task>,
+        get_task_creator: this.txFromJSON<string>,
+        get_task_assignee: this.txFromJSON<Option<string>>,
+        get_task_amount_decimals: this.txFromJSON<u32>,
+        get_remaining_time: this.txFromJSON<u64>,
+        get_task_sla_status: this.txFromJSON<SlaStatus>,
+        can_extend_deadline: this.txFromJSON<boolean>,
+        get_blocking_tasks: this.txFromJSON<Array<u64>>,
         get_user_tasks: this.txFromJSON<Array<u64>>,
+        get_user_tasks_paged: this.txFromJSON<Array<u64>>,
+        get_pending_approvals: this.txFromJSON<Array<u64>>,
+        get_creator_tasks_by_status: this.txFromJSON<Array<Task>>,
+        get_creator_fees_paid: this.txFromJSON<i128>,
+        get_user_financials: this.txFromJSON<readonly [i128, i128, i128]>,
+        rate_creator: this.txFromJSON<null>,
+        get_creator_rating: this.txFromJSON<readonly [u64, u64]>,
+        get_reputation: this.txFromJSON<AssigneeReputation>,
+        get_success_rate: this.txFromJSON<u32>,
         get_assigned_tasks: this.txFromJSON<Array<u64>>,
-        get_task_count: this.txFromJSON<u64>
+        get_assigned_tasks_paged: this.txFromJSON<Array<u64>>,
+        get_assignee_actionable: this.txFromJSON<Array<u64>>,
+        get_assignee_workload: this.txFromJSON<readonly [u64, i128]>,
+        get_assigned_tasks_by_priority: this.txFromJSON<Array<u64>>,
+        get_assignee_queue: this.txFromJSON<Array<Task>>,
+        get_tasks_needing_reminder: this.txFromJSON<TaskPage>,
+        mark_reminded: this.txFromJSON<null>,
+        get_github_tasks: this.txFromJSON<Array<Task>>,
+        get_disputed_tasks: this.txFromJSON<TaskPage>,
+        get_tasks_completed_between: this.txFromJSON<TaskPage>,
+        get_task_count: this.txFromJSON<u64>,
+        get_task_ids_in_range: this.txFromJSON<Array<u64>>,
+        get_recent_tasks: this.txFromJSON<Array<Task>>
   }
 }
\ No newline at end of file
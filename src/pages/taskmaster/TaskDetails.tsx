@@ -80,7 +80,7 @@ const TaskDetails: React.FC = () => {
           await taskMasterService.cancelTask(task.id, address);
           break;
         case "reclaim":
-          await taskMasterService.reclaimExpiredFunds(task.id, address);
+          await taskMasterService.reclaimExpiredFunds(task.id);
           break;
         case "reassign": {
           const newAssignee = prompt("Enter new assignee address:");
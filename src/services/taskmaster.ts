@@ -107,7 +107,7 @@ export class TaskMasterService {
       id: Number(contractTask.id),
       title: contractTask.title,
       description: contractTask.description,
-      github_link: contractTask.github_link || "",  // Ensure it's always a string
+      github_link: contractTask.github_link ?? "",  // The contract returns None for an empty link
       funding_amount: BigInt(contractTask.funding_amount),
       deadline: Number(contractTask.deadline),
       creator: contractTask.creator,
@@ -126,8 +126,13 @@ export class TaskMasterService {
     return task;
   }
 
-  // Initialize contract with token address and deployer
-  async initialize(tokenAddress?: string, deployerAddress?: string) {
+  // Initialize contract with token address, deployer and fee withdrawal signers
+  async initialize(
+    tokenAddress?: string,
+    deployerAddress?: string,
+    signers: string[] = [],
+    threshold: number = 0,
+  ) {
     // Use default values if not provided
     const tokenAddr = tokenAddress || NATIVE_TOKEN_CONTRACT_ID;
     const deployerAddr = deployerAddress || this.getDefaultDeployer();
@@ -135,6 +140,8 @@ export class TaskMasterService {
     const result = await this.client.initialize({
       token: tokenAddr,
       deployer: deployerAddr,
+      signers,
+      threshold,
     });
 
     return result;
@@ -439,9 +446,8 @@ export class TaskMasterService {
   }
 
   // Reclaim expired funds
-  async reclaimExpiredFunds(taskId: number, creator: string) {
+  async reclaimExpiredFunds(taskId: number) {
     const tx = await this.client.reclaim_expired_funds({
-      creator: creator,
       task_id: BigInt(taskId),
     });
 